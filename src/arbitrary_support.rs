@@ -0,0 +1,24 @@
+// Generation of valid values with the crate arbitrary, enabled by the `arbitrary` feature, for
+// fuzzing, where the dates are uniform over the days of the supported range
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+use {PersianDate, PersianTime, Tm};
+
+impl<'a> Arbitrary<'a> for PersianDate {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<PersianDate> {
+        let jdn = u.int_in_range(Tm::MIN.to_jdn()..=Tm::MAX.to_jdn())?;
+        Ok(PersianDate::from_tm(&::from_jdn(jdn).expect("day in supported range")))
+    }
+}
+
+// The offset is a whole number of minutes in [-12:00, +14:00]
+impl<'a> Arbitrary<'a> for Tm {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Tm> {
+        let date = PersianDate::arbitrary(u)?;
+        let seconds = u.int_in_range(0..=86399)?;
+        let nanosecond = u.int_in_range(0..=999_999_999)?;
+        let utcoff = u.int_in_range(-720..=840)? * 60;
+        let time = PersianTime::new_unchecked(seconds / 3600, seconds / 60 % 60, seconds % 60, nanosecond);
+        Ok(Tm { tm_utcoff: utcoff, ..date.and_time(time).to_tm() })
+    }
+}
@@ -0,0 +1,114 @@
+//! Provides the astronomical instants of the calendar, enabled by the `astro` feature.
+//!
+//! The March equinox is computed by the algorithm of Jean Meeus (Astronomical Algorithms, chapter
+//! 27) with the polynomials of delta T by Espenak and Meeus, which is accurate to about a minute
+//! for the years 1000 to 3000 of Gregorian calendar.
+
+use Tm;
+
+// The Julian date of the Unix epoch (January 1, 1970, 00:00 UTC)
+const UNIX_EPOCH_JD: f64 = 2440587.5;
+
+// The standard offset of Iran (+03:30), i.e. of the meridian 52.5 degrees east, whose noon decides
+// the day of Nowruz
+const IRAN_STANDARD_OFFSET: i32 = 12600;
+
+// The amplitude, the phase and the speed in degrees of the periodic terms of the equinoxes
+static PERIODIC_TERMS: [(f64, f64, f64); 24] = [
+    (485.0, 324.96, 1934.136),
+    (203.0, 337.23, 32964.467),
+    (199.0, 342.08, 20.186),
+    (182.0, 27.85, 445267.112),
+    (156.0, 73.14, 45036.886),
+    (136.0, 171.52, 22518.443),
+    (77.0, 222.54, 65928.934),
+    (74.0, 296.72, 3034.906),
+    (70.0, 243.58, 9037.513),
+    (58.0, 119.81, 33718.147),
+    (52.0, 297.17, 150.678),
+    (50.0, 21.02, 2281.226),
+    (45.0, 247.54, 29929.562),
+    (44.0, 325.15, 31555.956),
+    (29.0, 60.93, 4443.417),
+    (18.0, 155.12, 67555.328),
+    (17.0, 288.79, 4562.452),
+    (16.0, 198.04, 62894.029),
+    (14.0, 199.76, 31436.921),
+    (12.0, 95.39, 14577.848),
+    (12.0, 287.11, 31931.756),
+    (12.0, 320.81, 34777.259),
+    (9.0, 227.73, 1222.114),
+    (8.0, 15.45, 16859.074),
+];
+
+/// Creates a new instance of Persian time in UTC of the March equinox of the Gregorian year,
+/// rounded to the second, returns `None` if year is out of [-1000, 3000]
+pub fn march_equinox(g_year: i32) -> Option<Tm> {
+    if !(-1000..=3000).contains(&g_year) {
+        return None
+    }
+    let jde = get_equinox_jde(g_year);
+    let jd = jde - get_delta_t(g_year as f64 + 0.2) / 86400.0;
+    let seconds = ((jd - UNIX_EPOCH_JD) * 86400.0).round() as i64;
+    ::from_unix_millis(seconds * 1000)
+}
+
+/// Creates a new instance of Persian time in Tehran (see `Offset::Tehran`) of the March equinox
+/// starting the Persian year (i.e. the moment of تحویل سال), returns `None` if the equinox is not
+/// supported by `march_equinox`
+pub fn nowruz_instant(p_year: i32) -> Option<Tm> {
+    let equinox = march_equinox(p_year.checked_add(621)?)?;
+    Some(::at_tehran(equinox.to_timespec()))
+}
+
+/// Creates a new instance of Persian time at midnight in UTC of Nowruz of the year by the
+/// astronomical rule, i.e. the day of the March equinox if it is before the noon of Iran standard
+/// time (+03:30) and the next day otherwise, returns `None` if the equinox is not supported by
+/// `march_equinox`
+///
+/// The result may differ from `nowruz_of` of the arithmetic calendar in the years far from the
+/// present, and when the equinox is within minutes of the noon.
+pub fn astronomical_nowruz_of(p_year: i32) -> Option<Tm> {
+    let equinox = march_equinox(p_year.checked_add(621)?)?.with_offset(IRAN_STANDARD_OFFSET);
+    let after_noon = equinox.tm_hour >= 12;
+    ::from_jdn(equinox.to_jdn() + after_noon as i32)
+}
+
+// Returns the Julian ephemeris day (in terrestrial time) of the March equinox
+fn get_equinox_jde(year: i32) -> f64 {
+    let jde0 = if year < 1000 {
+        let y = year as f64 / 1000.0;
+        1721139.29189 + 365242.13740 * y + 0.06134 * y.powi(2) + 0.00111 * y.powi(3) - 0.00071 * y.powi(4)
+    } else {
+        let y = (year as f64 - 2000.0) / 1000.0;
+        2451623.80984 + 365242.37404 * y + 0.05169 * y.powi(2) - 0.00411 * y.powi(3) - 0.00057 * y.powi(4)
+    };
+    let t = (jde0 - 2451545.0) / 36525.0;
+    let w = (35999.373 * t - 2.47).to_radians();
+    let lambda = 1.0 + 0.0334 * w.cos() + 0.0007 * (2.0 * w).cos();
+    let s: f64 = PERIODIC_TERMS.iter().map(|&(a, b, c)| a * (b + c * t).to_radians().cos()).sum();
+    jde0 + 0.00001 * s / lambda
+}
+
+// Returns delta T, i.e. the terrestrial time minus the universal time, in seconds at the decimal
+// Gregorian year
+fn get_delta_t(y: f64) -> f64 {
+    let polynomial = |t: f64, coefficients: &[f64]| coefficients.iter().rev().fold(0.0, |sum, c| sum * t + c);
+    match y {
+        y if y < -500.0 => polynomial((y - 1820.0) / 100.0, &[-20.0, 0.0, 32.0]),
+        y if y < 500.0 => polynomial(y / 100.0, &[10583.6, -1014.41, 33.78311, -5.952053, -0.1798452, 0.022174192, 0.0090316521]),
+        y if y < 1600.0 => polynomial((y - 1000.0) / 100.0, &[1574.2, -556.01, 71.23472, 0.319781, -0.8503463, -0.005050998, 0.0083572073]),
+        y if y < 1700.0 => polynomial(y - 1600.0, &[120.0, -0.9808, -0.01532, 1.0 / 7129.0]),
+        y if y < 1800.0 => polynomial(y - 1700.0, &[8.83, 0.1603, -0.0059285, 0.00013336, -1.0 / 1174000.0]),
+        y if y < 1860.0 => polynomial(y - 1800.0, &[13.72, -0.332447, 0.0068612, 0.0041116, -0.00037436, 0.0000121272, -0.0000001699, 0.000000000875]),
+        y if y < 1900.0 => polynomial(y - 1860.0, &[7.62, 0.5737, -0.251754, 0.01680668, -0.0004473624, 1.0 / 233174.0]),
+        y if y < 1920.0 => polynomial(y - 1900.0, &[-2.79, 1.494119, -0.0598939, 0.0061966, -0.000197]),
+        y if y < 1941.0 => polynomial(y - 1920.0, &[21.20, 0.84493, -0.076100, 0.0020936]),
+        y if y < 1961.0 => polynomial(y - 1950.0, &[29.07, 0.407, -1.0 / 233.0, 1.0 / 2547.0]),
+        y if y < 1986.0 => polynomial(y - 1975.0, &[45.45, 1.067, -1.0 / 260.0, -1.0 / 718.0]),
+        y if y < 2005.0 => polynomial(y - 2000.0, &[63.86, 0.3345, -0.060374, 0.0017275, 0.000651814, 0.00002373599]),
+        y if y < 2050.0 => polynomial(y - 2000.0, &[62.92, 0.32217, 0.005589]),
+        y if y < 2150.0 => polynomial((y - 1820.0) / 100.0, &[-20.0, 0.0, 32.0]) - 0.5628 * (2150.0 - y),
+        y => polynomial((y - 1820.0) / 100.0, &[-20.0, 0.0, 32.0]),
+    }
+}
@@ -0,0 +1,26 @@
+// Conversions from and to `DateTime` of the crate bson, enabled by the `bson` feature, which
+// stores the milliseconds since January 1, 1970 UTC
+
+use bson::DateTime;
+use std::convert::TryFrom;
+use {DateError, Tm};
+
+// Truncates the nanosecond to milliseconds
+impl From<Tm> for DateTime {
+    fn from(tm: Tm) -> DateTime {
+        DateTime::from_millis(tm.timestamp_millis())
+    }
+}
+
+// Creates the Persian time in UTC, which fails if the year is out of the supported range
+impl TryFrom<DateTime> for Tm {
+    type Error = DateError;
+
+    fn try_from(date_time: DateTime) -> Result<Tm, DateError> {
+        let millis = date_time.timestamp_millis();
+        ::from_unix_millis(millis).ok_or_else(|| {
+            let (year, _, _) = ::get_gregorian_date(millis.div_euclid(86_400_000) + ::epoch::UNIX_EPOCH_JDN as i64);
+            DateError::YearOutOfRange { year }
+        })
+    }
+}
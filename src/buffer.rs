@@ -0,0 +1,97 @@
+// Formatting into the buffers of fixed length, e.g. on the devices without allocator
+
+use core::fmt;
+use locale::{PERSIAN_ERAS, PERSIAN_MARKERS, PERSIAN_MONTHS, PERSIAN_SHORT_ERAS, PERSIAN_SHORT_MARKERS, PERSIAN_SHORT_WEEKDAYS, PERSIAN_WEEKDAYS};
+use FORMAT_TOKENS;
+
+// The maximum lengths in bytes of the tokens of `FORMAT_TOKENS` in the same order, formatted by
+// the Persian locale, e.g. the sign and 4 digits of the year and +03:30 of the offset
+static TOKEN_LENGTHS: [usize; 32] = [
+    5, 5, 2, 5, longest(&PERSIAN_MONTHS), 2, 2, 3, 3, 2, 2, longest(&PERSIAN_WEEKDAYS),
+    longest(&PERSIAN_SHORT_WEEKDAYS), longest(&PERSIAN_ERAS), longest(&PERSIAN_SHORT_ERAS),
+    longest(&PERSIAN_MARKERS), longest(&PERSIAN_SHORT_MARKERS), 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 9, 2,
+    2, 6, 4,
+];
+
+// Writes to the start of a slice, failing instead of truncating if it is full
+pub(crate) struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> SliceWriter<'a> {
+    pub(crate) fn new(buf: &'a mut [u8]) -> SliceWriter<'a> {
+        SliceWriter { buf, len: 0 }
+    }
+
+    // Returns the number of bytes written
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<'a> fmt::Write for SliceWriter<'a> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.buf.len() {
+            return Err(fmt::Error)
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Returns the maximum length in bytes of the valid times formatted with `format` by
+/// `Tm::to_string`, `Tm::write_to` or `Tm::format_to_slice`, which can size a buffer at compile
+/// time (e.g. `[0; ptime::max_formatted_len("yyyy/MM/dd")]`)
+pub const fn max_formatted_len(format: &str) -> usize {
+    let format = format.as_bytes();
+    let mut len = 0;
+    let mut i = 0;
+    while i < format.len() {
+        match token_at(format, i) {
+            Some(token) => {
+                len += TOKEN_LENGTHS[token];
+                i += FORMAT_TOKENS[token].len();
+            }
+            // The other characters are written as is, where the tokens are ASCII and do not
+            // match inside a multibyte character
+            None => {
+                len += 1;
+                i += 1;
+            }
+        }
+    }
+    len
+}
+
+// Returns the index of the first token of `FORMAT_TOKENS` at `start` of `format`
+const fn token_at(format: &[u8], start: usize) -> Option<usize> {
+    let mut token = 0;
+    while token < FORMAT_TOKENS.len() {
+        let bytes = FORMAT_TOKENS[token].as_bytes();
+        let mut i = 0;
+        while i < bytes.len() && start + i < format.len() && format[start + i] == bytes[i] {
+            i += 1;
+        }
+        if i == bytes.len() {
+            return Some(token)
+        }
+        token += 1;
+    }
+    None
+}
+
+// Returns the length of the longest name in bytes
+const fn longest(names: &[&str]) -> usize {
+    let mut max = 0;
+    let mut i = 0;
+    while i < names.len() {
+        if names[i].len() > max {
+            max = names[i].len();
+        }
+        i += 1;
+    }
+    max
+}
@@ -0,0 +1,100 @@
+// Builder of Persian time from its components
+
+use {Component, DateError, Month, Tm};
+
+/// Builds a Persian time from its components, where unset month and day default to 1 and unset
+/// time of day and offset default to zero, e.g.
+/// `TmBuilder::new().year(1403).month(1).day(1).hour(12).build()`
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug, Hash)]
+pub struct TmBuilder {
+    year: Option<i32>,
+    month: Option<i32>,
+    day: Option<i32>,
+    hour: Option<i32>,
+    minute: Option<i32>,
+    second: Option<i32>,
+    nanosecond: Option<i32>,
+    utcoff: Option<i32>,
+}
+
+impl TmBuilder {
+    /// Creates a new builder without any components
+    pub fn new() -> TmBuilder {
+        TmBuilder::default()
+    }
+
+    /// Sets the year
+    pub fn year(mut self, year: i32) -> TmBuilder {
+        self.year = Some(year);
+        self
+    }
+
+    /// Sets the month - [1, 12]. 1 = Farvardin, ..., 12 = Esfand.
+    pub fn month(mut self, month: i32) -> TmBuilder {
+        self.month = Some(month);
+        self
+    }
+
+    /// Sets the month of `Month`, e.g. `Month::Farvardin`
+    pub fn persian_month(mut self, month: Month) -> TmBuilder {
+        self.month = Some(month.index() + 1);
+        self
+    }
+
+    /// Sets the day of month - [1, 31]
+    pub fn day(mut self, day: i32) -> TmBuilder {
+        self.day = Some(day);
+        self
+    }
+
+    /// Sets the hour - [0, 23]
+    pub fn hour(mut self, hour: i32) -> TmBuilder {
+        self.hour = Some(hour);
+        self
+    }
+
+    /// Sets the minute - [0, 59]
+    pub fn minute(mut self, minute: i32) -> TmBuilder {
+        self.minute = Some(minute);
+        self
+    }
+
+    /// Sets the second - [0, 59]
+    pub fn second(mut self, second: i32) -> TmBuilder {
+        self.second = Some(second);
+        self
+    }
+
+    /// Sets the nanosecond - [0, 999999999]
+    pub fn nanosecond(mut self, nanosecond: i32) -> TmBuilder {
+        self.nanosecond = Some(nanosecond);
+        self
+    }
+
+    /// Sets the offset from UTC in seconds - [-86399, 86399]
+    pub fn utc_offset(mut self, utcoff: i32) -> TmBuilder {
+        self.utcoff = Some(utcoff);
+        self
+    }
+
+    /// Validates the components and builds the time, returns the reason if year is not set or any
+    /// component is invalid, where the month is reported since Farvardin as `tm_mon`
+    pub fn build(&self) -> Result<Tm, DateError> {
+        let year = self.year.ok_or(DateError::Missing { component: Component::Year })?;
+        let month = self.month.unwrap_or(1);
+        let mon = month.checked_sub(1).ok_or(DateError::MonthOutOfRange { month })?;
+        let utcoff = self.utcoff.unwrap_or(0);
+        if !(-86399..=86399).contains(&utcoff) {
+            return Err(DateError::InvalidTime { component: Component::Offset });
+        }
+        let mut tm = ::try_from_persian_components(year,
+                                                   mon,
+                                                   self.day.unwrap_or(1),
+                                                   self.hour.unwrap_or(0),
+                                                   self.minute.unwrap_or(0),
+                                                   self.second.unwrap_or(0),
+                                                   self.nanosecond.unwrap_or(0))?;
+        tm.tm_utcoff = utcoff;
+        Ok(tm)
+    }
+}
@@ -0,0 +1,101 @@
+// Conversions from and to the types of the crate chrono, enabled by the `chrono` feature
+
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc};
+use std::convert::TryFrom;
+use {DateError, Tm};
+
+/// Converts the dates and times of the crate chrono to Persian calendar
+pub trait ToPersian {
+    /// Converts to Persian time, keeping the wall clock and the offset (if any), returns the
+    /// reason if it is out of the supported range or falls on a leap second
+    fn to_persian(&self) -> Result<Tm, DateError>;
+}
+
+impl ToPersian for NaiveDate {
+    fn to_persian(&self) -> Result<Tm, DateError> {
+        ::try_from_gregorian_date(self.year(), self.month0() as i32, self.day() as i32)
+    }
+}
+
+impl ToPersian for NaiveDateTime {
+    fn to_persian(&self) -> Result<Tm, DateError> {
+        ::try_from_gregorian_components(self.year(),
+                                        self.month0() as i32,
+                                        self.day() as i32,
+                                        self.hour() as i32,
+                                        self.minute() as i32,
+                                        self.second() as i32,
+                                        self.nanosecond() as i32)
+    }
+}
+
+impl<Tz: TimeZone> ToPersian for DateTime<Tz> {
+    fn to_persian(&self) -> Result<Tm, DateError> {
+        let tm = self.naive_local().to_persian()?;
+        Ok(Tm { tm_utcoff: ::chrono::Offset::fix(self.offset()).local_minus_utc(), ..tm })
+    }
+}
+
+// Creates the Persian date at midnight in UTC
+impl TryFrom<NaiveDate> for Tm {
+    type Error = DateError;
+
+    fn try_from(date: NaiveDate) -> Result<Tm, DateError> {
+        date.to_persian()
+    }
+}
+
+// Creates the Persian time in UTC
+impl TryFrom<NaiveDateTime> for Tm {
+    type Error = DateError;
+
+    fn try_from(date_time: NaiveDateTime) -> Result<Tm, DateError> {
+        date_time.to_persian()
+    }
+}
+
+impl TryFrom<DateTime<Utc>> for Tm {
+    type Error = DateError;
+
+    fn try_from(date_time: DateTime<Utc>) -> Result<Tm, DateError> {
+        date_time.to_persian()
+    }
+}
+
+impl TryFrom<DateTime<FixedOffset>> for Tm {
+    type Error = DateError;
+
+    fn try_from(date_time: DateTime<FixedOffset>) -> Result<Tm, DateError> {
+        date_time.to_persian()
+    }
+}
+
+// Returns the Gregorian date, ignoring the time of day and the offset
+impl From<Tm> for NaiveDate {
+    fn from(tm: Tm) -> NaiveDate {
+        let (year, month, day) = tm.to_gregorian_date();
+        NaiveDate::from_ymd_opt(year, month as u32 + 1, day as u32).expect("invalid date")
+    }
+}
+
+// Returns the Gregorian date and the wall clock, ignoring the offset
+impl From<Tm> for NaiveDateTime {
+    fn from(tm: Tm) -> NaiveDateTime {
+        let time = NaiveTime::from_hms_nano_opt(tm.tm_hour as u32, tm.tm_min as u32, tm.tm_sec as u32, tm.tm_nsec as u32);
+        NaiveDate::from(tm).and_time(time.expect("invalid time"))
+    }
+}
+
+impl From<Tm> for DateTime<Utc> {
+    fn from(tm: Tm) -> DateTime<Utc> {
+        let clock = tm.to_timespec();
+        DateTime::from_timestamp(clock.sec, clock.nsec as u32).expect("invalid date")
+    }
+}
+
+impl From<Tm> for DateTime<FixedOffset> {
+    fn from(tm: Tm) -> DateTime<FixedOffset> {
+        let offset = FixedOffset::east_opt(tm.tm_utcoff).expect("invalid offset");
+        DateTime::<Utc>::from(tm).with_timezone(&offset)
+    }
+}
@@ -0,0 +1,144 @@
+// Conversions from and to the types of the crate time 0.1, enabled by the `compat` feature
+
+use std::ops::Sub;
+use time;
+use {Duration, Timespec, Tm};
+
+impl Tm {
+    /// Converts Persian calendar to Gregorian calendar, keeping the wall clock and the offset
+    ///
+    /// `time::Tm::to_timespec` interprets the time with a nonzero offset in the local timezone,
+    /// so `Tm::to_timespec` should be used to get the instant of time.
+    pub fn to_gregorian(&self) -> time::Tm {
+        let (year, month, day) = self.to_gregorian_date();
+        time::Tm {
+            tm_sec: self.tm_sec,
+            tm_min: self.tm_min,
+            tm_hour: self.tm_hour,
+            tm_mday: day,
+            tm_mon: month,
+            tm_year: year - 1900,
+            tm_wday: ::get_gregorian_weekday(self.tm_wday),
+            tm_yday: get_gregorian_yday(year, month, day),
+            tm_isdst: self.tm_isdst,
+            tm_utcoff: self.tm_utcoff,
+            tm_nsec: self.tm_nsec,
+        }
+    }
+
+    /// Converts time to `time::Tm`, keeping the wall clock, the offset and the daylight saving
+    /// flag (i.e. `to_gregorian`), which is also available as `time::Tm::from(tm)`
+    pub fn to_time_tm(&self) -> time::Tm {
+        self.to_gregorian()
+    }
+}
+
+/// Converts Gregorian calendar to Persian calendar
+pub fn from_gregorian(gregorian_tm: time::Tm) -> Tm {
+    let jdn = ::get_gregorian_jdn(gregorian_tm.tm_year + 1900, gregorian_tm.tm_mon + 1, gregorian_tm.tm_mday);
+    let (year, month, day) = ::get_persian_date(jdn);
+
+    Tm {
+        tm_sec: gregorian_tm.tm_sec,
+        tm_min: gregorian_tm.tm_min,
+        tm_hour: gregorian_tm.tm_hour,
+        tm_mday: day,
+        tm_mon: month,
+        tm_year: year,
+        tm_wday: get_persian_weekday(gregorian_tm.tm_wday),
+        tm_yday: ::get_persian_yday(month, day),
+        tm_isdst: gregorian_tm.tm_isdst,
+        tm_utcoff: gregorian_tm.tm_utcoff,
+        tm_nsec: gregorian_tm.tm_nsec,
+    }
+}
+
+impl Sub<time::Tm> for Tm {
+    type Output = Duration;
+
+    fn sub(self, other: time::Tm) -> Duration {
+        self.to_timespec() - Timespec::from(other.to_timespec())
+    }
+}
+
+// Keeps the wall clock, the offset and the daylight saving flag like `from_gregorian`
+impl From<time::Tm> for Tm {
+    fn from(gregorian_tm: time::Tm) -> Tm {
+        from_gregorian(gregorian_tm)
+    }
+}
+
+impl<'a> From<&'a time::Tm> for Tm {
+    fn from(gregorian_tm: &'a time::Tm) -> Tm {
+        from_gregorian(*gregorian_tm)
+    }
+}
+
+// Keeps the wall clock, the offset and the daylight saving flag like `Tm::to_time_tm`
+impl From<Tm> for time::Tm {
+    fn from(tm: Tm) -> time::Tm {
+        tm.to_time_tm()
+    }
+}
+
+impl<'a> From<&'a Tm> for time::Tm {
+    fn from(tm: &'a Tm) -> time::Tm {
+        tm.to_time_tm()
+    }
+}
+
+impl From<time::Timespec> for Timespec {
+    fn from(clock: time::Timespec) -> Timespec {
+        Timespec::new(clock.sec, clock.nsec)
+    }
+}
+
+impl From<Timespec> for time::Timespec {
+    fn from(clock: Timespec) -> time::Timespec {
+        time::Timespec::new(clock.sec, clock.nsec)
+    }
+}
+
+impl From<time::Duration> for Duration {
+    fn from(duration: time::Duration) -> Duration {
+        let seconds = duration.num_seconds();
+        let nanoseconds = (duration - time::Duration::seconds(seconds)).num_nanoseconds().unwrap_or(0);
+        Duration::seconds(seconds) + Duration::nanoseconds(nanoseconds)
+    }
+}
+
+impl From<Duration> for time::Duration {
+    fn from(duration: Duration) -> time::Duration {
+        time::Duration::seconds(duration.secs) + time::Duration::nanoseconds(duration.nanos as i64)
+    }
+}
+
+fn get_persian_weekday(wd: i32) -> i32 {
+    match wd {
+        0 => 1,
+        1 => 2,
+        2 => 3,
+        3 => 4,
+        4 => 5,
+        5 => 6,
+        6 => 0,
+        _ => panic!("invalid weekday value of {}", wd),
+    }
+}
+
+const fn get_gregorian_yday(year: i32, month: i32, day: i32) -> i32 {
+    [
+        [0, 0],
+        [31, 31],
+        [59, 60],
+        [90, 91],
+        [120, 121],
+        [151, 152],
+        [181, 182],
+        [212, 213],
+        [243, 244],
+        [273, 274],
+        [304, 305],
+        [334, 335],
+    ][month as usize][::is_gregorian_leap(year) as usize] + day - 1
+}
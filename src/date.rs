@@ -0,0 +1,312 @@
+// Date-only and time-only representations of Persian calendar
+
+use core::fmt;
+use core::str::FromStr;
+use {Era, Month, ParseError, Tm, Weekday};
+
+/// Represents a date of Persian calendar without time of day
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "rkyv", derive(::rkyv::Archive, ::rkyv::Serialize, ::rkyv::Deserialize))]
+#[cfg_attr(feature = "rkyv", rkyv(derive(Debug)))]
+#[cfg_attr(feature = "diesel", derive(::diesel::expression::AsExpression, ::diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = ::diesel::sql_types::Date))]
+pub struct PersianDate {
+    year: i32,
+    month: i32,
+    day: i32,
+}
+
+/// Represents a time of day without date
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "rkyv", derive(::rkyv::Archive, ::rkyv::Serialize, ::rkyv::Deserialize))]
+#[cfg_attr(feature = "rkyv", rkyv(derive(Debug)))]
+pub struct PersianTime {
+    hour: i32,
+    minute: i32,
+    second: i32,
+    nanosecond: i32,
+}
+
+/// Represents a date of Persian calendar along with a time of day, without offset
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "rkyv", derive(::rkyv::Archive, ::rkyv::Serialize, ::rkyv::Deserialize))]
+#[cfg_attr(feature = "rkyv", rkyv(derive(Debug)))]
+pub struct PersianDateTime {
+    date: PersianDate,
+    time: PersianTime,
+}
+
+impl PersianDate {
+    /// Creates a new instance of date from the year, the month since Farvardin [0, 11] and the
+    /// day [1, 31], returns `None` if date does not exist or is out of the supported range
+    pub const fn new(year: i32, month: i32, day: i32) -> Option<PersianDate> {
+        match ::validate::persian_date(year, month, day) {
+            Ok(()) => Some(PersianDate { year, month, day }),
+            Err(_) => None,
+        }
+    }
+
+    /// Creates a new instance of date without validating it, for the dates that are already known
+    /// to exist (the resulting date of invalid components is unspecified)
+    #[inline]
+    pub const fn new_unchecked(year: i32, month: i32, day: i32) -> PersianDate {
+        PersianDate { year, month, day }
+    }
+
+    /// Creates a new instance of date from the year, the month and the day [1, 31], returns
+    /// `None` if date does not exist
+    pub const fn from_month(year: i32, month: Month, day: i32) -> Option<PersianDate> {
+        PersianDate::new(year, month.index(), day)
+    }
+
+    /// Creates the date of `tm`
+    pub const fn from_tm(tm: &Tm) -> PersianDate {
+        PersianDate {
+            year: tm.tm_year,
+            month: tm.tm_mon,
+            day: tm.tm_mday,
+        }
+    }
+
+    /// Returns the astronomical year, where 0 = 1 BP, -1 = 2 BP and so on
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the era of year
+    pub const fn era(&self) -> Era {
+        Era::of_year(self.year)
+    }
+
+    /// Returns the year of era [1, ..]
+    pub const fn year_of_era(&self) -> i32 {
+        Era::year_of_era(self.year)
+    }
+
+    /// Returns the month
+    pub const fn month(&self) -> Month {
+        ::month::MONTHS[self.month as usize]
+    }
+
+    /// Returns the number of month - [1, 12]. 1 = Farvardin, ..., 12 = Esfand.
+    pub const fn month_number(&self) -> i32 {
+        self.month + 1
+    }
+
+    /// Returns the day of month - [1, 31]
+    pub const fn day(&self) -> i32 {
+        self.day
+    }
+
+    /// Returns the day of year since Farvardin 1 - [0, 365]
+    pub const fn yday(&self) -> i32 {
+        ::get_persian_yday(self.month, self.day)
+    }
+
+    /// Returns the day of year - [1, 366]
+    pub const fn ordinal(&self) -> i32 {
+        self.yday() + 1
+    }
+
+    /// Returns the weekday
+    pub const fn weekday(&self) -> Weekday {
+        ::weekday::WEEKDAYS[::get_jdn_weekday(::get_jdn(self.year, self.month + 1, self.day)) as usize]
+    }
+
+    /// Combines date with `time`
+    pub const fn and_time(&self, time: PersianTime) -> PersianDateTime {
+        PersianDateTime::new(*self, time)
+    }
+
+    /// Converts date to Persian time at midnight in UTC
+    pub fn to_tm(&self) -> Tm {
+        self.and_time(PersianTime::MIDNIGHT).to_tm()
+    }
+}
+
+impl PersianTime {
+    /// The time of midnight (00:00:00)
+    pub const MIDNIGHT: PersianTime = PersianTime { hour: 0, minute: 0, second: 0, nanosecond: 0 };
+
+    /// Creates a new instance of time from the hour [0, 23], the minute [0, 59], the second
+    /// [0, 59] and the nanosecond [0, 999999999], returns `None` if any of them is out of range
+    pub const fn new(hour: i32, minute: i32, second: i32, nanosecond: i32) -> Option<PersianTime> {
+        if !::validate::is_time_valid(hour, minute, second, nanosecond) {
+            return None
+        }
+        Some(PersianTime { hour, minute, second, nanosecond })
+    }
+
+    /// Creates a new instance of time without validating it, for the times that are already known
+    /// to be valid (the resulting time of invalid components is unspecified)
+    #[inline]
+    pub const fn new_unchecked(hour: i32, minute: i32, second: i32, nanosecond: i32) -> PersianTime {
+        PersianTime { hour, minute, second, nanosecond }
+    }
+
+    /// Creates the time of day of `tm`
+    pub const fn from_tm(tm: &Tm) -> PersianTime {
+        PersianTime {
+            hour: tm.tm_hour,
+            minute: tm.tm_min,
+            second: tm.tm_sec,
+            nanosecond: tm.tm_nsec,
+        }
+    }
+
+    /// Returns the hour - [0, 23]
+    pub const fn hour(&self) -> i32 {
+        self.hour
+    }
+
+    /// Returns the minute - [0, 59]
+    pub const fn minute(&self) -> i32 {
+        self.minute
+    }
+
+    /// Returns the second - [0, 59]
+    pub const fn second(&self) -> i32 {
+        self.second
+    }
+
+    /// Returns the nanosecond - [0, 999999999]
+    pub const fn nanosecond(&self) -> i32 {
+        self.nanosecond
+    }
+}
+
+impl PersianDateTime {
+    /// Creates a new instance of date and time
+    pub const fn new(date: PersianDate, time: PersianTime) -> PersianDateTime {
+        PersianDateTime { date, time }
+    }
+
+    /// Creates the date and time of `tm`, ignoring its offset
+    pub const fn from_tm(tm: &Tm) -> PersianDateTime {
+        PersianDateTime::new(PersianDate::from_tm(tm), PersianTime::from_tm(tm))
+    }
+
+    /// Returns the date
+    pub const fn date(&self) -> PersianDate {
+        self.date
+    }
+
+    /// Returns the time of day
+    pub const fn time(&self) -> PersianTime {
+        self.time
+    }
+
+    /// Converts date and time to Persian time in UTC
+    pub fn to_tm(&self) -> Tm {
+        ::from_persian_components(self.date.year,
+                                  self.date.month,
+                                  self.date.day,
+                                  self.time.hour,
+                                  self.time.minute,
+                                  self.time.second,
+                                  self.time.nanosecond)
+            .expect("invalid date")
+    }
+}
+
+// Prints e.g. `1395-01-02`
+impl fmt::Display for PersianDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month + 1, self.day)
+    }
+}
+
+// Prints e.g. `10:30:05`, followed by the fraction of second if it is not zero
+impl fmt::Display for PersianTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)?;
+        if self.nanosecond != 0 {
+            write!(f, ".{:09}", self.nanosecond)?;
+        }
+        Ok(())
+    }
+}
+
+// Prints e.g. `1395-01-02T10:30:05`
+impl fmt::Display for PersianDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}T{}", self.date, self.time)
+    }
+}
+
+// Parses e.g. `1395-01-02`
+impl FromStr for PersianDate {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<PersianDate, ParseError> {
+        ::parse::date(s)
+    }
+}
+
+// Parses e.g. `10:30:05` or `10:30:05.250`
+impl FromStr for PersianTime {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<PersianTime, ParseError> {
+        ::parse::time_of_day(s)
+    }
+}
+
+// Parses e.g. `1395-01-02T10:30:05`
+impl FromStr for PersianDateTime {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<PersianDateTime, ParseError> {
+        ::parse::date_time(s)
+    }
+}
+
+impl From<Tm> for PersianDate {
+    fn from(tm: Tm) -> PersianDate {
+        PersianDate::from_tm(&tm)
+    }
+}
+
+impl From<Tm> for PersianTime {
+    fn from(tm: Tm) -> PersianTime {
+        PersianTime::from_tm(&tm)
+    }
+}
+
+impl From<Tm> for PersianDateTime {
+    fn from(tm: Tm) -> PersianDateTime {
+        PersianDateTime::from_tm(&tm)
+    }
+}
+
+impl From<PersianDate> for Tm {
+    fn from(date: PersianDate) -> Tm {
+        date.to_tm()
+    }
+}
+
+impl From<PersianDateTime> for Tm {
+    fn from(date_time: PersianDateTime) -> Tm {
+        date_time.to_tm()
+    }
+}
+
+impl From<PersianDate> for PersianDateTime {
+    fn from(date: PersianDate) -> PersianDateTime {
+        date.and_time(PersianTime::MIDNIGHT)
+    }
+}
+
+/// Creates a `PersianDate` constant from the Persian date written as `year-month-day`, failing to
+/// compile if date does not exist, e.g. `pdate!(1403-01-01)`
+#[macro_export]
+macro_rules! pdate {
+    ($year:literal - $month:literal - $day:literal) => {{
+        #[allow(unknown_lints, clippy::zero_prefixed_literal)]
+        const DATE: $crate::PersianDate = match $crate::PersianDate::new($year, $month - 1, $day) {
+            Some(date) => date,
+            None => panic!("invalid Persian date"),
+        };
+        DATE
+    }};
+}
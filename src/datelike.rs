@@ -0,0 +1,323 @@
+// Traits of the dates and the times of day, mirroring `Datelike` and `Timelike` of the crate chrono
+
+use {DateError, Era, Month, PersianDate, PersianDateTime, PersianTime, Tm, Weekday};
+
+/// Provides the components of Persian date, so generic code can work with any type having a date
+pub trait Datelike: Sized {
+    /// Returns the astronomical year, where 0 = 1 BP, -1 = 2 BP and so on
+    fn year(&self) -> i32;
+
+    /// Returns the month
+    fn month(&self) -> Month;
+
+    /// Returns the day of month - [1, 31]
+    fn day(&self) -> i32;
+
+    /// Returns the weekday
+    fn weekday(&self) -> Weekday;
+
+    /// Returns a copy with the year replaced
+    fn with_year(&self, year: i32) -> Result<Self, DateError>;
+
+    /// Returns a copy with the month replaced
+    fn with_month(&self, month: Month) -> Result<Self, DateError>;
+
+    /// Returns a copy with the day of month [1, 31] replaced
+    fn with_day(&self, day: i32) -> Result<Self, DateError>;
+
+    /// Returns the number of month - [1, 12]. 1 = Farvardin, ..., 12 = Esfand.
+    fn month_number(&self) -> i32 {
+        self.month().number()
+    }
+
+    /// Returns the month since Farvardin - [0, 11]
+    fn month0(&self) -> i32 {
+        self.month().index()
+    }
+
+    /// Returns the day of month since 1 - [0, 30]
+    fn day0(&self) -> i32 {
+        self.day() - 1
+    }
+
+    /// Returns the day of year - [1, 366]
+    fn ordinal(&self) -> i32 {
+        self.ordinal0() + 1
+    }
+
+    /// Returns the day of year since Farvardin 1 - [0, 365]
+    fn ordinal0(&self) -> i32 {
+        ::get_persian_yday(self.month0(), self.day())
+    }
+
+    /// Returns the era of year
+    fn era(&self) -> Era {
+        Era::of_year(self.year())
+    }
+
+    /// Returns the year of era [1, ..]
+    fn year_of_era(&self) -> i32 {
+        Era::year_of_era(self.year())
+    }
+
+    /// Returns true if year is leap
+    fn is_leap(&self) -> bool {
+        ::is_persian_leap(self.year())
+    }
+}
+
+/// Provides the components of time of day, so generic code can work with any type having a time
+pub trait Timelike: Sized {
+    /// Returns the hour - [0, 23]
+    fn hour(&self) -> i32;
+
+    /// Returns the minute - [0, 59]
+    fn minute(&self) -> i32;
+
+    /// Returns the second - [0, 59]
+    fn second(&self) -> i32;
+
+    /// Returns the nanosecond - [0, 999999999]
+    fn nanosecond(&self) -> i32;
+
+    /// Returns a copy with the hour [0, 23] replaced
+    fn with_hour(&self, hour: i32) -> Result<Self, DateError>;
+
+    /// Returns a copy with the minute [0, 59] replaced
+    fn with_minute(&self, minute: i32) -> Result<Self, DateError>;
+
+    /// Returns a copy with the second [0, 59] replaced
+    fn with_second(&self, second: i32) -> Result<Self, DateError>;
+
+    /// Returns a copy with the nanosecond [0, 999999999] replaced
+    fn with_nanosecond(&self, nanosecond: i32) -> Result<Self, DateError>;
+
+    /// Returns the hour on a 12-hour clock as (true if PM, [1, 12])
+    fn hour12(&self) -> (bool, i32) {
+        let hour = self.hour();
+        (hour >= 12, (hour + 11) % 12 + 1)
+    }
+
+    /// Returns the number of seconds since midnight - [0, 86399]
+    fn num_seconds_from_midnight(&self) -> i32 {
+        self.hour() * 3600 + self.minute() * 60 + self.second()
+    }
+}
+
+// Keeps the time of day and the offset
+impl Datelike for Tm {
+    fn year(&self) -> i32 {
+        self.tm_year
+    }
+
+    fn month(&self) -> Month {
+        Tm::month(self)
+    }
+
+    fn day(&self) -> i32 {
+        self.tm_mday
+    }
+
+    fn weekday(&self) -> Weekday {
+        Tm::weekday(self)
+    }
+
+    fn month_number(&self) -> i32 {
+        self.tm_mon + 1
+    }
+
+    fn month0(&self) -> i32 {
+        self.tm_mon
+    }
+
+    fn ordinal0(&self) -> i32 {
+        self.tm_yday
+    }
+
+    fn with_year(&self, year: i32) -> Result<Tm, DateError> {
+        Tm::with_year(self, year)
+    }
+
+    fn with_month(&self, month: Month) -> Result<Tm, DateError> {
+        Tm::with_month(self, month)
+    }
+
+    fn with_day(&self, day: i32) -> Result<Tm, DateError> {
+        Tm::with_day(self, day)
+    }
+}
+
+// Keeps the date and the offset
+impl Timelike for Tm {
+    fn hour(&self) -> i32 {
+        self.tm_hour
+    }
+
+    fn minute(&self) -> i32 {
+        self.tm_min
+    }
+
+    fn second(&self) -> i32 {
+        self.tm_sec
+    }
+
+    fn nanosecond(&self) -> i32 {
+        self.tm_nsec
+    }
+
+    fn with_hour(&self, hour: i32) -> Result<Tm, DateError> {
+        Tm::with_hour(self, hour)
+    }
+
+    fn with_minute(&self, minute: i32) -> Result<Tm, DateError> {
+        Tm::with_minute(self, minute)
+    }
+
+    fn with_second(&self, second: i32) -> Result<Tm, DateError> {
+        Tm::with_second(self, second)
+    }
+
+    fn with_nanosecond(&self, nanosecond: i32) -> Result<Tm, DateError> {
+        Tm::with_nanosecond(self, nanosecond)
+    }
+}
+
+impl Datelike for PersianDate {
+    fn year(&self) -> i32 {
+        PersianDate::year(self)
+    }
+
+    fn month(&self) -> Month {
+        PersianDate::month(self)
+    }
+
+    fn day(&self) -> i32 {
+        PersianDate::day(self)
+    }
+
+    fn weekday(&self) -> Weekday {
+        PersianDate::weekday(self)
+    }
+
+    fn with_year(&self, year: i32) -> Result<PersianDate, DateError> {
+        with_date(year, self.month0(), self.day())
+    }
+
+    fn with_month(&self, month: Month) -> Result<PersianDate, DateError> {
+        with_date(self.year(), month.index(), self.day())
+    }
+
+    fn with_day(&self, day: i32) -> Result<PersianDate, DateError> {
+        with_date(self.year(), self.month0(), day)
+    }
+}
+
+impl Timelike for PersianTime {
+    fn hour(&self) -> i32 {
+        PersianTime::hour(self)
+    }
+
+    fn minute(&self) -> i32 {
+        PersianTime::minute(self)
+    }
+
+    fn second(&self) -> i32 {
+        PersianTime::second(self)
+    }
+
+    fn nanosecond(&self) -> i32 {
+        PersianTime::nanosecond(self)
+    }
+
+    fn with_hour(&self, hour: i32) -> Result<PersianTime, DateError> {
+        with_time(hour, self.minute(), self.second(), self.nanosecond())
+    }
+
+    fn with_minute(&self, minute: i32) -> Result<PersianTime, DateError> {
+        with_time(self.hour(), minute, self.second(), self.nanosecond())
+    }
+
+    fn with_second(&self, second: i32) -> Result<PersianTime, DateError> {
+        with_time(self.hour(), self.minute(), second, self.nanosecond())
+    }
+
+    fn with_nanosecond(&self, nanosecond: i32) -> Result<PersianTime, DateError> {
+        with_time(self.hour(), self.minute(), self.second(), nanosecond)
+    }
+}
+
+// Keeps the time of day
+impl Datelike for PersianDateTime {
+    fn year(&self) -> i32 {
+        self.date().year()
+    }
+
+    fn month(&self) -> Month {
+        self.date().month()
+    }
+
+    fn day(&self) -> i32 {
+        self.date().day()
+    }
+
+    fn weekday(&self) -> Weekday {
+        self.date().weekday()
+    }
+
+    fn with_year(&self, year: i32) -> Result<PersianDateTime, DateError> {
+        Ok(PersianDateTime::new(self.date().with_year(year)?, self.time()))
+    }
+
+    fn with_month(&self, month: Month) -> Result<PersianDateTime, DateError> {
+        Ok(PersianDateTime::new(self.date().with_month(month)?, self.time()))
+    }
+
+    fn with_day(&self, day: i32) -> Result<PersianDateTime, DateError> {
+        Ok(PersianDateTime::new(self.date().with_day(day)?, self.time()))
+    }
+}
+
+// Keeps the date
+impl Timelike for PersianDateTime {
+    fn hour(&self) -> i32 {
+        self.time().hour()
+    }
+
+    fn minute(&self) -> i32 {
+        self.time().minute()
+    }
+
+    fn second(&self) -> i32 {
+        self.time().second()
+    }
+
+    fn nanosecond(&self) -> i32 {
+        self.time().nanosecond()
+    }
+
+    fn with_hour(&self, hour: i32) -> Result<PersianDateTime, DateError> {
+        Ok(PersianDateTime::new(self.date(), self.time().with_hour(hour)?))
+    }
+
+    fn with_minute(&self, minute: i32) -> Result<PersianDateTime, DateError> {
+        Ok(PersianDateTime::new(self.date(), self.time().with_minute(minute)?))
+    }
+
+    fn with_second(&self, second: i32) -> Result<PersianDateTime, DateError> {
+        Ok(PersianDateTime::new(self.date(), self.time().with_second(second)?))
+    }
+
+    fn with_nanosecond(&self, nanosecond: i32) -> Result<PersianDateTime, DateError> {
+        Ok(PersianDateTime::new(self.date(), self.time().with_nanosecond(nanosecond)?))
+    }
+}
+
+fn with_date(year: i32, month: i32, day: i32) -> Result<PersianDate, DateError> {
+    ::validate::persian_date(year, month, day)?;
+    Ok(PersianDate::new_unchecked(year, month, day))
+}
+
+fn with_time(hour: i32, minute: i32, second: i32, nanosecond: i32) -> Result<PersianTime, DateError> {
+    ::validate::time(hour, minute, second, nanosecond)?;
+    Ok(PersianTime::new_unchecked(hour, minute, second, nanosecond))
+}
@@ -0,0 +1,103 @@
+// Mappings of SQL types of the crate diesel, enabled by the `diesel_postgres` and `diesel_sqlite`
+// features, where `PersianDate` maps to `DATE` and `Tm` maps to `TIMESTAMP` (and `TIMESTAMPTZ` of
+// PostgreSQL) stored as the Gregorian date and time in UTC
+
+#[cfg(feature = "diesel_postgres")]
+mod pg {
+    use diesel::deserialize::{self, FromSql};
+    use diesel::pg::data_types::{PgDate, PgTimestamp};
+    use diesel::pg::{Pg, PgValue};
+    use diesel::serialize::{self, Output, ToSql};
+    use diesel::sql_types::{Date, Timestamp, Timestamptz};
+    use {PersianDate, Timespec, Tm};
+
+    // The Julian day number and the Unix time of January 1, 2000 as the epoch of PostgreSQL
+    const PG_EPOCH_JDN: i32 = 2451545;
+    const PG_EPOCH_UNIX: i64 = 946684800;
+
+    impl ToSql<Date, Pg> for PersianDate {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+            let days = self.to_tm().to_jdn() - PG_EPOCH_JDN;
+            ToSql::<Date, Pg>::to_sql(&PgDate(days), &mut out.reborrow())
+        }
+    }
+
+    impl FromSql<Date, Pg> for PersianDate {
+        fn from_sql(value: PgValue<'_>) -> deserialize::Result<PersianDate> {
+            let PgDate(days) = FromSql::<Date, Pg>::from_sql(value)?;
+            let tm = days.checked_add(PG_EPOCH_JDN).and_then(::from_jdn).ok_or("date out of supported range")?;
+            Ok(PersianDate::from_tm(&tm))
+        }
+    }
+
+    impl ToSql<Timestamp, Pg> for Tm {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+            let clock = self.to_timespec();
+            let microseconds = (clock.sec - PG_EPOCH_UNIX) * 1_000_000 + clock.nsec as i64 / 1000;
+            ToSql::<Timestamp, Pg>::to_sql(&PgTimestamp(microseconds), &mut out.reborrow())
+        }
+    }
+
+    impl FromSql<Timestamp, Pg> for Tm {
+        fn from_sql(value: PgValue<'_>) -> deserialize::Result<Tm> {
+            let PgTimestamp(microseconds) = FromSql::<Timestamp, Pg>::from_sql(value)?;
+            let sec = microseconds.div_euclid(1_000_000) + PG_EPOCH_UNIX;
+            let clock = Timespec::new(sec, microseconds.rem_euclid(1_000_000) as i32 * 1000);
+            if clock < Tm::MIN.to_timespec() || clock > Tm::MAX.to_timespec() {
+                return Err("timestamp out of supported range".into())
+            }
+            Ok(::at_utc(clock))
+        }
+    }
+
+    impl ToSql<Timestamptz, Pg> for Tm {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+            ToSql::<Timestamp, Pg>::to_sql(self, out)
+        }
+    }
+
+    impl FromSql<Timestamptz, Pg> for Tm {
+        fn from_sql(value: PgValue<'_>) -> deserialize::Result<Tm> {
+            FromSql::<Timestamp, Pg>::from_sql(value)
+        }
+    }
+}
+
+#[cfg(feature = "diesel_sqlite")]
+mod sqlite {
+    use diesel::deserialize::{self, FromSql};
+    use diesel::serialize::{self, IsNull, Output, ToSql};
+    use diesel::sql_types::{Date, Timestamp};
+    use diesel::sqlite::{Sqlite, SqliteValue};
+    use {PersianDate, Tm};
+
+    // Stores e.g. `2016-03-21`
+    impl ToSql<Date, Sqlite> for PersianDate {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+            let (year, month, day) = self.to_tm().to_gregorian_date();
+            out.set_value(format!("{:04}-{:02}-{:02}", year, month + 1, day));
+            Ok(IsNull::No)
+        }
+    }
+
+    impl FromSql<Date, Sqlite> for PersianDate {
+        fn from_sql(mut value: SqliteValue<'_, '_, '_>) -> deserialize::Result<PersianDate> {
+            Ok(PersianDate::from_tm(&::from_iso8601(value.read_text())?))
+        }
+    }
+
+    // Stores e.g. `2016-03-21 07:00:05.250` in UTC
+    impl ToSql<Timestamp, Sqlite> for Tm {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+            let rfc3339 = self.to_utc().to_rfc3339();
+            out.set_value(rfc3339.trim_end_matches('Z').replacen('T', " ", 1));
+            Ok(IsNull::No)
+        }
+    }
+
+    impl FromSql<Timestamp, Sqlite> for Tm {
+        fn from_sql(mut value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Tm> {
+            Ok(::from_iso8601(value.read_text())?.to_utc())
+        }
+    }
+}
@@ -0,0 +1,260 @@
+// Signed spans of time, compatible with `time::Duration` of the crate time 0.1
+
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use core::time;
+
+const NANOS_PER_SEC: i32 = 1_000_000_000;
+
+/// Represents a signed span of time with the precision of nanoseconds
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Default)]
+pub struct Duration {
+    pub(crate) secs: i64,
+    // Always in [0, 999999999], also for the negative durations
+    pub(crate) nanos: i32,
+}
+
+impl Duration {
+    /// The duration of zero
+    pub const ZERO: Duration = Duration { secs: 0, nanos: 0 };
+
+    /// The longest duration, i.e. `i64::MAX` milliseconds as in time 0.1
+    pub const MAX: Duration = Duration::milliseconds(i64::MAX);
+
+    /// The shortest duration, i.e. `-i64::MAX` milliseconds as in time 0.1
+    pub const MIN: Duration = Duration::milliseconds(-i64::MAX);
+
+    /// Returns the duration of zero
+    pub const fn zero() -> Duration {
+        Duration::ZERO
+    }
+
+    /// Returns the longest duration (see `MAX`)
+    pub const fn max_value() -> Duration {
+        Duration::MAX
+    }
+
+    /// Returns the shortest duration (see `MIN`)
+    pub const fn min_value() -> Duration {
+        Duration::MIN
+    }
+
+    /// Creates a new duration of `weeks` weeks, panics if it overflows
+    pub const fn weeks(weeks: i64) -> Duration {
+        Duration::seconds(mul_or_panic(weeks, 7 * 86400))
+    }
+
+    /// Creates a new duration of `days` days, panics if it overflows
+    pub const fn days(days: i64) -> Duration {
+        Duration::seconds(mul_or_panic(days, 86400))
+    }
+
+    /// Creates a new duration of `hours` hours, panics if it overflows
+    pub const fn hours(hours: i64) -> Duration {
+        Duration::seconds(mul_or_panic(hours, 3600))
+    }
+
+    /// Creates a new duration of `minutes` minutes, panics if it overflows
+    pub const fn minutes(minutes: i64) -> Duration {
+        Duration::seconds(mul_or_panic(minutes, 60))
+    }
+
+    /// Creates a new duration of `seconds` seconds
+    pub const fn seconds(seconds: i64) -> Duration {
+        Duration { secs: seconds, nanos: 0 }
+    }
+
+    /// Creates a new duration of `milliseconds` milliseconds
+    pub const fn milliseconds(milliseconds: i64) -> Duration {
+        Duration::from_parts(milliseconds.div_euclid(1000), milliseconds.rem_euclid(1000) as i32 * 1_000_000)
+    }
+
+    /// Creates a new duration of `microseconds` microseconds
+    pub const fn microseconds(microseconds: i64) -> Duration {
+        Duration::from_parts(microseconds.div_euclid(1_000_000), microseconds.rem_euclid(1_000_000) as i32 * 1000)
+    }
+
+    /// Creates a new duration of `nanoseconds` nanoseconds
+    pub const fn nanoseconds(nanoseconds: i64) -> Duration {
+        Duration::from_parts(nanoseconds.div_euclid(NANOS_PER_SEC as i64), nanoseconds.rem_euclid(NANOS_PER_SEC as i64) as i32)
+    }
+
+    // Creates a new duration of `secs` seconds and `nanos` nanoseconds [0, 999999999]
+    pub(crate) const fn from_parts(secs: i64, nanos: i32) -> Duration {
+        Duration { secs, nanos }
+    }
+
+    /// Creates a new duration from `std::time::Duration`, returns `None` if it overflows
+    pub const fn from_std(duration: time::Duration) -> Option<Duration> {
+        if duration.as_secs() > i64::MAX as u64 {
+            return None
+        }
+        Some(Duration::from_parts(duration.as_secs() as i64, duration.subsec_nanos() as i32))
+    }
+
+    /// Converts duration to `std::time::Duration`, returns `None` if it is negative
+    pub const fn to_std(&self) -> Option<time::Duration> {
+        if self.secs < 0 {
+            return None
+        }
+        Some(time::Duration::new(self.secs as u64, self.nanos as u32))
+    }
+
+    /// Returns the number of whole weeks, truncated towards zero
+    pub const fn num_weeks(&self) -> i64 {
+        self.num_seconds() / (7 * 86400)
+    }
+
+    /// Returns the number of whole days, truncated towards zero
+    pub const fn num_days(&self) -> i64 {
+        self.num_seconds() / 86400
+    }
+
+    /// Returns the number of whole hours, truncated towards zero
+    pub const fn num_hours(&self) -> i64 {
+        self.num_seconds() / 3600
+    }
+
+    /// Returns the number of whole minutes, truncated towards zero
+    pub const fn num_minutes(&self) -> i64 {
+        self.num_seconds() / 60
+    }
+
+    /// Returns the number of whole seconds, truncated towards zero
+    pub const fn num_seconds(&self) -> i64 {
+        if self.secs < 0 && self.nanos > 0 {
+            self.secs + 1
+        } else {
+            self.secs
+        }
+    }
+
+    /// Returns the number of whole milliseconds, truncated towards zero
+    pub const fn num_milliseconds(&self) -> i64 {
+        (self.total_nanoseconds() / 1_000_000) as i64
+    }
+
+    /// Returns the number of whole microseconds, truncated towards zero, returns `None` if it
+    /// overflows `i64`
+    pub const fn num_microseconds(&self) -> Option<i64> {
+        to_i64(self.total_nanoseconds() / 1000)
+    }
+
+    /// Returns the number of nanoseconds, returns `None` if it overflows `i64`
+    pub const fn num_nanoseconds(&self) -> Option<i64> {
+        to_i64(self.total_nanoseconds())
+    }
+
+    /// Returns true if duration is zero
+    pub const fn is_zero(&self) -> bool {
+        self.secs == 0 && self.nanos == 0
+    }
+
+    /// Returns the sum of durations, returns `None` if it overflows
+    pub const fn checked_add(&self, other: &Duration) -> Option<Duration> {
+        let (carry, nanos) = match self.nanos + other.nanos {
+            nanos if nanos >= NANOS_PER_SEC => (1, nanos - NANOS_PER_SEC),
+            nanos => (0, nanos),
+        };
+        match self.secs.checked_add(other.secs) {
+            Some(secs) => match secs.checked_add(carry) {
+                Some(secs) => Some(Duration { secs, nanos }),
+                None => None,
+            },
+            None => None,
+        }
+    }
+
+    /// Returns the difference of durations, returns `None` if it overflows
+    pub const fn checked_sub(&self, other: &Duration) -> Option<Duration> {
+        let (borrow, nanos) = match self.nanos - other.nanos {
+            nanos if nanos < 0 => (1, nanos + NANOS_PER_SEC),
+            nanos => (0, nanos),
+        };
+        match self.secs.checked_sub(other.secs) {
+            Some(secs) => match secs.checked_sub(borrow) {
+                Some(secs) => Some(Duration { secs, nanos }),
+                None => None,
+            },
+            None => None,
+        }
+    }
+
+    // Returns the number of nanoseconds, which always fits in `i128`
+    const fn total_nanoseconds(&self) -> i128 {
+        self.secs as i128 * NANOS_PER_SEC as i128 + self.nanos as i128
+    }
+
+    // Creates a new duration of `nanoseconds` nanoseconds, panics if it overflows
+    fn from_total_nanoseconds(nanoseconds: i128) -> Duration {
+        let secs = nanoseconds.div_euclid(NANOS_PER_SEC as i128);
+        assert!(secs >= i64::MIN as i128 && secs <= i64::MAX as i128, "duration out of bounds");
+        Duration::from_parts(secs as i64, nanoseconds.rem_euclid(NANOS_PER_SEC as i128) as i32)
+    }
+}
+
+const fn mul_or_panic(value: i64, factor: i64) -> i64 {
+    match value.checked_mul(factor) {
+        Some(value) => value,
+        None => panic!("duration out of bounds"),
+    }
+}
+
+const fn to_i64(value: i128) -> Option<i64> {
+    if value < i64::MIN as i128 || value > i64::MAX as i128 {
+        return None
+    }
+    Some(value as i64)
+}
+
+impl Neg for Duration {
+    type Output = Duration;
+
+    fn neg(self) -> Duration {
+        Duration::ZERO.checked_sub(&self).expect("duration out of bounds")
+    }
+}
+
+impl Add for Duration {
+    type Output = Duration;
+
+    fn add(self, other: Duration) -> Duration {
+        self.checked_add(&other).expect("duration out of bounds")
+    }
+}
+
+impl Sub for Duration {
+    type Output = Duration;
+
+    fn sub(self, other: Duration) -> Duration {
+        self.checked_sub(&other).expect("duration out of bounds")
+    }
+}
+
+impl AddAssign for Duration {
+    fn add_assign(&mut self, other: Duration) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Duration {
+    fn sub_assign(&mut self, other: Duration) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i32> for Duration {
+    type Output = Duration;
+
+    fn mul(self, rhs: i32) -> Duration {
+        Duration::from_total_nanoseconds(self.total_nanoseconds() * rhs as i128)
+    }
+}
+
+// Truncates the result towards zero
+impl Div<i32> for Duration {
+    type Output = Duration;
+
+    fn div(self, rhs: i32) -> Duration {
+        Duration::from_total_nanoseconds(self.total_nanoseconds() / rhs as i128)
+    }
+}
@@ -0,0 +1,34 @@
+//! Provides the well-known anchors of calendars as constants.
+
+use Tm;
+
+/// The Julian day number of the Persian epoch (1 Farvardin 1 AP, i.e. March 18, 622 of Julian calendar)
+pub const PERSIAN_EPOCH_JDN: i32 = 1948320;
+
+/// The Julian day number of the Unix epoch (January 1, 1970, i.e. 11 Dey 1348)
+pub const UNIX_EPOCH_JDN: i32 = 2440588;
+
+/// The Julian day number of the day 0 of Modified Julian Date (November 17, 1858)
+pub const MJD_EPOCH_JDN: i32 = 2400001;
+
+/// The Julian day number of the day 0 of Rata Die (December 31, 0 of proleptic Gregorian calendar)
+pub const RATA_DIE_EPOCH_JDN: i32 = 1721425;
+
+/// The Julian day number of the epoch of Windows `FILETIME` (January 1, 1601)
+pub const WINDOWS_EPOCH_JDN: i32 = 2305814;
+
+/// The Julian day number of the first day of Gregorian calendar (October 15, 1582), where the
+/// earlier days are converted to Julian calendar
+pub const GREGORIAN_REFORM_JDN: i32 = 2299161;
+
+/// The Persian epoch (1 Farvardin 1 AP, 00:00:00 UTC)
+pub const PERSIAN_EPOCH: Tm = match ::from_persian_date(1, 0, 1) {
+    Some(tm) => tm,
+    None => unreachable!(),
+};
+
+/// The Unix epoch (11 Dey 1348, 00:00:00 UTC)
+pub const UNIX_EPOCH: Tm = match ::from_jdn(UNIX_EPOCH_JDN) {
+    Some(tm) => tm,
+    None => unreachable!(),
+};
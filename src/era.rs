@@ -0,0 +1,58 @@
+// Eras of Persian calendar
+
+use core::fmt;
+
+/// Represents an era of Persian calendar, where the year 1 AP is preceded by the year 1 BP
+///
+/// The years of `ptime::Tm` use the astronomical numbering, i.e. the year 0 is 1 BP, the year -1
+/// is 2 BP and so on.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum Era {
+    /// Before the Persian epoch (years 0 and earlier)
+    BP,
+    /// Anno Persico, i.e. since the Persian epoch (years 1 and later)
+    AP,
+}
+
+impl Era {
+    /// Returns the era of the astronomical `year`
+    pub const fn of_year(year: i32) -> Era {
+        if year > 0 {
+            Era::AP
+        } else {
+            Era::BP
+        }
+    }
+
+    /// Returns the year of era [1, ..] of the astronomical `year`
+    pub const fn year_of_era(year: i32) -> i32 {
+        if year > 0 {
+            year
+        } else {
+            1 - year
+        }
+    }
+
+    /// Returns the astronomical year of the year of era [1, ..]
+    pub const fn to_year(&self, year_of_era: i32) -> i32 {
+        match *self {
+            Era::AP => year_of_era,
+            Era::BP => 1 - year_of_era,
+        }
+    }
+
+    /// Returns the abbreviation of era (i.e. AP or BP)
+    pub const fn abbreviation(&self) -> &'static str {
+        match *self {
+            Era::AP => "AP",
+            Era::BP => "BP",
+        }
+    }
+}
+
+impl fmt::Display for Era {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.abbreviation())
+    }
+}
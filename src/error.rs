@@ -0,0 +1,38 @@
+// Errors of creating time from its components
+
+use core::error::Error;
+use core::fmt;
+use Component;
+
+/// Represents the reason of failure in creating time from its components
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum DateError {
+    /// The year is out of the supported range (see `MIN_YEAR` and `MAX_YEAR`)
+    YearOutOfRange { year: i32 },
+
+    /// The month is out of [0, 11]
+    MonthOutOfRange { month: i32 },
+
+    /// The day is out of [1, `max`], where `max` is the number of days of month
+    DayOutOfRange { day: i32, max: i32 },
+
+    /// The `component` of time of day is out of its range (e.g. the hour of 24)
+    InvalidTime { component: Component },
+
+    /// The `component` required to create time is not provided
+    Missing { component: Component },
+}
+
+impl fmt::Display for DateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DateError::YearOutOfRange { year } => write!(f, "year {} out of supported range", year),
+            DateError::MonthOutOfRange { month } => write!(f, "month {} out of range [0, 11]", month),
+            DateError::DayOutOfRange { day, max } => write!(f, "day {} out of range [1, {}]", day, max),
+            DateError::InvalidTime { component } => write!(f, "invalid {}", component),
+            DateError::Missing { component } => write!(f, "missing {}", component),
+        }
+    }
+}
+
+impl Error for DateError {}
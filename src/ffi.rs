@@ -0,0 +1,208 @@
+//! C interface of the conversions, the formatting and the parsing, enabled by the `ffi` feature,
+//! where `Tm` has the layout of C and the header can be generated by cbindgen (see
+//! `cbindgen.toml`), e.g. build the static library with
+//! `cargo rustc --release --features ffi --crate-type staticlib`.
+//!
+//! The functions return `Status`, write the results through the out pointers only on success and
+//! validate the `Tm` passed by caller, recomputing its weekday and day of year.
+
+use core::ffi::{c_char, CStr};
+use core::ptr;
+use {Timespec, Tm};
+
+/// Represents the result of a function
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum Status {
+    /// The function succeeded
+    Ok = 0,
+    /// A required pointer is null
+    NullPointer = 1,
+    /// A string is not valid UTF-8
+    InvalidUtf8 = 2,
+    /// The date, the time of day or the offset is invalid or out of the supported range
+    InvalidTime = 3,
+    /// The string does not match the format
+    ParseFailed = 4,
+}
+
+/// Writes the Persian time of the Gregorian date and time of day (the month is in [0, 11]) to
+/// `out`, where the offset is UTC
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes of `Tm`.
+#[no_mangle]
+pub unsafe extern "C" fn ptime_from_gregorian(g_year: i32, g_month: i32, g_day: i32, hour: i32, minute: i32, second: i32, nanosecond: i32, out: *mut Tm) -> Status {
+    match ::from_gregorian_components(g_year, g_month, g_day, hour, minute, second, nanosecond) {
+        Some(tm) => write(out, tm),
+        None => Status::InvalidTime,
+    }
+}
+
+/// Writes the Persian time of the Persian date and time of day (the month is in [0, 11]) to
+/// `out`, where the offset is UTC
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes of `Tm`.
+#[no_mangle]
+pub unsafe extern "C" fn ptime_from_persian(p_year: i32, p_month: i32, p_day: i32, hour: i32, minute: i32, second: i32, nanosecond: i32, out: *mut Tm) -> Status {
+    match ::from_persian_components(p_year, p_month, p_day, hour, minute, second, nanosecond) {
+        Some(tm) => write(out, tm),
+        None => Status::InvalidTime,
+    }
+}
+
+/// Writes the Persian time of the number of seconds since January 1, 1970 UTC in the offset of
+/// `utcoff` seconds east of UTC to `out`
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes of `Tm`.
+#[no_mangle]
+pub unsafe extern "C" fn ptime_at_offset(sec: i64, nsec: i32, utcoff: i32, out: *mut Tm) -> Status {
+    if !(0..=999_999_999).contains(&nsec) || !(-86399..=86399).contains(&utcoff) {
+        return Status::InvalidTime
+    }
+    let utc = Timespec::new(sec, nsec);
+    if utc < Tm::MIN.to_timespec() || utc > Tm::MAX.to_timespec() {
+        return Status::InvalidTime
+    }
+    // The wall clock in the offset may cross the bounds of the supported range
+    let tm = ::at_utc(utc).with_offset(utcoff);
+    if ::validate::persian_date(tm.tm_year, tm.tm_mon, tm.tm_mday).is_err() {
+        return Status::InvalidTime
+    }
+    write(out, tm)
+}
+
+/// Writes the Gregorian date of `tm` (the month is in [0, 11]) to `g_year`, `g_month` and `g_day`
+///
+/// # Safety
+///
+/// `tm` must be null or valid for reads of `Tm`, and the out pointers must be null or valid for
+/// writes of `int32_t`.
+#[no_mangle]
+pub unsafe extern "C" fn ptime_to_gregorian(tm: *const Tm, g_year: *mut i32, g_month: *mut i32, g_day: *mut i32) -> Status {
+    let tm = match read(tm) {
+        Ok(tm) => tm,
+        Err(status) => return status,
+    };
+    if g_year.is_null() || g_month.is_null() || g_day.is_null() {
+        return Status::NullPointer
+    }
+    let (year, month, day) = tm.to_gregorian_date();
+    ptr::write(g_year, year);
+    ptr::write(g_month, month);
+    ptr::write(g_day, day);
+    Status::Ok
+}
+
+/// Writes the number of seconds since January 1, 1970 UTC and the nanosecond of `tm` to `sec` and
+/// `nsec`
+///
+/// # Safety
+///
+/// `tm` must be null or valid for reads of `Tm`, and the out pointers must be null or valid for
+/// writes of their types.
+#[no_mangle]
+pub unsafe extern "C" fn ptime_to_timestamp(tm: *const Tm, sec: *mut i64, nsec: *mut i32) -> Status {
+    let tm = match read(tm) {
+        Ok(tm) => tm,
+        Err(status) => return status,
+    };
+    if sec.is_null() || nsec.is_null() {
+        return Status::NullPointer
+    }
+    let clock = tm.to_timespec();
+    ptr::write(sec, clock.sec);
+    ptr::write(nsec, clock.nsec);
+    Status::Ok
+}
+
+/// Formats `tm` with the UTF-8 `format` (see `Tm::to_string`) into `buf` of `len` bytes, returns
+/// the length of formatted string in bytes without the terminating null, or the negated `Status`
+/// on failure
+///
+/// The string is truncated to `len - 1` bytes (possibly in the middle of a character) and
+/// terminated with null if `len` is not zero, so the string is complete if the returned length is
+/// less than `len`, like `snprintf`.
+///
+/// # Safety
+///
+/// `tm` must be null or valid for reads of `Tm`, `format` must be null or a null-terminated
+/// string, and `buf` must be valid for writes of `len` bytes (it may be null if `len` is zero).
+#[no_mangle]
+pub unsafe extern "C" fn ptime_format(tm: *const Tm, format: *const c_char, buf: *mut c_char, len: usize) -> isize {
+    let tm = match read(tm) {
+        Ok(tm) => tm,
+        Err(status) => return -(status as isize),
+    };
+    let format = match read_str(format) {
+        Ok(format) => format,
+        Err(status) => return -(status as isize),
+    };
+    if buf.is_null() && len != 0 {
+        return -(Status::NullPointer as isize)
+    }
+
+    let formatted = tm.to_string(format);
+    if len != 0 {
+        let count = formatted.len().min(len - 1);
+        ptr::copy_nonoverlapping(formatted.as_ptr() as *const c_char, buf, count);
+        ptr::write(buf.add(count), 0);
+    }
+    formatted.len() as isize
+}
+
+/// Parses the UTF-8 `s` formatted with the UTF-8 `format` (see `strptime`) and writes the Persian
+/// time to `out`
+///
+/// # Safety
+///
+/// `s` and `format` must be null or null-terminated strings, and `out` must be null or valid for
+/// writes of `Tm`.
+#[no_mangle]
+pub unsafe extern "C" fn ptime_parse(s: *const c_char, format: *const c_char, out: *mut Tm) -> Status {
+    let (s, format) = match (read_str(s), read_str(format)) {
+        (Ok(s), Ok(format)) => (s, format),
+        (Err(status), _) | (_, Err(status)) => return status,
+    };
+    match ::strptime(s, format) {
+        Ok(tm) => write(out, tm),
+        Err(_) => Status::ParseFailed,
+    }
+}
+
+// Writes `tm` to `out`
+unsafe fn write(out: *mut Tm, tm: Tm) -> Status {
+    if out.is_null() {
+        return Status::NullPointer
+    }
+    ptr::write(out, tm);
+    Status::Ok
+}
+
+// Reads and validates the time passed by caller
+unsafe fn read(tm: *const Tm) -> Result<Tm, Status> {
+    if tm.is_null() {
+        return Err(Status::NullPointer)
+    }
+    let tm = ptr::read(tm);
+    if !(-86399..=86399).contains(&tm.tm_utcoff) {
+        return Err(Status::InvalidTime)
+    }
+    match ::from_persian_components(tm.tm_year, tm.tm_mon, tm.tm_mday, tm.tm_hour, tm.tm_min, tm.tm_sec, tm.tm_nsec) {
+        Some(valid) => Ok(Tm { tm_isdst: tm.tm_isdst, tm_utcoff: tm.tm_utcoff, ..valid }),
+        None => Err(Status::InvalidTime),
+    }
+}
+
+// Reads the null-terminated UTF-8 string passed by caller
+unsafe fn read_str<'a>(s: *const c_char) -> Result<&'a str, Status> {
+    if s.is_null() {
+        return Err(Status::NullPointer)
+    }
+    CStr::from_ptr(s).to_str().map_err(|_| Status::InvalidUtf8)
+}
@@ -0,0 +1,15 @@
+// Formatting into `String` of the crate heapless
+
+use heapless::String;
+use Tm;
+
+impl Tm {
+    /// Returns the formatted representation of time as `heapless::String` of at most `N` bytes
+    /// (see `to_string` for the list of supported tokens), returns `None` if it is longer, which
+    /// never happens if `N` is at least `ptime::max_formatted_len(format)`
+    pub fn to_heapless_string<const N: usize>(&self, format: &str) -> Option<String<N>> {
+        let mut result = String::new();
+        self.write_to(&mut result, format).ok()?;
+        Some(result)
+    }
+}
@@ -0,0 +1,281 @@
+//! Provides the official public holidays of Iran.
+//!
+//! The holidays are those of the current law regardless of the year, e.g. 14 and 15 Khordad are
+//! also returned for the years before 1368.
+//!
+//! The dates of the lunar holidays (e.g. Eid al-Fitr and Ashura) are computed by the tabular
+//! Islamic calendar, which may differ by a day or two from the months started by sighting the
+//! moon, so the observed first days of Hijri months can be set by `HijriMonthStarts`.
+//!
+//! The holidays of other jurisdictions or organizations can be provided by implementing
+//! `HolidayCalendar`, or by `Custom` layered on `Iran`, e.g. `(Iran::new(), company)`.
+
+use alloc::vec::Vec;
+use {PersianDate, Tm};
+
+// The Julian day number of 1 Muharram 1 AH of the tabular Islamic calendar (July 16, 622 of
+// Julian calendar)
+const HIJRI_EPOCH_JDN: i64 = 1948440;
+
+/// Represents a public holiday on a date
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Holiday {
+    date: PersianDate,
+    name: &'static str,
+    lunar: bool,
+}
+
+impl Holiday {
+    /// Creates a new holiday named `name` on `date`
+    pub const fn new(date: PersianDate, name: &'static str) -> Holiday {
+        Holiday { date, name, lunar: false }
+    }
+
+    /// Returns the date of holiday
+    pub const fn date(&self) -> PersianDate {
+        self.date
+    }
+
+    /// Returns the Persian name of holiday (e.g. عید نوروز)
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns true if the holiday is on a date of Hijri calendar, whose Persian date changes
+    /// every year
+    pub const fn is_lunar(&self) -> bool {
+        self.lunar
+    }
+}
+
+/// Represents the first days of Hijri months as observed (e.g. as announced by the calendar
+/// center of Iran), overriding the tabular Islamic calendar for the lunar holidays
+#[derive(Clone, PartialEq, Eq, Debug, Default, Hash)]
+pub struct HijriMonthStarts {
+    starts: Vec<(i32, i32, i64)>,
+}
+
+impl HijriMonthStarts {
+    /// Creates a new set of month starts, where all months follow the tabular calendar
+    pub fn new() -> HijriMonthStarts {
+        HijriMonthStarts::default()
+    }
+
+    /// Returns the set where the Hijri month [1, 12] of the Hijri year starts on `first_day`
+    pub fn with_month_start(mut self, year: i32, month: i32, first_day: PersianDate) -> HijriMonthStarts {
+        let jdn = first_day.to_tm().to_jdn() as i64;
+        self.starts.retain(|&(y, m, _)| (y, m) != (year, month));
+        self.starts.push((year, month, jdn));
+        self
+    }
+
+    // Returns the Julian day number of the first day of the Hijri month [1, 12]
+    fn month_start(&self, year: i32, month: i32) -> i64 {
+        match self.starts.iter().find(|&&(y, m, _)| (y, m) == (year, month)) {
+            Some(&(_, _, jdn)) => jdn,
+            None => get_hijri_jdn(year, month, 1),
+        }
+    }
+}
+
+/// Represents a set of holidays, e.g. of a jurisdiction or an organization
+pub trait HolidayCalendar {
+    /// Returns the holidays of the Persian year in the order of date
+    fn holidays(&self, year: i32) -> Vec<Holiday>;
+
+    /// Returns true if the date of `tm` is a holiday, regardless of the weekend
+    fn is_holiday(&self, tm: &Tm) -> bool {
+        let date = PersianDate::from_tm(tm);
+        self.holidays(tm.tm_year).iter().any(|holiday| holiday.date() == date)
+    }
+}
+
+/// The official public holidays of Iran (see `holidays_in_year`)
+#[derive(Clone, PartialEq, Eq, Debug, Default, Hash)]
+pub struct Iran {
+    starts: HijriMonthStarts,
+}
+
+impl Iran {
+    /// Creates the calendar whose lunar holidays follow the tabular Islamic calendar
+    pub fn new() -> Iran {
+        Iran::default()
+    }
+
+    /// Creates the calendar whose lunar holidays follow the observed Hijri months of `starts`
+    pub fn observed(starts: HijriMonthStarts) -> Iran {
+        Iran { starts }
+    }
+}
+
+impl HolidayCalendar for Iran {
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        holidays_in_year_observed(year, &self.starts)
+    }
+
+    fn is_holiday(&self, tm: &Tm) -> bool {
+        is_public_holiday_observed(tm, &self.starts)
+    }
+}
+
+/// Represents the holidays recurring every year or on single dates, e.g. of an organization
+#[derive(Clone, PartialEq, Eq, Debug, Default, Hash)]
+pub struct Custom {
+    yearly: Vec<(i32, i32, &'static str)>,
+    dates: Vec<Holiday>,
+}
+
+impl Custom {
+    /// Creates a new calendar without holidays
+    pub fn new() -> Custom {
+        Custom::default()
+    }
+
+    /// Returns the calendar with the holiday named `name` on the month since Farvardin [0, 11] and
+    /// the day of every year, except the years where the date does not exist (e.g. Esfand 30)
+    pub fn with_yearly(mut self, month: i32, day: i32, name: &'static str) -> Custom {
+        self.yearly.push((month, day, name));
+        self
+    }
+
+    /// Returns the calendar with `holiday` on its date only
+    pub fn with_holiday(mut self, holiday: Holiday) -> Custom {
+        self.dates.push(holiday);
+        self
+    }
+}
+
+impl HolidayCalendar for Custom {
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        let mut holidays: Vec<Holiday> = self.yearly.iter()
+            .filter_map(|&(month, day, name)| Some(Holiday::new(PersianDate::new(year, month, day)?, name)))
+            .chain(self.dates.iter().filter(|holiday| holiday.date.year() == year).cloned())
+            .collect();
+        holidays.sort_by_key(|holiday| holiday.date);
+        holidays
+    }
+}
+
+// Layers the holidays of both calendars
+impl<A: HolidayCalendar, B: HolidayCalendar> HolidayCalendar for (A, B) {
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        let mut holidays = self.0.holidays(year);
+        holidays.extend(self.1.holidays(year));
+        holidays.sort_by_key(|holiday| holiday.date);
+        holidays
+    }
+
+    fn is_holiday(&self, tm: &Tm) -> bool {
+        self.0.is_holiday(tm) || self.1.is_holiday(tm)
+    }
+}
+
+impl<C: HolidayCalendar + ?Sized> HolidayCalendar for &C {
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        (**self).holidays(year)
+    }
+
+    fn is_holiday(&self, tm: &Tm) -> bool {
+        (**self).is_holiday(tm)
+    }
+}
+
+// The month since Farvardin, the day and the name of the holidays of Solar Hijri calendar in the
+// order of the year
+static FIXED_HOLIDAYS: [(i32, i32, &str); 10] = [
+    (0, 1, "عید نوروز"),
+    (0, 2, "عید نوروز"),
+    (0, 3, "عید نوروز"),
+    (0, 4, "عید نوروز"),
+    (0, 12, "روز جمهوری اسلامی"),
+    (0, 13, "روز طبیعت"),
+    (2, 14, "رحلت امام خمینی"),
+    (2, 15, "قیام ۱۵ خرداد"),
+    (10, 22, "پیروزی انقلاب اسلامی"),
+    (11, 29, "روز ملی شدن صنعت نفت"),
+];
+
+// The Hijri month [1, 12], the day and the name of the holidays of Hijri calendar in the order of
+// the year, where the day 0 is the last day of the previous month
+static LUNAR_HOLIDAYS: [(i32, i32, &str); 17] = [
+    (1, 9, "تاسوعای حسینی"),
+    (1, 10, "عاشورای حسینی"),
+    (2, 20, "اربعین حسینی"),
+    (2, 28, "رحلت حضرت رسول اکرم و شهادت امام حسن مجتبی"),
+    (3, 0, "شهادت امام رضا"),
+    (3, 8, "شهادت امام حسن عسکری"),
+    (3, 17, "میلاد حضرت رسول اکرم و امام جعفر صادق"),
+    (6, 3, "شهادت حضرت فاطمه زهرا"),
+    (7, 13, "ولادت امام علی"),
+    (7, 27, "مبعث حضرت رسول اکرم"),
+    (8, 15, "ولادت حضرت قائم"),
+    (9, 21, "شهادت امام علی"),
+    (10, 1, "عید سعید فطر"),
+    (10, 2, "تعطیل به مناسبت عید سعید فطر"),
+    (10, 25, "شهادت امام جعفر صادق"),
+    (12, 10, "عید سعید قربان"),
+    (12, 18, "عید سعید غدیر خم"),
+];
+
+/// Returns true if the date of `tm` is a public holiday, regardless of the weekend, where the
+/// lunar holidays follow the tabular Islamic calendar
+pub fn is_public_holiday(tm: &Tm) -> bool {
+    is_public_holiday_observed(tm, &HijriMonthStarts::new())
+}
+
+/// Returns true if the date of `tm` is a public holiday, regardless of the weekend, where the
+/// lunar holidays follow the observed Hijri months of `starts`
+pub fn is_public_holiday_observed(tm: &Tm, starts: &HijriMonthStarts) -> bool {
+    let jdn = tm.to_jdn() as i64;
+    FIXED_HOLIDAYS.iter().any(|&(month, day, _)| tm.tm_mon == month && tm.tm_mday == day) ||
+        lunar_holidays_between(jdn, jdn, starts).next().is_some()
+}
+
+/// Returns the public holidays of the Persian year in the order of date, which is empty if year
+/// is out of the supported range, where the lunar holidays follow the tabular Islamic calendar
+pub fn holidays_in_year(year: i32) -> Vec<Holiday> {
+    holidays_in_year_observed(year, &HijriMonthStarts::new())
+}
+
+/// Returns the public holidays of the Persian year in the order of date, which is empty if year
+/// is out of the supported range, where the lunar holidays follow the observed Hijri months of
+/// `starts`
+pub fn holidays_in_year_observed(year: i32, starts: &HijriMonthStarts) -> Vec<Holiday> {
+    let first = match PersianDate::new(year, 0, 1) {
+        Some(nowruz) => nowruz.to_tm().to_jdn() as i64,
+        None => return Vec::new(),
+    };
+    let last = first + ::days_in_year(year) as i64 - 1;
+
+    let mut holidays: Vec<Holiday> = FIXED_HOLIDAYS.iter()
+        .map(|&(month, day, name)| Holiday::new(PersianDate::new_unchecked(year, month, day), name))
+        .collect();
+    holidays.extend(lunar_holidays_between(first, last, starts).map(|(jdn, name)| Holiday {
+        date: PersianDate::from_tm(&::from_jdn(jdn as i32).expect("day in supported range")),
+        name,
+        lunar: true,
+    }));
+    holidays.sort_by_key(|holiday| holiday.date);
+    holidays
+}
+
+// Returns the Julian day numbers and the names of the lunar holidays in [`first`, `last`]
+fn lunar_holidays_between(first: i64, last: i64, starts: &HijriMonthStarts) -> impl Iterator<Item = (i64, &'static str)> + '_ {
+    // The observed months may start a few days apart from the tabular calendar
+    let years = get_hijri_year(first) - 1..=get_hijri_year(last) + 1;
+    years
+        .flat_map(move |year| LUNAR_HOLIDAYS.iter().map(move |&(month, day, name)| (starts.month_start(year, month) + day as i64 - 1, name)))
+        .filter(move |&(jdn, _)| jdn >= first && jdn <= last)
+}
+
+// Returns the Julian day number of the date of the tabular Islamic calendar, where the years 2,
+// 5, 7, 10, 13, 16, 18, 21, 24, 26 and 29 of each 30 years are leap years
+fn get_hijri_jdn(year: i32, month: i32, day: i32) -> i64 {
+    let (year, month, day) = (year as i64, month as i64, day as i64);
+    HIJRI_EPOCH_JDN - 1 + day + (59 * (month - 1) + 1) / 2 + 354 * (year - 1) + (3 + 11 * year).div_euclid(30)
+}
+
+// Returns the Hijri year of the Julian day number in the tabular Islamic calendar
+fn get_hijri_year(jdn: i64) -> i32 {
+    (30 * (jdn - HIJRI_EPOCH_JDN) + 10646).div_euclid(10631) as i32
+}
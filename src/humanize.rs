@@ -0,0 +1,55 @@
+//! Provides the Persian representation of relative times and durations.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use {locale, Duration};
+
+/// Returns the Persian phrase describing `duration` relative to now (e.g. ۳ روز پیش)
+pub fn relative(duration: Duration) -> String {
+    let seconds = duration.num_seconds();
+    let suffix = if seconds < 0 { "پیش" } else { "دیگر" };
+    let seconds = seconds.abs();
+
+    let (count, unit) = if seconds < 60 {
+        return format!("چند لحظه {}", suffix);
+    } else if seconds < 3600 {
+        (seconds / 60, "دقیقه")
+    } else if seconds < 86400 {
+        (seconds / 3600, "ساعت")
+    } else if seconds < 7 * 86400 {
+        (seconds / 86400, "روز")
+    } else if seconds < 30 * 86400 {
+        (seconds / (7 * 86400), "هفته")
+    } else if seconds < 365 * 86400 {
+        (seconds / (30 * 86400), "ماه")
+    } else {
+        (seconds / (365 * 86400), "سال")
+    };
+
+    format!("{} {} {}", locale::to_persian_digits(&count.to_string()), unit, suffix)
+}
+
+/// Returns the Persian representation of `duration` (e.g. ۲ ساعت و ۱۵ دقیقه)
+pub fn duration(duration: Duration) -> String {
+    let seconds = duration.num_seconds();
+    let mut parts = Vec::new();
+    let mut rest = seconds.abs();
+
+    for &(size, unit) in [(86400, "روز"), (3600, "ساعت"), (60, "دقیقه"), (1, "ثانیه")].iter() {
+        if rest >= size {
+            parts.push(format!("{} {}", locale::to_persian_digits(&(rest / size).to_string()), unit));
+            rest %= size;
+        }
+    }
+
+    if parts.is_empty() {
+        return "۰ ثانیه".to_string();
+    }
+
+    let result = parts.join(" و ");
+    if seconds < 0 {
+        format!("منفی {}", result)
+    } else {
+        result
+    }
+}
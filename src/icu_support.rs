@@ -0,0 +1,27 @@
+// Conversions from and to the dates of the crate icu_calendar, enabled by the `icu_calendar` feature
+
+use icu_calendar::cal::Persian;
+use icu_calendar::{AsCalendar, Date, Iso, RangeError};
+use std::convert::TryFrom;
+use {DateError, Tm};
+
+// Creates the Persian date at midnight in UTC from the date of any calendar, converting it
+// through the ISO calendar
+impl<A: AsCalendar> TryFrom<Date<A>> for Tm {
+    type Error = DateError;
+
+    fn try_from(date: Date<A>) -> Result<Tm, DateError> {
+        let iso = date.to_calendar(Iso);
+        ::try_from_gregorian_date(iso.year().extended_year(), iso.month().ordinal as i32 - 1, iso.day_of_month().0 as i32)
+    }
+}
+
+// Returns the Persian date of ICU4X, ignoring the time of day and the offset, where ICU4X corrects
+// the 33-year rule from 1502 (e.g. 30 Esfand 1502 does not exist there)
+impl TryFrom<Tm> for Date<Persian> {
+    type Error = RangeError;
+
+    fn try_from(tm: Tm) -> Result<Date<Persian>, RangeError> {
+        Date::try_new_persian(tm.tm_year, tm.tm_mon as u8 + 1, tm.tm_mday as u8)
+    }
+}
@@ -0,0 +1,55 @@
+//! Provides the iterators over the weeks, months and years of Persian calendar.
+
+use {EndOfMonth, Tm, Unit, Weekday};
+
+/// Iterates over the starts of consecutive weeks, months or years at midnight, keeping the offset
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Periods {
+    next: Option<Tm>,
+    last_jdn: i32,
+    unit: Unit,
+}
+
+impl Iterator for Periods {
+    type Item = Tm;
+
+    fn next(&mut self) -> Option<Tm> {
+        let tm = self.next.filter(|tm| tm.to_jdn() <= self.last_jdn)?;
+        self.next = match self.unit {
+            Unit::Week => tm.checked_add_days(7),
+            Unit::Year => tm.checked_add_years(1, EndOfMonth::Clamp),
+            _ => tm.checked_add_months(1, EndOfMonth::Clamp),
+        };
+        Some(tm)
+    }
+}
+
+/// Returns the iterator over the first days of months from the month of `from` to the month of
+/// `to` inclusive (e.g. 1 Mehr, 1 Aban and 1 Azar of 15 Mehr to 10 Azar)
+pub fn months_between(from: &Tm, to: &Tm) -> Periods {
+    Periods { next: Some(from.start_of(Unit::Month)), last_jdn: to.to_jdn(), unit: Unit::Month }
+}
+
+/// Returns the iterator over the first days of years from the year of `from` to the year of `to`
+/// inclusive (i.e. 1 Farvardin of each year)
+pub fn years_between(from: &Tm, to: &Tm) -> Periods {
+    Periods { next: Some(from.start_of(Unit::Year)), last_jdn: to.to_jdn(), unit: Unit::Year }
+}
+
+/// Returns the iterator over the Shanbehs starting the weeks from the week of `from` to the week
+/// of `to` inclusive
+pub fn weeks_between(from: &Tm, to: &Tm) -> Periods {
+    weeks_starting_on(from, to, Weekday::Shanbeh)
+}
+
+/// Returns the iterator over the days starting the weeks from the week of `from` to the week of
+/// `to` inclusive, where weeks start on `first_weekday` (e.g. Doshanbeh)
+pub fn weeks_starting_on(from: &Tm, to: &Tm, first_weekday: Weekday) -> Periods {
+    let day = from.start_of(Unit::Day);
+    let first = if day.weekday() == first_weekday {
+        day
+    } else {
+        day.previous_occurrence_of(first_weekday)
+    };
+    Periods { next: Some(first), last_jdn: to.to_jdn(), unit: Unit::Week }
+}
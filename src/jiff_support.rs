@@ -0,0 +1,73 @@
+// Conversions from and to the types of the crate jiff, enabled by the `jiff` feature
+
+use jiff::civil::{Date, DateTime, Time};
+use jiff::tz::{self, TimeZone};
+use jiff::{Error, Timestamp, Zoned};
+use std::convert::TryFrom;
+use {DateError, Tm};
+
+// Creates the Persian date at midnight in UTC
+impl TryFrom<Date> for Tm {
+    type Error = DateError;
+
+    fn try_from(date: Date) -> Result<Tm, DateError> {
+        ::try_from_gregorian_date(date.year() as i32, date.month() as i32 - 1, date.day() as i32)
+    }
+}
+
+// Creates the Persian time in UTC
+impl TryFrom<DateTime> for Tm {
+    type Error = DateError;
+
+    fn try_from(date_time: DateTime) -> Result<Tm, DateError> {
+        ::try_from_gregorian_components(date_time.year() as i32,
+                                        date_time.month() as i32 - 1,
+                                        date_time.day() as i32,
+                                        date_time.hour() as i32,
+                                        date_time.minute() as i32,
+                                        date_time.second() as i32,
+                                        date_time.subsec_nanosecond())
+    }
+}
+
+// Keeps the wall clock and the offset, where the timezone is not kept
+impl TryFrom<Zoned> for Tm {
+    type Error = DateError;
+
+    fn try_from(zoned: Zoned) -> Result<Tm, DateError> {
+        let tm = Tm::try_from(zoned.datetime())?;
+        Ok(Tm { tm_utcoff: zoned.offset().seconds(), ..tm })
+    }
+}
+
+// Returns the Gregorian date, ignoring the time of day and the offset, which fails if the year
+// is out of the range supported by jiff
+impl TryFrom<Tm> for Date {
+    type Error = Error;
+
+    fn try_from(tm: Tm) -> Result<Date, Error> {
+        let (year, month, day) = tm.to_gregorian_date();
+        Date::new(year as i16, month as i8 + 1, day as i8)
+    }
+}
+
+// Returns the Gregorian date and the wall clock, ignoring the offset
+impl TryFrom<Tm> for DateTime {
+    type Error = Error;
+
+    fn try_from(tm: Tm) -> Result<DateTime, Error> {
+        let time = Time::new(tm.tm_hour as i8, tm.tm_min as i8, tm.tm_sec as i8, tm.tm_nsec)?;
+        Ok(Date::try_from(tm)?.to_datetime(time))
+    }
+}
+
+// Returns the same instant in the fixed offset of `tm`
+impl TryFrom<Tm> for Zoned {
+    type Error = Error;
+
+    fn try_from(tm: Tm) -> Result<Zoned, Error> {
+        let clock = tm.to_timespec();
+        let offset = tz::Offset::from_seconds(tm.tm_utcoff)?;
+        Ok(Timestamp::new(clock.sec, clock.nsec)?.to_zoned(TimeZone::fixed(offset)))
+    }
+}
@@ -2,7 +2,8 @@
 
 //! Provides functionality for conversion among Persian (Solar Hijri) and Gregorian calendars.
 //! A Julian calendar is used as an interface for all conversions.
-//! The crate name is ptime and it is compatible with the crate [time](https://crates.io/crates/time).
+//! The crate name is ptime and the API of the crate [time](https://crates.io/crates/time) 0.1 is
+//! available behind the `compat` feature.
 //! This source code is licensed under MIT license that can be found in the LICENSE file.
 //!
 //! # Example
@@ -17,16 +18,184 @@
 //!     assert_eq!(p_tm.tm_mday, 2);
 //! }
 //! ```
-
+//!
+//! Without the default `std` feature, the crate is `no_std` and depends on `alloc` for the
+//! formatting, where the clock and the local timezone (e.g. `now` and `at`) are unavailable.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[macro_use]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate core;
+#[cfg(feature = "arbitrary")]
+extern crate arbitrary;
+#[cfg(feature = "bson")]
+extern crate bson;
+#[cfg(feature = "postgres-types")]
+extern crate bytes;
+#[cfg(feature = "chrono")]
+extern crate chrono;
+#[cfg(feature = "diesel")]
+extern crate diesel;
+#[cfg(feature = "heapless")]
+extern crate heapless;
+#[cfg(feature = "icu_calendar")]
+extern crate icu_calendar;
+#[cfg(feature = "jiff")]
+extern crate jiff;
+#[cfg(feature = "wasm")]
+extern crate js_sys;
+#[cfg(feature = "compat")]
 extern crate time;
-
-use std::cmp::Ordering;
-use std::ops::{Add, Sub};
-use std::fmt;
+#[cfg(feature = "postgres-types")]
+extern crate postgres_types;
+#[cfg(feature = "proptest")]
+extern crate proptest;
+#[cfg(feature = "rand")]
+extern crate rand;
+#[cfg(feature = "rkyv")]
+extern crate rkyv;
+#[cfg(feature = "rusqlite")]
+extern crate rusqlite as rusqlite_crate;
+#[cfg(feature = "schemars")]
+extern crate schemars;
+#[cfg(feature = "serde")]
+extern crate serde as serde_crate;
+#[cfg(feature = "sqlx")]
+extern crate sqlx;
+#[cfg(feature = "time03")]
+extern crate time03;
+#[cfg(feature = "tzdb")]
+extern crate tzdb;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_support;
+#[cfg(feature = "astro")]
+pub mod astro;
+#[cfg(feature = "bson")]
+mod bson_support;
+mod buffer;
+mod builder;
+#[cfg(feature = "chrono")]
+mod chrono_support;
+#[cfg(feature = "compat")]
+mod compat;
+mod date;
+mod datelike;
+#[cfg(feature = "diesel")]
+mod diesel_support;
+mod duration;
+mod era;
+mod error;
+pub mod epoch;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "heapless")]
+mod heapless_support;
+pub mod holidays;
+pub mod humanize;
+#[cfg(feature = "icu_calendar")]
+mod icu_support;
+pub mod iter;
+#[cfg(feature = "jiff")]
+mod jiff_support;
+pub mod locale;
+pub mod occasions;
+pub mod recurrence;
+mod month;
+mod offset;
+mod packed;
+mod parse;
+mod period;
+#[cfg(feature = "rand")]
+mod rand_support;
+#[cfg(feature = "postgres-types")]
+mod postgres_support;
+#[cfg(feature = "std")]
+mod sys;
+mod unit;
+#[cfg(feature = "wasm")]
+mod wasm_support;
+pub mod render;
+#[cfg(feature = "rusqlite")]
+pub mod rusqlite;
+#[cfg(feature = "schemars")]
+mod schemars_support;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "serde")]
+mod serde_support;
+#[cfg(feature = "sqlx")]
+mod sqlx_support;
+mod tehran;
+#[cfg(feature = "proptest")]
+pub mod testing;
+#[cfg(feature = "time03")]
+mod time03_support;
+mod timespec;
+#[cfg(all(unix, feature = "std"))]
+mod tzfile;
+pub mod validate;
+mod week;
+mod weekday;
+#[cfg(feature = "tzdb")]
+mod zone;
+
+pub use buffer::max_formatted_len;
+pub use builder::TmBuilder;
+#[cfg(feature = "chrono")]
+pub use chrono_support::ToPersian;
+#[cfg(feature = "compat")]
+pub use compat::from_gregorian;
+#[cfg(feature = "rkyv")]
+pub use date::{ArchivedPersianDate, ArchivedPersianDateTime, ArchivedPersianTime};
+pub use date::{PersianDate, PersianDateTime, PersianTime};
+pub use datelike::{Datelike, Timelike};
+pub use duration::Duration;
+pub use era::Era;
+pub use error::DateError;
+use holidays::HolidayCalendar;
+use locale::Locale;
+pub use month::{EndOfMonth, InvalidMonth, Month};
+#[cfg(feature = "std")]
+pub use offset::{default_offset, set_default_offset};
+pub use offset::Offset;
+pub use packed::{PackedPersianDate, PackedPersianDateTime};
+pub use week::PersianIsoWeek;
+pub use weekday::{Weekday, Weekend};
+pub use parse::{Component, ParseError};
+pub use period::PersianPeriod;
+#[cfg(feature = "rand")]
+pub use rand_support::UniformDate;
+#[cfg(feature = "rkyv")]
+pub use timespec::ArchivedTimespec;
+pub use timespec::Timespec;
+pub use unit::Unit;
+use alloc::string::{String, ToString};
+use core::cmp::Ordering;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+use core::fmt;
+use core::time::Duration as StdDuration;
+
+/// The default pivot used to expand two-digit years, mapping 31 to 99 to 1331 to 1399 and
+/// 00 to 30 to 1400 to 1430
+pub const DEFAULT_YEAR_PIVOT: i32 = 1331;
+
+/// The earliest Persian year supported by the constructors (i.e. 5001 BP, see `Era`)
+pub const MIN_YEAR: i32 = -5000;
+
+/// The latest Persian year supported by the constructors
+pub const MAX_YEAR: i32 = 9999;
 
 /// Represents the components of a moment in time in Persian Calendar.
-#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
-#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(C)]
+#[cfg_attr(feature = "rkyv", derive(::rkyv::Archive, ::rkyv::Serialize, ::rkyv::Deserialize))]
+#[cfg_attr(feature = "rkyv", rkyv(derive(Debug)))]
+#[cfg_attr(feature = "diesel", derive(::diesel::expression::AsExpression, ::diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = ::diesel::sql_types::Timestamp))]
+#[cfg_attr(feature = "diesel_postgres", diesel(sql_type = ::diesel::sql_types::Timestamptz))]
 pub struct Tm {
     /// The same as `tm_sec` of `time::Tm`
     pub tm_sec: i32,
@@ -62,49 +231,137 @@ pub struct Tm {
     pub tm_nsec: i32,
 }
 
+// The default time is the Unix epoch (1970-01-01 00:00:00 UTC), i.e. 11 Dey 1348
+impl Default for Tm {
+    fn default() -> Tm {
+        epoch::UNIX_EPOCH
+    }
+}
+
 impl fmt::Display for Tm {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.to_string("yyyy-MM-ddTHH:mm:ss.ns"))
     }
 }
 
-impl Add<time::Duration> for Tm {
+// Prints e.g. `Tm(1395-01-02 Doshanbeh 2 Farvardin 10:30:05.000000000 +00:00)`,
+// followed by the raw fields when `{:#?}` is used
+impl fmt::Debug for Tm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let readable = format!("{:04}-{:02}-{:02} {} {} {} {:02}:{:02}:{:02}.{:09} {}{:02}:{:02}",
+                               self.tm_year,
+                               self.tm_mon + 1,
+                               self.tm_mday,
+                               get_debug_name(&locale::TRANSLITERATED_WEEKDAYS, self.tm_wday),
+                               self.tm_mday,
+                               get_debug_name(&locale::TRANSLITERATED_MONTHS, self.tm_mon),
+                               self.tm_hour,
+                               self.tm_min,
+                               self.tm_sec,
+                               self.tm_nsec,
+                               if self.tm_utcoff < 0 { '-' } else { '+' },
+                               self.tm_utcoff.abs() / 3600,
+                               self.tm_utcoff.abs() % 3600 / 60);
+
+        if !f.alternate() {
+            return write!(f, "Tm({})", readable)
+        }
+
+        f.debug_struct("Tm")
+            .field("date", &format_args!("{}", readable))
+            .field("tm_sec", &self.tm_sec)
+            .field("tm_min", &self.tm_min)
+            .field("tm_hour", &self.tm_hour)
+            .field("tm_mday", &self.tm_mday)
+            .field("tm_mon", &self.tm_mon)
+            .field("tm_year", &self.tm_year)
+            .field("tm_wday", &self.tm_wday)
+            .field("tm_yday", &self.tm_yday)
+            .field("tm_isdst", &self.tm_isdst)
+            .field("tm_utcoff", &self.tm_utcoff)
+            .field("tm_nsec", &self.tm_nsec)
+            .finish()
+    }
+}
+
+// Adds the absolute duration, i.e. a day is 24 hours even across a transition of daylight saving
+// time, resulting in UTC (see `Tm::add_days_in` to keep the wall clock)
+impl Add<Duration> for Tm {
     type Output = Tm;
 
     // FIXME: The timezone of `self` is different from resulting time
-    fn add(self, other: time::Duration) -> Tm {
+    fn add(self, other: Duration) -> Tm {
         at_utc(self.to_timespec() + other)
     }
 }
 
-impl Sub<time::Duration> for Tm {
+// Subtracts the absolute duration, resulting in UTC
+impl Sub<Duration> for Tm {
     type Output = Tm;
 
     // FIXME: The timezone of `self` is different from resulting time
-    fn sub(self, other: time::Duration) -> Tm {
+    fn sub(self, other: Duration) -> Tm {
         at_utc(self.to_timespec() - other)
     }
 }
 
-impl Sub<Tm> for Tm {
-    type Output = time::Duration;
+// Adds the absolute duration, keeping the offset of `self` unlike `Add`
+impl AddAssign<Duration> for Tm {
+    fn add_assign(&mut self, other: Duration) {
+        *self = self.shift_keeping_offset(other);
+    }
+}
 
-    fn sub(self, other: Tm) -> time::Duration {
-        self.to_timespec() - other.to_timespec()
+// Subtracts the absolute duration, keeping the offset of `self` unlike `Sub`
+impl SubAssign<Duration> for Tm {
+    fn sub_assign(&mut self, other: Duration) {
+        *self = self.shift_keeping_offset(-other);
+    }
+}
+
+// Adds the duration of std like `Add<Duration>`, panics if it overflows
+impl Add<StdDuration> for Tm {
+    type Output = Tm;
+
+    fn add(self, other: StdDuration) -> Tm {
+        self + Duration::from_std(other).expect("duration out of bounds")
+    }
+}
+
+// Subtracts the duration of std like `Sub<Duration>`, panics if it overflows
+impl Sub<StdDuration> for Tm {
+    type Output = Tm;
+
+    fn sub(self, other: StdDuration) -> Tm {
+        self - Duration::from_std(other).expect("duration out of bounds")
     }
 }
 
-impl Sub<time::Tm> for Tm {
-    type Output = time::Duration;
+// Adds the duration of std, keeping the offset of `self` like `AddAssign<Duration>`
+impl AddAssign<StdDuration> for Tm {
+    fn add_assign(&mut self, other: StdDuration) {
+        *self += Duration::from_std(other).expect("duration out of bounds");
+    }
+}
 
-    fn sub(self, other: time::Tm) -> time::Duration {
+// Subtracts the duration of std, keeping the offset of `self` like `SubAssign<Duration>`
+impl SubAssign<StdDuration> for Tm {
+    fn sub_assign(&mut self, other: StdDuration) {
+        *self -= Duration::from_std(other).expect("duration out of bounds");
+    }
+}
+
+impl Sub<Tm> for Tm {
+    type Output = Duration;
+
+    fn sub(self, other: Tm) -> Duration {
         self.to_timespec() - other.to_timespec()
     }
 }
 
 impl PartialOrd for Tm {
     fn partial_cmp(&self, other: &Tm) -> Option<Ordering> {
-        self.to_timespec().partial_cmp(&other.to_timespec())
+        Some(self.cmp(other))
     }
 }
 
@@ -115,82 +372,732 @@ impl Ord for Tm {
 }
 
 impl Tm {
-    /// Converts Persian calendar to Gregorian calendar
-    pub fn to_gregorian(&self) -> time::Tm {
-        let year: i32;
-        let month: i32;
-        let day: i32;
-
-        let jdn = get_jdn(self.tm_year, self.tm_mon + 1, self.tm_mday);
-
-        if jdn > 2299160 {
-            let mut l = jdn + 68569;
-            let n = 4 * l / 146097;
-            l = l - (146097 * n + 3) / 4;
-            let i = 4000 * (l + 1) / 1461001;
-            l = l - 1461 * i / 4 + 31;
-            let j = 80 * l / 2447;
-            day = l - 2447 * j / 80;
-            l = j / 11;
-            month = j + 2 - 12 * l;
-            year = 100 * (n - 49) + i + l;
-        } else {
-            let mut j = jdn + 1402;
-            let k = (j - 1) / 1461;
-            let l = j - 1461 * k;
-            let n = (l - 1) / 365 - l / 1461;
-            let mut i = l - 365 * n + 30;
-            j = 80 * i / 2447;
-            day = i - 2447 * j / 80;
-            i = j / 11;
-            month = j + 2 - 12 * i;
-            year = 4 * k + n + i - 4716;
-        }
+    /// The earliest supported time (1 Farvardin 5001 BP, 00:00:00 UTC)
+    pub const MIN: Tm = match from_persian_components(MIN_YEAR, 0, 1, 0, 0, 0, 0) {
+        Some(tm) => tm,
+        None => unreachable!(),
+    };
 
-        time::Tm {
-            tm_sec: self.tm_sec,
-            tm_min: self.tm_min,
-            tm_hour: self.tm_hour,
-            tm_mday: day,
-            tm_mon: month - 1,
-            tm_year: year - 1900,
-            tm_wday: get_gregorian_weekday(self.tm_wday),
-            tm_yday: get_gregorian_yday(year, month - 1, day),
-            tm_isdst: self.tm_isdst,
-            tm_utcoff: self.tm_utcoff,
-            tm_nsec: self.tm_nsec,
-        }
+    /// The latest supported time (29 Esfand 9999, 23:59:59.999999999 UTC)
+    pub const MAX: Tm = match from_persian_components(MAX_YEAR, 11, 29, 23, 59, 59, 999999999) {
+        Some(tm) => tm,
+        None => unreachable!(),
+    };
+
+    /// Returns the equivalent Gregorian date as the year, the month since January [0, 11] and the
+    /// day [1, 31], where the dates before October 15, 1582 are of Julian calendar
+    pub const fn to_gregorian_date(&self) -> (i32, i32, i32) {
+        get_gregorian_date(get_jdn(self.tm_year, self.tm_mon + 1, self.tm_mday))
+    }
+
+    /// Returns the number of seconds since January 1, 1970 UTC, subtracting the offset from the
+    /// wall clock
+    pub fn to_timespec(&self) -> Timespec {
+        self.to_timespec_utc_naive() - Duration::seconds(self.tm_utcoff as i64)
     }
 
-    /// Returns the number of seconds since January 1, 1970 UTC
-    pub fn to_timespec(&self) -> time::Timespec {
-        self.to_gregorian().to_timespec()
+    /// Returns the number of seconds since January 1, 1970 UTC of the wall clock as if it were in
+    /// UTC, ignoring the offset (i.e. `to_timespec` of the time with a zero offset)
+    pub fn to_timespec_utc_naive(&self) -> Timespec {
+        Timespec::new(get_unix_seconds(self.to_jdn() as i64, self.tm_hour, self.tm_min, self.tm_sec), self.tm_nsec)
+    }
+
+    /// Returns the number of seconds since January 1, 1970 UTC (i.e. `to_timespec().sec`)
+    pub fn timestamp(&self) -> i64 {
+        self.to_timespec().sec
+    }
+
+    /// Returns the number of milliseconds since January 1, 1970 UTC, truncating the nanosecond
+    /// (e.g. for `Date` of JavaScript)
+    pub fn timestamp_millis(&self) -> i64 {
+        let clock = self.to_timespec();
+        clock.sec * 1000 + clock.nsec as i64 / 1_000_000
+    }
+
+    /// Returns the number of nanoseconds since January 1, 1970 UTC
+    pub fn timestamp_nanos(&self) -> i128 {
+        let clock = self.to_timespec();
+        clock.sec as i128 * 1_000_000_000 + clock.nsec as i128
+    }
+
+    /// Returns the Windows `FILETIME` of time, i.e. the number of 100-nanosecond ticks since
+    /// January 1, 1601 UTC truncating the nanosecond, returns `None` if time is before 1601
+    #[cfg(feature = "filetime")]
+    pub fn to_filetime(&self) -> Option<u64> {
+        let clock = self.to_timespec();
+        let sec = clock.sec + (epoch::UNIX_EPOCH_JDN - epoch::WINDOWS_EPOCH_JDN) as i64 * 86400;
+        if sec < 0 {
+            return None
+        }
+        Some(sec as u64 * 10_000_000 + clock.nsec as u64 / 100)
     }
 
     /// Returns true if the year is a leap year
-    pub fn is_leap(&self) -> bool {
+    pub const fn is_leap(&self) -> bool {
         is_persian_leap(self.tm_year)
     }
 
+    /// Returns the Rata Die of date, i.e. the fixed day number where 1 is January 1, 1 of
+    /// proleptic Gregorian calendar
+    pub const fn to_rata_die(&self) -> i32 {
+        self.to_jdn() - epoch::RATA_DIE_EPOCH_JDN
+    }
+
+    /// Returns the Julian day number of date
+    pub const fn to_jdn(&self) -> i32 {
+        get_jdn(self.tm_year, self.tm_mon + 1, self.tm_mday) as i32
+    }
+
+    /// Returns the Modified Julian Date of time in UTC, where days start at midnight and 0 is
+    /// November 17, 1858
+    pub fn to_mjd(&self) -> f64 {
+        let seconds = self.tm_hour * 3600 + self.tm_min * 60 + self.tm_sec - self.tm_utcoff;
+        let fraction = (seconds as f64 + self.tm_nsec as f64 / 1e9) / 86_400.0;
+        (self.to_jdn() - epoch::MJD_EPOCH_JDN) as f64 + fraction
+    }
+
+    /// Returns the number of days of the month - [29, 31]
+    pub const fn days_in_month(&self) -> i32 {
+        get_persian_month_days(self.tm_year, self.tm_mon)
+    }
+
+    /// Returns the number of days of the year - [365, 366]
+    pub const fn days_in_year(&self) -> i32 {
+        get_persian_year_days(self.tm_year)
+    }
+
+    /// Returns the Persian phrase describing time relative to `relative_to` (e.g. ۳ روز پیش)
+    pub fn humanize(&self, relative_to: &Tm) -> String {
+        humanize::relative(*self - *relative_to)
+    }
+
     /// Convert time to the local timezone
+    #[cfg(feature = "std")]
     pub fn to_local(&self) -> Tm {
-        match self.tm_utcoff {
-            0 => at(self.to_timespec()),
-            _ => *self
-        }
+        self.to_offset(Offset::Local)
     }
 
     /// Convert time to the UTC
     pub fn to_utc(&self) -> Tm {
+        self.to_offset(Offset::Utc)
+    }
+
+    /// Converts time to the same instant in `offset`
+    pub fn to_offset(&self, offset: Offset) -> Tm {
+        at_offset(self.to_timespec(), offset)
+    }
+
+    /// Converts time to the same instant in the fixed offset of `seconds` east of UTC, e.g.
+    /// `with_offset(12600)` for +03:30
+    pub fn with_offset(&self, seconds: i32) -> Tm {
+        self.to_offset(Offset::Fixed(seconds))
+    }
+
+    /// Converts time to the same instant in `timezone` (see `to_offset`)
+    pub fn with_timezone(&self, timezone: &Offset) -> Tm {
+        self.to_offset(*timezone)
+    }
+
+    /// Converts time to the same instant in the IANA timezone named `zone` (e.g. `Asia/Kabul`),
+    /// returns `None` if zone is unknown
+    #[cfg(feature = "tzdb")]
+    pub fn to_zone(&self, zone: &str) -> Option<Tm> {
+        at_zone(self.to_timespec(), zone)
+    }
+
+    /// Returns the offset of time, i.e. `Offset::Utc` or the fixed offset of `tm_utcoff`
+    pub const fn offset(&self) -> Offset {
         match self.tm_utcoff {
-            0 => *self,
-            _ => at_utc(self.to_timespec())
+            0 => Offset::Utc,
+            utcoff => Offset::Fixed(utcoff),
+        }
+    }
+
+    /// Returns the RFC 3339 representation of the equivalent Gregorian time (e.g. 2016-03-21T10:30:00+03:30)
+    pub fn to_rfc3339(&self) -> String {
+        let (year, month, day) = self.to_gregorian_date();
+        format_rfc3339(year, month + 1, day, self)
+    }
+
+    /// Returns the RFC 3339 representation of time using the Persian date (e.g. 1395-01-02T10:30:00+03:30)
+    pub fn to_rfc3339_persian(&self) -> String {
+        format_rfc3339(self.tm_year, self.tm_mon + 1, self.tm_mday, self)
+    }
+
+    /// Returns the RFC 2822 representation of the equivalent Gregorian time (e.g. Mon, 21 Mar 2016 10:30:05 +0330)
+    pub fn to_rfc2822(&self) -> String {
+        let (year, month, day) = self.to_gregorian_date();
+        format!("{}, {:02} {} {:04} {:02}:{:02}:{:02} {}{:02}{:02}",
+                GREGORIAN_WEEKDAYS[get_gregorian_weekday(self.tm_wday) as usize],
+                day,
+                GREGORIAN_MONTHS[month as usize],
+                year,
+                self.tm_hour,
+                self.tm_min,
+                self.tm_sec,
+                if self.tm_utcoff < 0 { '-' } else { '+' },
+                self.tm_utcoff.abs() / 3600,
+                self.tm_utcoff.abs() % 3600 / 60)
+    }
+
+    /// Returns the ordinal representation of date (e.g. 1403-123)
+    pub fn to_ordinal_string(&self) -> String {
+        format!("{:04}-{:03}", self.tm_year, self.tm_yday + 1)
+    }
+
+    /// Returns the week-date representation of date, where weeks start from Shanbeh (e.g. 1403-W18-3)
+    pub fn to_week_date_string(&self) -> String {
+        let week = self.week();
+        format!("{:04}-W{:02}-{}", week.year(), week.week(), week.weekday())
+    }
+
+    /// Returns the week of date, where weeks start from Shanbeh
+    pub fn week(&self) -> PersianIsoWeek {
+        PersianIsoWeek::from_tm(self)
+    }
+
+    /// Returns the astronomical year, where 0 = 1 BP, -1 = 2 BP and so on
+    pub const fn year(&self) -> i32 {
+        self.tm_year
+    }
+
+    /// Returns the era of year
+    pub const fn era(&self) -> Era {
+        Era::of_year(self.tm_year)
+    }
+
+    /// Returns the year of era [1, ..] (e.g. 1 for the year 0, i.e. 1 BP)
+    pub const fn year_of_era(&self) -> i32 {
+        Era::year_of_era(self.tm_year)
+    }
+
+    /// Returns the month
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tm_mon` is out of [0, 11], see `checked_month` for the fallible version
+    pub fn month(&self) -> Month {
+        Month::from_index(self.tm_mon).expect("invalid month")
+    }
+
+    /// Returns the month, or `None` if `tm_mon` is out of [0, 11]
+    pub const fn checked_month(&self) -> Option<Month> {
+        Month::from_index(self.tm_mon)
+    }
+
+    /// Returns the number of month - [1, 12]. 1 = Farvardin, ..., 12 = Esfand.
+    pub const fn month_number(&self) -> i32 {
+        self.tm_mon + 1
+    }
+
+    /// Returns the day of month - [1, 31]
+    pub const fn day(&self) -> i32 {
+        self.tm_mday
+    }
+
+    /// Returns the day of year - [1, 366]
+    pub const fn ordinal(&self) -> i32 {
+        self.tm_yday + 1
+    }
+
+    /// Returns the weekday
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tm_wday` is out of [0, 6], see `checked_weekday` for the fallible version
+    pub fn weekday(&self) -> Weekday {
+        Weekday::from_index(self.tm_wday).expect("invalid weekday")
+    }
+
+    /// Returns the weekday, or `None` if `tm_wday` is out of [0, 6]
+    pub const fn checked_weekday(&self) -> Option<Weekday> {
+        Weekday::from_index(self.tm_wday)
+    }
+
+    /// Returns the hour - [0, 23]
+    pub const fn hour(&self) -> i32 {
+        self.tm_hour
+    }
+
+    /// Returns the minute - [0, 59]
+    pub const fn minute(&self) -> i32 {
+        self.tm_min
+    }
+
+    /// Returns the second - [0, 59]
+    pub const fn second(&self) -> i32 {
+        self.tm_sec
+    }
+
+    /// Returns the nanosecond - [0, 999999999]
+    pub const fn nanosecond(&self) -> i32 {
+        self.tm_nsec
+    }
+
+    /// Returns the offset from UTC in seconds
+    pub const fn utc_offset(&self) -> i32 {
+        self.tm_utcoff
+    }
+
+    /// Returns a copy with the year replaced, keeping the offset
+    pub fn with_year(&self, year: i32) -> Result<Tm, DateError> {
+        self.with_components(year, self.tm_mon, self.tm_mday, self.tm_hour, self.tm_min, self.tm_sec, self.tm_nsec)
+    }
+
+    /// Returns a copy with the month replaced, keeping the offset
+    pub fn with_month(&self, month: Month) -> Result<Tm, DateError> {
+        self.with_components(self.tm_year, month.index(), self.tm_mday, self.tm_hour, self.tm_min, self.tm_sec, self.tm_nsec)
+    }
+
+    /// Returns a copy with the day of month [1, 31] replaced, keeping the offset
+    pub fn with_day(&self, day: i32) -> Result<Tm, DateError> {
+        self.with_components(self.tm_year, self.tm_mon, day, self.tm_hour, self.tm_min, self.tm_sec, self.tm_nsec)
+    }
+
+    /// Returns a copy with the hour [0, 23] replaced, keeping the offset
+    pub fn with_hour(&self, hour: i32) -> Result<Tm, DateError> {
+        self.with_components(self.tm_year, self.tm_mon, self.tm_mday, hour, self.tm_min, self.tm_sec, self.tm_nsec)
+    }
+
+    /// Returns a copy with the minute [0, 59] replaced, keeping the offset
+    pub fn with_minute(&self, minute: i32) -> Result<Tm, DateError> {
+        self.with_components(self.tm_year, self.tm_mon, self.tm_mday, self.tm_hour, minute, self.tm_sec, self.tm_nsec)
+    }
+
+    /// Returns a copy with the second [0, 59] replaced, keeping the offset
+    pub fn with_second(&self, second: i32) -> Result<Tm, DateError> {
+        self.with_components(self.tm_year, self.tm_mon, self.tm_mday, self.tm_hour, self.tm_min, second, self.tm_nsec)
+    }
+
+    /// Returns a copy with the nanosecond [0, 999999999] replaced, keeping the offset
+    pub fn with_nanosecond(&self, nanosecond: i32) -> Result<Tm, DateError> {
+        self.with_components(self.tm_year, self.tm_mon, self.tm_mday, self.tm_hour, self.tm_min, self.tm_sec, nanosecond)
+    }
+
+    // Returns a copy with all components replaced, recomputing the weekday and the day of year
+    #[allow(clippy::too_many_arguments)]
+    fn with_components(&self, year: i32, month: i32, day: i32, hour: i32, minute: i32, second: i32, nanosecond: i32) -> Result<Tm, DateError> {
+        let tm = try_from_persian_components(year, month, day, hour, minute, second, nanosecond)?;
+        Ok(Tm {
+            tm_isdst: self.tm_isdst,
+            tm_utcoff: self.tm_utcoff,
+            ..tm
+        })
+    }
+
+    /// Returns the time `days` days later (or earlier if negative), keeping the time of day and
+    /// the offset (see `add_days_in` to update the offset across daylight saving time)
+    pub const fn add_days(&self, days: i32) -> Tm {
+        shift_persian_days(self, days)
+    }
+
+    /// Returns the time `days` days later (or earlier if negative) in `offset`, keeping the time
+    /// of day, so a day may be 23 or 25 hours across a transition of daylight saving time (see
+    /// `localize` for the time of day skipped or repeated by the transition)
+    pub fn add_days_in(&self, days: i32, offset: Offset) -> Tm {
+        localize(&self.to_offset(offset).add_days(days), offset)
+    }
+
+    /// Returns the time `months` months later (or earlier if negative) in `offset`, keeping the
+    /// time of day (see `add_days_in` and `add_months`)
+    pub fn add_months_in(&self, months: i32, policy: EndOfMonth, offset: Offset) -> Tm {
+        localize(&self.to_offset(offset).add_months(months, policy), offset)
+    }
+
+    /// Returns the time `months` months later (or earlier if negative), keeping the time of day
+    /// and the offset, where the day missing from the resulting month is handled by `policy`
+    /// (e.g. 31 Farvardin + 6 months is 30 Mehr or 1 Aban)
+    pub const fn add_months(&self, months: i32, policy: EndOfMonth) -> Tm {
+        let tm = shift_persian_months(self, months);
+        match policy {
+            EndOfMonth::Clamp => tm,
+            EndOfMonth::Overflow => shift_persian_days(&tm, self.tm_mday - tm.tm_mday),
+        }
+    }
+
+    /// Returns the time `years` years later (or earlier if negative), keeping the time of day
+    /// and the offset, where the day missing from the resulting month is handled by `policy`
+    /// (e.g. 30 Esfand of a leap year + 1 year is 29 Esfand or 1 Farvardin)
+    pub const fn add_years(&self, years: i32, policy: EndOfMonth) -> Tm {
+        self.add_months(years * 12, policy)
+    }
+
+    /// Returns the number of whole years, months and days from the date of `other` to the date of
+    /// `self`, ignoring the time of day, such that adding them to `other` with `EndOfMonth::Clamp`
+    /// results in the date of `self` (e.g. 1 year, 2 months and 3 days from 1 Farvardin 1395 to
+    /// 4 Khordad 1396), where all of them are negative if `self` is before `other`
+    pub fn years_months_days_since(&self, other: &Tm) -> (i32, i32, i32) {
+        let jdn = self.to_jdn();
+        if jdn < other.to_jdn() {
+            let (years, months, days) = other.years_months_days_since(self);
+            return (-years, -months, -days)
+        }
+
+        let mut months = (self.tm_year - other.tm_year) * 12 + self.tm_mon - other.tm_mon;
+        if other.add_months(months, EndOfMonth::Clamp).to_jdn() > jdn {
+            months -= 1;
+        }
+        let days = jdn - other.add_months(months, EndOfMonth::Clamp).to_jdn();
+        (months / 12, months % 12, days)
+    }
+
+    /// Returns the age in completed years on the date of `as_of` of someone born on the date of
+    /// time, where the birthday of 30 Esfand is 29 Esfand in common years (e.g. 1 on 29 Esfand
+    /// 1400 if born on 30 Esfand 1399), returns a negative age if `as_of` is before time
+    pub fn age_on(&self, as_of: &Tm) -> i32 {
+        as_of.years_months_days_since(self).0
+    }
+
+    /// Returns the age in completed years, months and days on the date of `as_of` (see `age_on`)
+    pub fn age_period_on(&self, as_of: &Tm) -> PersianPeriod {
+        PersianPeriod::between(self, as_of)
+    }
+
+    /// Returns the first yearly occurrence of the month and the day of time on a date after the
+    /// date of `after`, keeping the time of day and the offset, where 30 Esfand in common years is
+    /// handled by `policy` (i.e. 29 Esfand or 1 Farvardin)
+    pub fn next_anniversary(&self, after: &Tm, policy: EndOfMonth) -> Tm {
+        let mut year = after.tm_year - 1;
+        loop {
+            let tm = self.add_years(year - self.tm_year, policy);
+            if tm.to_jdn() > after.to_jdn() {
+                return tm
+            }
+            year += 1;
+        }
+    }
+
+    /// Returns the upcoming Nowruz (i.e. 1 Farvardin of the next year at midnight), keeping the offset
+    pub const fn next_nowruz(&self) -> Tm {
+        self.start_of(Unit::Year).add_years(1, EndOfMonth::Clamp)
+    }
+
+    /// Returns the number of days from the date of time to the upcoming Nowruz - [1, 366]
+    pub const fn days_until_nowruz(&self) -> i32 {
+        get_persian_year_days(self.tm_year) - self.tm_yday
+    }
+
+    /// Returns the duration from time to the upcoming Nowruz
+    pub fn until_nowruz(&self) -> Duration {
+        self.next_nowruz().wall_clock() - self.wall_clock()
+    }
+
+    /// Returns the time `days` days later (see `add_days`), returns `None` if the resulting date is
+    /// out of the supported range
+    pub const fn checked_add_days(&self, days: i32) -> Option<Tm> {
+        if !is_jdn_supported(get_jdn(self.tm_year, self.tm_mon + 1, self.tm_mday) + days as i64) {
+            return None
+        }
+        Some(self.add_days(days))
+    }
+
+    /// Returns the time `months` months later (see `add_months`), returns `None` if the resulting
+    /// date is out of the supported range
+    pub const fn checked_add_months(&self, months: i32, policy: EndOfMonth) -> Option<Tm> {
+        let year = (self.tm_year as i64 * 12 + self.tm_mon as i64 + months as i64).div_euclid(12);
+        if year < MIN_YEAR as i64 || year > MAX_YEAR as i64 {
+            return None
+        }
+        let tm = self.add_months(months, policy);
+        if !is_jdn_supported(get_jdn(tm.tm_year, tm.tm_mon + 1, tm.tm_mday)) {
+            return None
+        }
+        Some(tm)
+    }
+
+    /// Returns the time `years` years later (see `add_years`), returns `None` if the resulting
+    /// date is out of the supported range
+    pub const fn checked_add_years(&self, years: i32, policy: EndOfMonth) -> Option<Tm> {
+        match years.checked_mul(12) {
+            Some(months) => self.checked_add_months(months, policy),
+            None => None,
+        }
+    }
+
+    /// Returns the time in UTC after `duration`, returns `None` if the resulting time is out of
+    /// the supported range
+    pub fn checked_add_signed(&self, duration: Duration) -> Option<Tm> {
+        let seconds = duration.num_seconds();
+        let nanoseconds = (duration - Duration::seconds(seconds)).num_nanoseconds()? as i32;
+        let clock = self.to_timespec();
+        let (sec, nsec) = match clock.nsec + nanoseconds {
+            nsec if nsec < 0 => (clock.sec.checked_add(seconds)?.checked_sub(1)?, nsec + 1_000_000_000),
+            nsec if nsec >= 1_000_000_000 => (clock.sec.checked_add(seconds)?.checked_add(1)?, nsec - 1_000_000_000),
+            nsec => (clock.sec.checked_add(seconds)?, nsec),
+        };
+        checked_at_utc(Timespec::new(sec, nsec))
+    }
+
+    /// Returns the time in UTC before `duration`, returns `None` if the resulting time is out of
+    /// the supported range
+    pub fn checked_sub_signed(&self, duration: Duration) -> Option<Tm> {
+        self.checked_add_signed(-duration)
+    }
+
+    /// Returns the time `days` days later (see `add_days`), clamped to `Tm::MIN` and `Tm::MAX`
+    pub const fn saturating_add_days(&self, days: i32) -> Tm {
+        match self.checked_add_days(days) {
+            Some(tm) => tm,
+            None => saturate(days as i64),
+        }
+    }
+
+    /// Returns the time `months` months later (see `add_months`), clamped to `Tm::MIN` and `Tm::MAX`
+    pub const fn saturating_add_months(&self, months: i32, policy: EndOfMonth) -> Tm {
+        match self.checked_add_months(months, policy) {
+            Some(tm) => tm,
+            None => saturate(months as i64),
+        }
+    }
+
+    /// Returns the time `years` years later (see `add_years`), clamped to `Tm::MIN` and `Tm::MAX`
+    pub const fn saturating_add_years(&self, years: i32, policy: EndOfMonth) -> Tm {
+        match self.checked_add_years(years, policy) {
+            Some(tm) => tm,
+            None => saturate(years as i64),
+        }
+    }
+
+    /// Returns the time in UTC after `duration`, clamped to `Tm::MIN` and `Tm::MAX`
+    pub fn saturating_add_signed(&self, duration: Duration) -> Tm {
+        self.checked_add_signed(duration).unwrap_or_else(|| saturate(duration.num_seconds()))
+    }
+
+    // Returns the time after `duration` in the offset of `self`
+    fn shift_keeping_offset(&self, duration: Duration) -> Tm {
+        Tm {
+            tm_isdst: self.tm_isdst,
+            tm_utcoff: self.tm_utcoff,
+            ..at_utc(self.to_timespec_utc_naive() + duration)
+        }
+    }
+
+    // Returns the same date and time of day in UTC
+    const fn wall_clock(&self) -> Tm {
+        Tm { tm_isdst: 0, tm_utcoff: 0, ..*self }
+    }
+
+    /// Returns the quarter (i.e. season) of year - [1, 4]. 1 = Farvardin to Khordad, ...,
+    /// 4 = Dey to Esfand.
+    pub const fn quarter(&self) -> i32 {
+        self.tm_mon / 3 + 1
+    }
+
+    /// Returns the start of `unit` containing time, keeping the offset (e.g. 1 Mehr 00:00:00 of
+    /// any time in Mehr with `Unit::Month`)
+    pub const fn start_of(&self, unit: Unit) -> Tm {
+        let tm = match unit {
+            Unit::Second => return Tm { tm_nsec: 0, ..*self },
+            Unit::Minute => return Tm { tm_sec: 0, tm_nsec: 0, ..*self },
+            Unit::Hour => return Tm { tm_min: 0, tm_sec: 0, tm_nsec: 0, ..*self },
+            Unit::Day => *self,
+            Unit::Week => shift_persian_days(self, -self.tm_wday),
+            Unit::Month => with_persian_jdn(self, get_jdn(self.tm_year, self.tm_mon + 1, 1)),
+            Unit::Quarter => with_persian_jdn(self, get_jdn(self.tm_year, self.tm_mon - self.tm_mon % 3 + 1, 1)),
+            Unit::Year => with_persian_jdn(self, get_jdn(self.tm_year, 1, 1)),
+        };
+        Tm { tm_hour: 0, tm_min: 0, tm_sec: 0, tm_nsec: 0, ..tm }
+    }
+
+    /// Returns the first day after time falling on `weekday` (e.g. the next Jomeh), keeping the time
+    /// of day and the offset, which is a week later if time already falls on `weekday`
+    pub const fn next_occurrence_of(&self, weekday: Weekday) -> Tm {
+        shift_persian_days(self, (weekday.num_days_from_shanbeh() - self.tm_wday + 6).rem_euclid(7) + 1)
+    }
+
+    /// Returns the last day before time falling on `weekday` (e.g. the previous Shanbeh), keeping
+    /// the time of day and the offset, which is a week earlier if time already falls on `weekday`
+    pub const fn previous_occurrence_of(&self, weekday: Weekday) -> Tm {
+        shift_persian_days(self, -((self.tm_wday - weekday.num_days_from_shanbeh() + 6).rem_euclid(7) + 1))
+    }
+
+    /// Returns true if the weekday is off work in `weekend` (e.g. `Weekend::IRAN_FRIDAY_ONLY` or
+    /// `Weekend::from_bits(mask)`)
+    pub const fn is_weekend(&self, weekend: &Weekend) -> bool {
+        weekend.contains(weekday::WEEKDAYS[self.tm_wday as usize])
+    }
+
+    /// Returns true if the weekday is not off work in `weekend`
+    pub const fn is_business_day(&self, weekend: &Weekend) -> bool {
+        !self.is_weekend(weekend)
+    }
+
+    /// Returns true if the weekday is not off work in `weekend` and the date is not a holiday of
+    /// `calendar` (e.g. `holidays::Iran`)
+    pub fn is_working_day(&self, weekend: &Weekend, calendar: &dyn HolidayCalendar) -> bool {
+        self.is_business_day(weekend) && !calendar.is_holiday(self)
+    }
+
+    /// Returns the time `days` working days later (or earlier if negative), skipping the days of
+    /// `weekend` and the holidays of `calendar` and keeping the time of day and the offset,
+    /// returns `None` if the resulting date is out of the supported range (e.g. of a calendar
+    /// whose every day is a holiday), panics if all weekdays are off work
+    pub fn add_working_days(&self, days: i32, weekend: &Weekend, calendar: &dyn HolidayCalendar) -> Option<Tm> {
+        assert!(weekend.business_days_per_week() > 0, "weekend without business days");
+        let step = days.signum();
+        let mut rest = days.unsigned_abs();
+        let mut tm = *self;
+        while rest > 0 {
+            tm = tm.checked_add_days(step)?;
+            if tm.is_working_day(weekend, calendar) {
+                rest -= 1;
+            }
+        }
+        Some(tm)
+    }
+
+    /// Returns the time `days` business days later (or earlier if negative), skipping the days of
+    /// `weekend` and keeping the time of day and the offset (e.g. Shanbeh of Panjshanbeh + 1 with
+    /// Jomeh off), panics if all weekdays are off work
+    ///
+    /// Like `add_days`, the resulting date is not checked against the supported range (see
+    /// `checked_add_business_days`).
+    pub fn add_business_days(&self, days: i32, weekend: &Weekend) -> Tm {
+        let per_week = weekend.business_days_per_week();
+        assert!(per_week > 0, "weekend without business days");
+        if days == 0 {
+            return *self
+        }
+
+        let step = days.signum();
+        let weeks = (days.abs() - 1) / per_week;
+        let mut rest = days.abs() - weeks * per_week;
+        let mut tm = self.add_days(step * weeks * 7);
+        while rest > 0 {
+            tm = tm.add_days(step);
+            if tm.is_business_day(weekend) {
+                rest -= 1;
+            }
+        }
+        tm
+    }
+
+    /// Returns the time `days` business days later (see `add_business_days`), returns `None` if
+    /// the resulting date is out of the supported range, panics if all weekdays are off work
+    pub fn checked_add_business_days(&self, days: i32, weekend: &Weekend) -> Option<Tm> {
+        assert!(weekend.business_days_per_week() > 0, "weekend without business days");
+        // Every business day is at least one day, so the larger counts leave the supported range
+        if days.unsigned_abs() as i64 > (Tm::MAX.to_jdn() - Tm::MIN.to_jdn()) as i64 {
+            return None
+        }
+        let tm = self.add_business_days(days, weekend);
+        if !is_jdn_supported(get_jdn(tm.tm_year, tm.tm_mon + 1, tm.tm_mday)) {
+            return None
         }
+        Some(tm)
+    }
+
+    /// Returns the start of `unit` nearest to time, keeping the offset, where the time in the
+    /// middle is rounded up (e.g. 1 Aban of 16 Mehr 12:00:00 with `Unit::Month`)
+    pub fn round(&self, unit: Unit) -> Tm {
+        let start = self.start_of(unit);
+        let next = match unit {
+            Unit::Second => start.shift_keeping_offset(Duration::seconds(1)),
+            Unit::Minute => start.shift_keeping_offset(Duration::minutes(1)),
+            Unit::Hour => start.shift_keeping_offset(Duration::hours(1)),
+            Unit::Day => start.add_days(1),
+            Unit::Week => start.add_days(7),
+            Unit::Month => start.add_months(1, EndOfMonth::Clamp),
+            Unit::Quarter => start.add_months(3, EndOfMonth::Clamp),
+            Unit::Year => start.add_years(1, EndOfMonth::Clamp),
+        };
+        if self.wall_clock() - start.wall_clock() < next.wall_clock() - self.wall_clock() {
+            start
+        } else {
+            next
+        }
+    }
+
+    /// Returns the time truncated to a multiple of `duration` since the Unix epoch in the wall
+    /// clock of time, keeping the offset (e.g. 10:45 of 10:52 with 15 minutes), returns `None` if
+    /// `duration` is not positive or the result is out of the supported range
+    pub fn duration_trunc(&self, duration: Duration) -> Option<Tm> {
+        self.duration_step(duration, false)
+    }
+
+    /// Returns the time rounded to the nearest multiple of `duration` since the Unix epoch in the
+    /// wall clock of time, keeping the offset (e.g. 11:00 of 10:53 with 15 minutes), returns
+    /// `None` if `duration` is not positive or the result is out of the supported range
+    pub fn duration_round(&self, duration: Duration) -> Option<Tm> {
+        self.duration_step(duration, true)
+    }
+
+    fn duration_step(&self, duration: Duration, round: bool) -> Option<Tm> {
+        let step = match duration.num_nanoseconds() {
+            Some(step) if step > 0 => step as i128,
+            _ => return None,
+        };
+        let clock = self.to_timespec_utc_naive();
+        let nanos = clock.sec as i128 * 1_000_000_000 + clock.nsec as i128;
+        let rest = nanos.rem_euclid(step);
+        let nanos = if round && rest * 2 >= step { nanos - rest + step } else { nanos - rest };
+
+        let sec = nanos.div_euclid(1_000_000_000) as i64;
+        if !is_jdn_supported(sec.div_euclid(86400) + epoch::UNIX_EPOCH_JDN as i64) {
+            return None
+        }
+        let clock = Timespec::new(sec, nanos.rem_euclid(1_000_000_000) as i32);
+        Some(Tm {
+            tm_isdst: self.tm_isdst,
+            tm_utcoff: self.tm_utcoff,
+            ..at_utc(clock)
+        })
+    }
+
+    /// Returns the last nanosecond of `unit` containing time, keeping the offset (e.g. 30 Esfand
+    /// 23:59:59.999999999 of any time in a leap year with `Unit::Year`)
+    pub const fn end_of(&self, unit: Unit) -> Tm {
+        let tm = match unit {
+            Unit::Second => return Tm { tm_nsec: 999_999_999, ..*self },
+            Unit::Minute => return Tm { tm_sec: 59, tm_nsec: 999_999_999, ..*self },
+            Unit::Hour => return Tm { tm_min: 59, tm_sec: 59, tm_nsec: 999_999_999, ..*self },
+            Unit::Day => *self,
+            Unit::Week => shift_persian_days(self, 6 - self.tm_wday),
+            Unit::Month => self.last_day_of_month(),
+            Unit::Quarter => {
+                let month = self.tm_mon - self.tm_mon % 3 + 2;
+                with_persian_jdn(self, get_jdn(self.tm_year, month + 1, get_persian_month_days(self.tm_year, month)))
+            }
+            Unit::Year => with_persian_jdn(self, get_jdn(self.tm_year, 12, get_persian_month_days(self.tm_year, 11))),
+        };
+        Tm { tm_hour: 23, tm_min: 59, tm_sec: 59, tm_nsec: 999_999_999, ..tm }
+    }
+
+    /// Returns the last nanosecond of month, keeping the offset
+    pub const fn end_of_month(&self) -> Tm {
+        self.end_of(Unit::Month)
+    }
+
+    /// Returns the last nanosecond of year (i.e. of 29 or 30 Esfand), keeping the offset
+    pub const fn end_of_year(&self) -> Tm {
+        self.end_of(Unit::Year)
+    }
+
+    /// Returns the last day of month (e.g. 29 or 30 Esfand), keeping the time of day and the offset
+    pub const fn last_day_of_month(&self) -> Tm {
+        with_persian_jdn(self, get_jdn(self.tm_year, self.tm_mon + 1, get_persian_month_days(self.tm_year, self.tm_mon)))
+    }
+
+    /// Returns the date without time of day
+    pub fn date(&self) -> PersianDate {
+        PersianDate::from_tm(self)
+    }
+
+    /// Returns the time of day without date
+    pub fn time(&self) -> PersianTime {
+        PersianTime::from_tm(self)
+    }
+
+    /// Returns the date and time without offset
+    pub fn date_time(&self) -> PersianDateTime {
+        PersianDateTime::from_tm(self)
     }
 
     /// Returns the formatted representation of time
-    ///     yyyy, yyy, y     year (e.g. 1394)
-    ///     yy               2-digits representation of year (e.g. 94)
+    ///     yyyy             4-digits representation of year, where 0 = 1 BP and -1 = 2 BP (e.g. 1394)
+///     yyy, y           year, where 0 = 1 BP and -1 = 2 BP (e.g. 1394)
+///     Y                year of era, used with G or g (e.g. 1394 or 5 of 5 BP)
+    ///     yy               2-digits representation of year of era (e.g. 94)
     ///     MMM              the Persian name of month (e.g. فروردین)
     ///     MM               2-digits representation of month (e.g. 01)
     ///     M                month (e.g. 1)
@@ -200,6 +1107,8 @@ impl Tm {
     ///     d                day (e.g. 1)
     ///     E                the Persian name of weekday (e.g. شنبه)
     ///     e                the Persian short name of weekday (e.g. ش)
+    ///     G                the Persian name of era (e.g. هجری شمسی or پیش از هجرت)
+    ///     g                the Persian short name of era (e.g. ه.ش. or پ.ه.)
     ///     A                the Persian name of 12-Hour marker (e.g. قبل از ظهر)
     ///     a                the Persian short name of 12-Hour marker (e.g. ق.ظ)
     ///     HH               2-digits representation of hour [00-23]
@@ -215,96 +1124,109 @@ impl Tm {
     ///     ss               2-digits representation of seconds [00-59]
     ///     s                seconds [0-59]
     ///     ns               nanoseconds
+    ///     Z                offset (e.g. +03:30, or Z for UTC)
     pub fn to_string<'a>(&'a self, format: &'a str) -> String {
-        format
-            .replace("yyyy", &self.tm_year.to_string())
-            .replace("yyy", &self.tm_year.to_string())
-            .replace("yy", &self.tm_year.to_string()[2..])
-            .replace("y", &self.tm_year.to_string())
-            .replace("MMM", match self.tm_mon {
-                                0 => "فروردین",
-                                1 => "اردیبهشت",
-                                2 => "خرداد",
-                                3 => "تیر",
-                                4 => "مرداد",
-                                5 => "شهریور",
-                                6 => "مهر",
-                                7 => "آبان",
-                                8 => "آذر",
-                                9 => "دی",
-                                10 => "بهمن",
-                                11 => "اسفند",
-                                _ => panic!("invalid month value of {}", self.tm_mon),
-                            })
-            .replace("MM", &format!("{:02}", self.tm_mon + 1))
-            .replace("M", &format!("{}", self.tm_mon + 1))
-            .replace("DD", &format!("{}", self.tm_yday + 1))
-            .replace("D", &self.tm_yday.to_string())
-            .replace("dd", &format!("{:02}", self.tm_mday))
-            .replace("d", &self.tm_mday.to_string())
-            .replace("E", match self.tm_wday {
-                              0 => "شنبه",
-                              1 => "یک‌شنبه",
-                              2 => "دوشنبه",
-                              3 => "سه‌شنبه",
-                              4 => "چهارشنبه",
-                              5 => "پنج‌شنبه",
-                              6 => "جمعه",
-                              _ => panic!("invalid weekday value of {}", self.tm_wday),
-                          })
-            .replace("e", match self.tm_wday {
-                              0 => "ش",
-                              1 => "ی",
-                              2 => "د",
-                              3 => "س",
-                              4 => "چ",
-                              5 => "پ",
-                              6 => "ج",
-                              _ => panic!("invalid weekday value of {}", self.tm_wday),
-                          })
-            .replace("A", if self.tm_hour < 12 {
-                              "قبل از ظهر"
-                          } else {
-                              "بعد از ظهر"
-                          })
-            .replace("a", if self.tm_hour < 12 {
-                              "ق.ظ"
-                          } else {
-                              "ب.ظ"
-                          })
-            .replace("HH", &format!("{:02}", self.tm_hour))
-            .replace("H", &self.tm_hour.to_string())
-            .replace("kk", &format!("{:02}", self.tm_hour + 1))
-            .replace("k", &format!("{}", self.tm_hour + 1))
-            .replace("hh", &format!("{:02}", if self.tm_hour > 11 {
-                                                 self.tm_hour - 12
-                                             } else {
-                                                 self.tm_hour
-                                             } + 1))
-            .replace("h", &format!("{}", if self.tm_hour > 11 {
-                                             self.tm_hour - 12
-                                         } else {
-                                             self.tm_hour
-                                         } + 1))
-            .replace("KK", &format!("{:02}", if self.tm_hour > 11 {
-                                                 self.tm_hour - 12
-                                             } else {
-                                                 self.tm_hour
-                                             }))
-            .replace("K", &format!("{}", if self.tm_hour > 11 {
-                                             self.tm_hour - 12
-                                         } else {
-                                             self.tm_hour
-                                         }))
-            .replace("mm", &format!("{:02}", self.tm_min))
-            .replace("m", &self.tm_min.to_string())
-            .replace("ns", &self.tm_nsec.to_string())
-            .replace("ss", &format!("{:02}", self.tm_sec))
-            .replace("s", &self.tm_sec.to_string())
-    }
-}
-
-/// Creates an empty `ptime::Tm`
+        self.to_locale_string(format, &locale::Persian)
+    }
+
+    /// Returns the formatted representation of time using the names of `locale`
+    /// (see `to_string` for the list of supported tokens)
+    pub fn to_locale_string<L: Locale>(&self, format: &str, locale: &L) -> String {
+        let mut result = String::with_capacity(format.len());
+        self.write_locale_to(&mut result, format, locale).expect("writing to string failed");
+        result
+    }
+
+    /// Writes the formatted representation of time to `writer` without allocation (see
+    /// `to_string` for the list of supported tokens), e.g. to a `heapless::String`
+    pub fn write_to<W: fmt::Write>(&self, writer: &mut W, format: &str) -> fmt::Result {
+        self.write_locale_to(writer, format, &locale::Persian)
+    }
+
+    /// Writes the formatted representation of time to `writer` using the names of `locale`
+    /// (see `to_string` for the list of supported tokens)
+    pub fn write_locale_to<L: Locale, W: fmt::Write>(&self, writer: &mut W, format: &str, locale: &L) -> fmt::Result {
+        let mut rest = format;
+        while let Some(c) = rest.chars().next() {
+            match FORMAT_TOKENS.iter().find(|token| rest.starts_with(*token)) {
+                Some(token) => {
+                    self.write_token(writer, token, locale)?;
+                    rest = &rest[token.len()..];
+                }
+                None => {
+                    writer.write_char(c)?;
+                    rest = &rest[c.len_utf8()..];
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the formatted representation of time to `buf` (see `to_string` for the list of
+    /// supported tokens), returns the number of bytes written or `None` if `buf` is too short,
+    /// which never happens if its length is at least `ptime::max_formatted_len(format)`
+    pub fn format_to_slice(&self, format: &str, buf: &mut [u8]) -> Option<usize> {
+        let mut writer = buffer::SliceWriter::new(buf);
+        self.write_to(&mut writer, format).ok()?;
+        Some(writer.len())
+    }
+
+    fn write_token<L: Locale, W: fmt::Write>(&self, w: &mut W, token: &str, locale: &L) -> fmt::Result {
+        let hour12 = if self.tm_hour > 11 {
+            self.tm_hour - 12
+        } else {
+            self.tm_hour
+        };
+
+        match token {
+            "yyyy" if self.tm_year < 0 => write!(w, "-{:04}", -self.tm_year),
+            "yyyy" => write!(w, "{:04}", self.tm_year),
+            "yyy" | "y" => write!(w, "{}", self.tm_year),
+            "Y" => write!(w, "{}", self.year_of_era()),
+            "yy" => write!(w, "{:02}", self.year_of_era() % 100),
+            "MMM" => w.write_str(locale.month_name(self.tm_mon)),
+            "MM" => write!(w, "{:02}", self.tm_mon + 1),
+            "M" => write!(w, "{}", self.tm_mon + 1),
+            "DD" => write!(w, "{}", self.tm_yday + 1),
+            "D" => write!(w, "{}", self.tm_yday),
+            "dd" => write!(w, "{:02}", self.tm_mday),
+            "d" => write!(w, "{}", self.tm_mday),
+            "E" => w.write_str(locale.weekday_name(self.tm_wday)),
+            "e" => w.write_str(locale.weekday_short_name(self.tm_wday)),
+            "G" => match self.era() {
+                Era::AP => w.write_str(locale.era_name()),
+                Era::BP => w.write_str(locale.before_era_name()),
+            },
+            "g" => match self.era() {
+                Era::AP => w.write_str(locale.era_short_name()),
+                Era::BP => w.write_str(locale.before_era_short_name()),
+            },
+            "A" => w.write_str(locale.marker_name(self.tm_hour)),
+            "a" => w.write_str(locale.marker_short_name(self.tm_hour)),
+            "HH" => write!(w, "{:02}", self.tm_hour),
+            "H" => write!(w, "{}", self.tm_hour),
+            "kk" => write!(w, "{:02}", self.tm_hour + 1),
+            "k" => write!(w, "{}", self.tm_hour + 1),
+            "hh" => write!(w, "{:02}", hour12 + 1),
+            "h" => write!(w, "{}", hour12 + 1),
+            "KK" => write!(w, "{:02}", hour12),
+            "K" => write!(w, "{}", hour12),
+            "mm" => write!(w, "{:02}", self.tm_min),
+            "m" => write!(w, "{}", self.tm_min),
+            "ns" => write!(w, "{}", self.tm_nsec),
+            "ss" => write!(w, "{:02}", self.tm_sec),
+            "s" => write!(w, "{}", self.tm_sec),
+            "Z" => match self.tm_utcoff {
+                0 => w.write_char('Z'),
+                utcoff => write!(w, "{}{:02}:{:02}", if utcoff < 0 { '-' } else { '+' }, utcoff.abs() / 3600, utcoff.abs() % 3600 / 60),
+            },
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Creates an empty `ptime::Tm` with all fields set to zero, which is not a valid date (see
+/// `Tm::default` for a valid one)
 pub fn empty_tm() -> Tm {
     Tm {
         tm_sec: 0,
@@ -321,172 +1243,559 @@ pub fn empty_tm() -> Tm {
     }
 }
 
-/// Converts Gregorian calendar to Persian calendar
-pub fn from_gregorian(gregorian_tm:time::Tm) -> Tm {
-    let mut year: i32;
-    let gy = gregorian_tm.tm_year + 1900;
-    let gm = gregorian_tm.tm_mon + 1;
-    let gd = gregorian_tm.tm_mday;
+/// Creates a new instance of Persian time from Gregorian date
+pub fn from_gregorian_date(g_year: i32, g_month: i32, g_day: i32) -> Option<Tm> {
+    try_from_gregorian_date(g_year, g_month, g_day).ok()
+}
 
-    let jdn: i32 = if gy > 1582 || (gy == 1582 && gm > 10) || (gy == 1582 && gm == 10 && gd > 14) {
-        ((1461 * (gy + 4800 + ((gm - 14) / 12))) / 4) + ((367 * (gm - 2 - 12*((gm-14)/12))) / 12) - ((3 * ((gy + 4900 + ((gm - 14) / 12)) / 100)) / 4) + gd - 32075
-    } else {
-        367 * gy - ((7 * (gy + 5001 + ((gm - 9) / 7))) / 4) + ((275 * gm) / 9) + gd + 1729777
+/// Creates a new instance of Persian time from Persian date
+pub const fn from_persian_date(p_year: i32, p_month: i32, p_day: i32) -> Option<Tm> {
+    from_persian_components(p_year, p_month, p_day, 0, 0, 0, 0)
+}
+
+/// Creates a new instance of Persian time from Persian date of `Month`, e.g.
+/// `ptime::from_persian_month(1403, Month::Farvardin, 1)`
+pub const fn from_persian_month(p_year: i32, p_month: Month, p_day: i32) -> Option<Tm> {
+    from_persian_date(p_year, p_month.index(), p_day)
+}
+
+/// Creates a new instance of Persian time from Gregorian date components
+pub fn from_gregorian_components(g_year: i32, g_month: i32, g_day: i32, hour: i32, minute: i32, second: i32, nanosecond: i32) -> Option<Tm> {
+    try_from_gregorian_components(g_year, g_month, g_day, hour, minute, second, nanosecond).ok()
+}
+
+/// Creates a new instance of Persian time from Persian date components
+pub const fn from_persian_components(p_year: i32, p_month: i32, p_day: i32, hour: i32, minute: i32, second: i32, nanosecond: i32) -> Option<Tm> {
+    match try_from_persian_components(p_year, p_month, p_day, hour, minute, second, nanosecond) {
+        Ok(tm) => Some(tm),
+        Err(_) => None,
+    }
+}
+
+/// Creates a new instance of Persian time from Gregorian date, returns the reason if date is invalid
+pub fn try_from_gregorian_date(g_year: i32, g_month: i32, g_day: i32) -> Result<Tm, DateError> {
+    try_from_gregorian_components(g_year, g_month, g_day, 0, 0, 0, 0)
+}
+
+/// Creates a new instance of Persian time from Persian date, returns the reason if date is invalid
+pub const fn try_from_persian_date(p_year: i32, p_month: i32, p_day: i32) -> Result<Tm, DateError> {
+    try_from_persian_components(p_year, p_month, p_day, 0, 0, 0, 0)
+}
+
+/// Creates a new instance of Persian time from Persian date of `Month`, returns the reason if date
+/// is invalid
+pub const fn try_from_persian_month(p_year: i32, p_month: Month, p_day: i32) -> Result<Tm, DateError> {
+    try_from_persian_date(p_year, p_month.index(), p_day)
+}
+
+/// Creates a new instance of Persian time from Gregorian date components, returns the reason if
+/// any of them is invalid
+pub fn try_from_gregorian_components(g_year: i32, g_month: i32, g_day: i32, hour: i32, minute: i32, second: i32, nanosecond: i32) -> Result<Tm, DateError> {
+    validate::gregorian_date(g_year, g_month, g_day)?;
+    validate::time(hour, minute, second, nanosecond)?;
+    let tm = Tm {
+        tm_sec: second,
+        tm_min: minute,
+        tm_hour: hour,
+        tm_nsec: nanosecond,
+        ..empty_tm()
     };
+    let p_tm = with_persian_jdn(&tm, get_gregorian_jdn(g_year, g_month + 1, g_day));
+    if p_tm.tm_year < MIN_YEAR || p_tm.tm_year > MAX_YEAR {
+        return Err(DateError::YearOutOfRange { year: g_year })
+    }
+    Ok(p_tm)
+}
+
+/// Creates a new instance of Persian time from Persian date components, returns the reason if
+/// any of them is invalid
+pub const fn try_from_persian_components(p_year: i32, p_month: i32, p_day: i32, hour: i32, minute: i32, second: i32, nanosecond: i32) -> Result<Tm, DateError> {
+    if let Err(err) = validate::persian_date(p_year, p_month, p_day) {
+        return Err(err)
+    }
+    if let Err(err) = validate::time(hour, minute, second, nanosecond) {
+        return Err(err)
+    }
+    Ok(from_persian_components_unchecked(p_year, p_month, p_day, hour, minute, second, nanosecond))
+}
+
+/// Creates a new instance of Persian time from Persian date components without validating them,
+/// for the components that are already known to be valid (e.g. see `validate`)
+///
+/// The resulting time of invalid components is unspecified and may make the other methods panic.
+#[inline]
+pub const fn from_persian_components_unchecked(p_year: i32, p_month: i32, p_day: i32, hour: i32, minute: i32, second: i32, nanosecond: i32) -> Tm {
+    Tm{
+        tm_sec: second,
+        tm_min: minute,
+        tm_hour: hour,
+        tm_mday: p_day,
+        tm_mon: p_month,
+        tm_year: p_year,
+        tm_wday: get_jdn_weekday(get_jdn(p_year, p_month + 1, p_day)),
+        tm_yday: get_persian_yday(p_month, p_day),
+        tm_isdst: 0,
+        tm_utcoff: 0,
+        tm_nsec: nanosecond,
+    }
+}
+
+/// Creates a new instance of Persian time at midnight in UTC of Nowruz (i.e. 1 Farvardin) of year,
+/// returns `None` if year is out of the supported range
+pub const fn nowruz_of(p_year: i32) -> Option<Tm> {
+    from_persian_date(p_year, 0, 1)
+}
 
-    let dep = jdn - get_jdn(475, 1, 1);
-    let cyc = dep / 1029983;
-    let rem = dep % 1029983;
-    let ycyc = if rem == 1029982 {
-        2820
+/// Creates a new instance of Persian time at midnight in UTC of the `n`th `weekday` of the month
+/// since Farvardin [0, 11] in year, where negative `n` counts from the end of month (e.g. the
+/// third Doshanbeh of Mehr 1403 with `n` = 3 or the last Jomeh with `n` = -1), returns `None` if
+/// month does not have such a day
+pub const fn nth_weekday_of_month(p_year: i32, p_month: i32, weekday: Weekday, n: i32) -> Option<Tm> {
+    if validate::persian_date(p_year, p_month, 1).is_err() || n == 0 || n < -5 || n > 5 {
+        return None
+    }
+    let max = get_persian_month_days(p_year, p_month);
+    let weekday = weekday.num_days_from_shanbeh();
+    let day = if n > 0 {
+        let first = get_jdn_weekday(get_jdn(p_year, p_month + 1, 1));
+        1 + (weekday - first).rem_euclid(7) + 7 * (n - 1)
     } else {
-        let a = rem / 366;
-        (2134 * a + 2816 * (rem % 366) + 2815) / 1028522 + a + 1
+        let last = get_jdn_weekday(get_jdn(p_year, p_month + 1, max));
+        max - (last - weekday).rem_euclid(7) - 7 * (-n - 1)
     };
+    if day < 1 || day > max {
+        return None
+    }
+    from_persian_date(p_year, p_month, day)
+}
 
-    year = ycyc + 2820 * cyc + 474;
-    if year <= 0 {
-        year -= 1;
+/// Creates a new instance of Persian time at midnight in UTC of the last `weekday` of the month
+/// since Farvardin [0, 11] in year, returns `None` if month is out of range
+pub const fn last_weekday_of_month(p_year: i32, p_month: i32, weekday: Weekday) -> Option<Tm> {
+    nth_weekday_of_month(p_year, p_month, weekday, -1)
+}
+
+/// Creates a new instance of Persian time at midnight in UTC from the Rata Die, i.e. the fixed
+/// day number where 1 is January 1, 1 of proleptic Gregorian calendar, returns `None` if date is
+/// out of the supported range
+pub const fn from_rata_die(rata_die: i32) -> Option<Tm> {
+    match rata_die.checked_add(epoch::RATA_DIE_EPOCH_JDN) {
+        Some(jdn) => from_jdn(jdn),
+        None => None,
     }
+}
 
-    let dy: f64 = (jdn - get_jdn(year, 1, 1) + 1) as f64;
-    let month: i32 = if dy <= 186f64 {
-        let mod_dy: f64 = dy / 31f64;
-        mod_dy.ceil() as i32
-    } else {
-        let mod_dy: f64 = (dy - 6f64) / 30f64;
-        mod_dy.ceil() as i32
-    } - 1;
-    let day = jdn - get_jdn(year, month + 1, 1) + 1;
+/// Creates a new instance of Persian time at midnight in UTC from the Julian day number, returns
+/// `None` if date is out of the supported range
+pub const fn from_jdn(jdn: i32) -> Option<Tm> {
+    if !is_jdn_supported(jdn as i64) {
+        return None
+    }
+    let (year, month, day) = get_persian_date(jdn as i64);
+    from_persian_date(year, month, day)
+}
 
-    Tm {
-        tm_sec: gregorian_tm.tm_sec,
-        tm_min: gregorian_tm.tm_min,
-        tm_hour: gregorian_tm.tm_hour,
-        tm_mday: day,
-        tm_mon: month,
-        tm_year: year,
-        tm_wday: get_persian_weekday(gregorian_tm.tm_wday),
-        tm_yday: get_persian_yday(month, day),
-        tm_isdst: gregorian_tm.tm_isdst,
-        tm_utcoff: gregorian_tm.tm_utcoff,
-        tm_nsec: gregorian_tm.tm_nsec,
+/// Creates a new instance of Persian time in UTC from the Modified Julian Date, where days start
+/// at midnight and 0 is November 17, 1858, returns `None` if time is out of the supported range
+#[cfg(feature = "std")]
+pub fn from_mjd(mjd: f64) -> Option<Tm> {
+    let day = mjd.floor();
+    if !day.is_finite() || day < i32::MIN as f64 || day > i32::MAX as f64 {
+        return None
     }
+    let tm = from_jdn((day as i32).checked_add(epoch::MJD_EPOCH_JDN)?)?;
+    let nanoseconds = ((mjd - day) * 86_400e9).round() as i64;
+    Some(tm + Duration::nanoseconds(nanoseconds.min(86_400_000_000_000 - 1)))
 }
 
-/// Creates a new instance of Persian time from Gregorian date
-pub fn from_gregorian_date(g_year: i32, g_month: i32, g_day: i32) -> Option<Tm> {
-    from_gregorian_components(g_year, g_month, g_day, 0, 0, 0, 0)
+/// Creates a new instance of Persian time from the RFC 2822 representation of Gregorian time
+/// (e.g. Mon, 21 Mar 2016 10:30:05 +0330)
+pub fn from_rfc2822(s: &str) -> Result<Tm, ParseError> {
+    parse::rfc2822(s)
 }
 
-/// Creates a new instance of Persian time from Persian date
-pub fn from_persian_date(p_year: i32, p_month: i32, p_day: i32) -> Option<Tm> {
-    from_persian_components(p_year, p_month, p_day, 0, 0, 0, 0)
+/// Creates a new instance of Persian time from the ISO 8601 representation of Gregorian time with
+/// optional fraction of second and offset (e.g. 2016-03-21, 2016-03-21T10:30:05Z or
+/// 2016-03-21T10:30:05.250+03:30), where the resulting time keeps the offset
+pub fn from_iso8601(s: &str) -> Result<Tm, ParseError> {
+    parse::iso8601(s)
 }
 
-/// Creates a new instance of Persian time from Gregorian date components
-pub fn from_gregorian_components(g_year: i32, g_month: i32, g_day: i32, hour: i32, minute: i32, second: i32, nanosecond: i32) -> Option<Tm> {
-    if is_time_valid(hour, minute, second, nanosecond) && is_gregorian_date_valid(g_year, g_month, g_day) {
-        let tm = time::Tm{
-            tm_sec: second,
-            tm_min: minute,
-            tm_hour: hour,
-            tm_mday: g_day,
-            tm_mon: g_month,
-            tm_year: g_year - 1900,
-            tm_wday: 0,
-            tm_yday: 0,
-            tm_isdst: 0,
-            tm_utcoff: 0,
-            tm_nsec: nanosecond,
-        };
-        return Some(at_utc(tm.to_timespec()))
+/// Creates a new instance of Persian time from the RFC 3339 representation of Persian time as
+/// returned by `Tm::to_rfc3339_persian` (e.g. 1395-01-02T10:30:05+03:30), where the resulting
+/// time keeps the offset (see `Tm::to_utc` to normalize it to UTC)
+pub fn from_rfc3339_persian(s: &str) -> Result<Tm, ParseError> {
+    parse::rfc3339_persian(s)
+}
+
+/// Creates a new instance of Persian time from the ordinal representation of Persian date (e.g. 1403-123)
+pub fn from_ordinal_string(s: &str) -> Result<Tm, ParseError> {
+    parse::ordinal(s)
+}
+
+/// Creates a new instance of Persian time from the week-date representation of Persian date (e.g. 1403-W18-3)
+pub fn from_week_date_string(s: &str) -> Result<Tm, ParseError> {
+    parse::week_date(s)
+}
+
+/// Creates a new instance of Persian time by parsing `s` formatted with `format`, which accepts
+/// the same tokens as `Tm::to_string` (e.g. `ptime::strptime("دوشنبه ۲ فروردین ۱۳۹۵", "E d MMM yyyy")`)
+pub fn strptime(s: &str, format: &str) -> Result<Tm, ParseError> {
+    parse::pattern(s, format, &locale::Persian, DEFAULT_YEAR_PIVOT)
+}
+
+/// Creates a new instance of Persian time by parsing `s` formatted with `format` using the names
+/// of `locale`
+pub fn strptime_locale<L: Locale>(s: &str, format: &str, locale: &L) -> Result<Tm, ParseError> {
+    parse::pattern(s, format, locale, DEFAULT_YEAR_PIVOT)
+}
+
+/// Creates a new instance of Persian time by parsing `s` formatted with `format` using the names
+/// of `locale`, where the two-digit year of `yy` is expanded to the year in `[pivot, pivot + 99]`
+/// (e.g. with the pivot of 1331, 31 to 99 become 1331 to 1399 and 00 to 30 become 1400 to 1430)
+pub fn strptime_pivot<L: Locale>(s: &str, format: &str, locale: &L, pivot: i32) -> Result<Tm, ParseError> {
+    parse::pattern(s, format, locale, pivot)
+}
+
+/// Creates a new instance of Persian time from the Persian date written in a common way, accepting
+/// `/`, `-`, `.` or `٫` as separator, Persian digits, optional leading zeros, two-digit years
+/// (see `DEFAULT_YEAR_PIVOT`) and an optional time followed by an optional offset
+/// (e.g. 1403/1/1, 1403-01-01, ۱۴۰۳٫۱٫۱, 1/1/1403, 03/1/1 or 1403/01/01 12:30:00 +03:30)
+pub fn parse_lenient(s: &str) -> Result<Tm, ParseError> {
+    parse::lenient(s)
+}
+
+/// Resolves a Persian relative date phrase (e.g. امروز, دیروز, پس‌فردا, سه روز پیش or هفته آینده)
+/// relative to `anchor`, keeping the time of day of `anchor`
+pub fn parse_relative(s: &str, anchor: &Tm) -> Result<Tm, ParseError> {
+    parse::relative(s, anchor)
+}
+
+/// Creates a new instance of Persian time from the number of seconds since January 1, 1970 in UTC,
+/// panics if the day of `clock` does not fit in the `i32` Julian day number
+pub fn at_utc(clock: Timespec) -> Tm {
+    let jdn = clock.sec.div_euclid(86400) + epoch::UNIX_EPOCH_JDN as i64;
+    assert!(jdn >= i32::MIN as i64 && jdn <= i32::MAX as i64, "time out of the range of Julian day numbers");
+    let seconds = clock.sec.rem_euclid(86400) as i32;
+    let tm = Tm {
+        tm_sec: seconds % 60,
+        tm_min: seconds / 60 % 60,
+        tm_hour: seconds / 3600,
+        tm_nsec: clock.nsec,
+        ..empty_tm()
+    };
+    with_persian_jdn(&tm, jdn)
+}
+
+/// Creates a new instance of Persian time in UTC from the number of milliseconds since January 1,
+/// 1970 UTC (e.g. `Date.now()` of JavaScript), returns `None` if time is out of the supported range
+pub fn from_unix_millis(millis: i64) -> Option<Tm> {
+    checked_at_utc(Timespec::new(millis.div_euclid(1000), millis.rem_euclid(1000) as i32 * 1_000_000))
+}
+
+/// Creates a new instance of Persian time in UTC from the number of nanoseconds since January 1,
+/// 1970 UTC, returns `None` if time is out of the supported range
+pub fn from_unix_nanos(nanos: i128) -> Option<Tm> {
+    let sec = nanos.div_euclid(1_000_000_000);
+    if sec < i64::MIN as i128 || sec > i64::MAX as i128 {
+        return None
     }
-    None
+    checked_at_utc(Timespec::new(sec as i64, nanos.rem_euclid(1_000_000_000) as i32))
 }
 
-/// Creates a new instance of Persian time from Persian date components
-// FIXME: Calculate the weekday without converting to Gregorian calendar
-pub fn from_persian_components(p_year: i32, p_month: i32, p_day: i32, hour: i32, minute: i32, second: i32, nanosecond: i32) -> Option<Tm> {
-    if is_time_valid(hour, minute, second, nanosecond) && is_persian_date_valid(p_year, p_month, p_day) {
-        let mut tm = Tm{
-            tm_sec: second,
-            tm_min: minute,
-            tm_hour: hour,
-            tm_mday: p_day,
-            tm_mon: p_month,
-            tm_year: p_year,
-            tm_wday: 0,
-            tm_yday: get_persian_yday(p_month, p_day),
-            tm_isdst: 0,
-            tm_utcoff: 0,
-            tm_nsec: nanosecond,
-        };
-        tm.tm_wday = get_persian_weekday(time::at_utc(tm.to_timespec()).tm_wday);
-        return Some(tm)
+/// Creates a new instance of Persian time in UTC from the Windows `FILETIME`, i.e. the number of
+/// 100-nanosecond ticks since January 1, 1601 UTC, returns `None` if time is out of the supported
+/// range
+#[cfg(feature = "filetime")]
+pub fn from_filetime(ticks: u64) -> Option<Tm> {
+    let sec = (ticks / 10_000_000) as i64 - (epoch::UNIX_EPOCH_JDN - epoch::WINDOWS_EPOCH_JDN) as i64 * 86400;
+    checked_at_utc(Timespec::new(sec, (ticks % 10_000_000) as i32 * 100))
+}
+
+// Returns the Persian time in UTC of `clock`, returns `None` if it is out of the supported range
+fn checked_at_utc(clock: Timespec) -> Option<Tm> {
+    if clock < Tm::MIN.to_timespec() || clock > Tm::MAX.to_timespec() {
+        return None
     }
-    None
+    Some(at_utc(clock))
 }
 
-/// Creates a new instance of Persian time from the number of seconds since January 1, 1970 in UTC
-pub fn at_utc(clock: time::Timespec) -> Tm {
-    from_gregorian(time::at_utc(clock))
+/// Returns the time with the wall clock of `tm` in `offset`, ignoring the offset of `tm`, where
+/// the time of day skipped by a transition of daylight saving time is moved forward by the
+/// length of transition and the repeated one resolves to the earlier instant
+pub fn localize(tm: &Tm, offset: Offset) -> Tm {
+    let clock = tm.to_timespec_utc_naive();
+    let day = Duration::days(1);
+    let before = offset.utc_offset_at(clock - day);
+    let after = offset.utc_offset_at(clock + day);
+    let utcoff = [before, after].iter()
+        .cloned()
+        .find(|&utcoff| offset.utc_offset_at(clock - Duration::seconds(utcoff as i64)) == utcoff)
+        .unwrap_or(before);
+    at_offset(clock - Duration::seconds(utcoff as i64), offset)
 }
 
 /// Creates a new instance of Persian time from the number of seconds since January 1, 1970 in the local timezone
-pub fn at(clock: time::Timespec) -> Tm {
-    from_gregorian(time::at(clock))
+#[cfg(feature = "std")]
+pub fn at(clock: Timespec) -> Tm {
+    at_offset(clock, Offset::Local)
+}
+
+/// Creates a new instance of Persian time from the number of seconds since January 1, 1970 in
+/// `offset`, panics if the day of `clock` does not fit in the `i32` Julian day number
+pub fn at_offset(clock: Timespec, offset: Offset) -> Tm {
+    let (utcoff, isdst) = offset.resolve(clock);
+    Tm {
+        tm_isdst: isdst,
+        tm_utcoff: utcoff,
+        ..at_utc(clock + Duration::seconds(utcoff as i64))
+    }
 }
 
 /// Creates a new instance of Persian time corresponding to the current time in UTC
+#[cfg(feature = "std")]
 pub fn now_utc() -> Tm {
-    from_gregorian(time::now_utc())
+    at_utc(sys::now())
 }
 
 /// Creates a new instance of Persian time corresponding to the current time in the local timezone
+#[cfg(feature = "std")]
 pub fn now() -> Tm {
-    from_gregorian(time::now())
+    at(sys::now())
 }
 
-fn divider(num: i32, den: i32) -> i32 {
-    if num > 0 {
-        num % den
-    } else {
-        num - ((((num + 1) / den) - 1) * den)
+/// Creates a new instance of Persian time from the number of seconds since January 1, 1970 in
+/// Tehran (see `Offset::Tehran`)
+pub fn at_tehran(clock: Timespec) -> Tm {
+    at_offset(clock, Offset::Tehran)
+}
+
+/// Creates a new instance of Persian time corresponding to the current time in Tehran
+#[cfg(feature = "std")]
+pub fn now_tehran() -> Tm {
+    at_tehran(sys::now())
+}
+
+/// Creates a new instance of Persian time from the number of seconds since January 1, 1970 in the
+/// IANA timezone named `zone` (e.g. `Asia/Kabul`), returns `None` if zone is unknown
+#[cfg(feature = "tzdb")]
+pub fn at_zone(clock: Timespec, zone: &str) -> Option<Tm> {
+    let (utcoff, isdst) = zone::resolve(clock, zone)?;
+    Some(Tm {
+        tm_isdst: isdst,
+        tm_utcoff: utcoff,
+        ..at_utc(clock + Duration::seconds(utcoff as i64))
+    })
+}
+
+/// Creates a new instance of Persian time corresponding to the current time in the IANA timezone
+/// named `zone`, returns `None` if zone is unknown
+#[cfg(feature = "tzdb")]
+pub fn now_zone(zone: &str) -> Option<Tm> {
+    at_zone(sys::now(), zone)
+}
+
+/// Returns the number of days of the month since Farvardin [0, 11] in the Persian year, returns
+/// `None` if month is out of range
+pub const fn days_in_month(year: i32, month: i32) -> Option<i32> {
+    if month < 0 || month > 11 {
+        return None
     }
+    Some(get_persian_month_days(year, month))
 }
 
-fn get_jdn(year: i32, month: i32, day: i32) -> i32 {
-    let base = if year >= 0 {
-        year - 474
-    } else {
-        year - 473
+/// Returns the number of days of the Persian year - [365, 366]
+pub const fn days_in_year(year: i32) -> i32 {
+    get_persian_year_days(year)
+}
+
+/// Returns the number of working days from the date of `from` inclusive to the date of `to`
+/// exclusive, skipping the days of `weekend` and the holidays of `calendar` (e.g.
+/// `Some(&holidays::Iran::new())`), where the result is negative if `to` is before `from`
+pub fn working_days_between(from: &Tm, to: &Tm, weekend: &Weekend, calendar: Option<&dyn HolidayCalendar>) -> i32 {
+    let (start, end) = (from.to_jdn(), to.to_jdn());
+    if end < start {
+        return -working_days_between(to, from, weekend, calendar)
+    }
+
+    let first = from.start_of(Unit::Day);
+    (0..end - start)
+        .map(|days| first.add_days(days))
+        .filter(|tm| tm.is_business_day(weekend) && !calendar.is_some_and(|calendar| calendar.is_holiday(tm)))
+        .count() as i32
+}
+
+fn get_debug_name(names: &[&'static str], index: i32) -> &'static str {
+    if index < 0 {
+        return "?"
+    }
+    names.get(index as usize).cloned().unwrap_or("?")
+}
+
+static GREGORIAN_MONTHS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+
+static GREGORIAN_WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+fn format_rfc3339(year: i32, month: i32, day: i32, tm: &Tm) -> String {
+    let fraction = match tm.tm_nsec {
+        0 => String::new(),
+        nsec if nsec % 1_000_000 == 0 => format!(".{:03}", nsec / 1_000_000),
+        nsec if nsec % 1_000 == 0 => format!(".{:06}", nsec / 1_000),
+        nsec => format!(".{:09}", nsec),
     };
+    format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}{}", year, month, day, tm.tm_hour, tm.tm_min, tm.tm_sec, fraction, format_offset(tm.tm_utcoff))
+}
+
+// Returns the offset of RFC 3339, e.g. `+03:30` or `Z` for UTC
+fn format_offset(utcoff: i32) -> String {
+    match utcoff {
+        0 => "Z".to_string(),
+        utcoff => format!("{}{:02}:{:02}", if utcoff < 0 { '-' } else { '+' }, utcoff.abs() / 3600, utcoff.abs() % 3600 / 60),
+    }
+}
 
-    let epy = 474 + (base % 2820);
+// Longer tokens must come before their prefixes
+static FORMAT_TOKENS: [&str; 32] = [
+    "yyyy", "yyy", "yy", "y", "MMM", "MM", "M", "DD", "D", "dd", "d", "E", "e", "G", "g",
+    "A", "a", "HH", "H", "kk", "k", "hh", "h", "KK", "K", "mm", "m", "ns", "ss", "s", "Z", "Y",
+];
 
+// Returns the number of days from the Persian epoch to 1 Farvardin of year, using the
+// astronomical numbering of years (i.e. 0 = 1 BP) and the 33-year cycle of leap years
+const fn get_days_before_year(year: i64) -> i64 {
+    365 * (year - 1) + (8 * (year - 1) + 29).div_euclid(33)
+}
+
+const fn get_jdn(year: i32, month: i32, day: i32) -> i64 {
     let md = if month <= 7 {
         (month - 1) * 31
     } else {
         (month - 1) * 30 + 6
     };
 
-    day + md + (epy * 682 - 110) / 2816 + (epy - 1) * 365 + base / 2820 * 1029983 + 1948320
+    get_days_before_year(year as i64) + (md + day - 1) as i64 + epoch::PERSIAN_EPOCH_JDN as i64
 }
 
-fn get_persian_weekday(wd: i32) -> i32 {
-    match wd {
-        0 => 1,
-        1 => 2,
-        2 => 3,
-        3 => 4,
-        4 => 5,
-        5 => 6,
-        6 => 0,
-        _ => panic!("invalid weekday value of {}", wd),
+// Returns the Persian year, month since Farvardin and day of Julian day number
+const fn get_persian_date(jdn: i64) -> (i32, i32, i32) {
+    let days = jdn - epoch::PERSIAN_EPOCH_JDN as i64;
+    // A cycle of 33 years has 12053 days, so the estimate is off by at most one year
+    let mut year = (33 * days + 3).div_euclid(12053) + 1;
+    if get_days_before_year(year) > days {
+        year -= 1;
+    } else if get_days_before_year(year + 1) <= days {
+        year += 1;
+    }
+
+    let dy = (days - get_days_before_year(year)) as i32 + 1;
+    let month = if dy <= 186 {
+        (dy + 30) / 31
+    } else {
+        (dy - 6 + 29) / 30
+    } - 1;
+    let day = dy - get_persian_yday(month, 1);
+
+    (year as i32, month, day)
+}
+
+// Returns `tm` moved to the Persian date of Julian day number, keeping its time of day
+const fn with_persian_jdn(tm: &Tm, jdn: i64) -> Tm {
+    let (year, month, day) = get_persian_date(jdn);
+    Tm {
+        tm_mday: day,
+        tm_mon: month,
+        tm_year: year,
+        tm_wday: get_jdn_weekday(jdn),
+        tm_yday: get_persian_yday(month, day),
+        ..*tm
+    }
+}
+
+// Returns `tm` moved by `days` days, keeping its time of day
+const fn shift_persian_days(tm: &Tm, days: i32) -> Tm {
+    with_persian_jdn(tm, get_jdn(tm.tm_year, tm.tm_mon + 1, tm.tm_mday) + days as i64)
+}
+
+// Returns true if the date of Julian day number is in the supported range
+const fn is_jdn_supported(jdn: i64) -> bool {
+    jdn >= Tm::MIN.to_jdn() as i64 && jdn <= Tm::MAX.to_jdn() as i64
+}
+
+// Returns the bound of the supported range in the direction of `amount`
+const fn saturate(amount: i64) -> Tm {
+    if amount < 0 {
+        Tm::MIN
+    } else {
+        Tm::MAX
+    }
+}
+
+// Returns the weekday since Shanbeh of Julian day number
+const fn get_jdn_weekday(jdn: i64) -> i32 {
+    (jdn + 2).rem_euclid(7) as i32
+}
+
+// Returns the number of seconds since January 1, 1970 of the time of day on Julian day number
+const fn get_unix_seconds(jdn: i64, hour: i32, minute: i32, second: i32) -> i64 {
+    (jdn - epoch::UNIX_EPOCH_JDN as i64) * 86400 + (hour * 3600 + minute * 60 + second) as i64
+}
+
+// Returns the Julian day number of Gregorian date, where the dates before October 15, 1582 are
+// of Julian calendar
+const fn get_gregorian_jdn(year: i32, month: i32, day: i32) -> i64 {
+    let (gy, gm, gd) = (year as i64, month as i64, day as i64);
+    if gy > 1582 || (gy == 1582 && gm > 10) || (gy == 1582 && gm == 10 && gd > 14) {
+        ((1461 * (gy + 4800 + ((gm - 14) / 12))) / 4) + ((367 * (gm - 2 - 12*((gm-14)/12))) / 12) - ((3 * ((gy + 4900 + ((gm - 14) / 12)) / 100)) / 4) + gd - 32075
+    } else {
+        367 * gy - ((7 * (gy + 5001 + ((gm - 9) / 7))) / 4) + ((275 * gm) / 9) + gd + 1729777
+    }
+}
+
+// Returns the Gregorian date of the Julian day number as the year, the month since January
+// [0, 11] and the day, where the dates before October 15, 1582 are of Julian calendar
+const fn get_gregorian_date(jdn: i64) -> (i32, i32, i32) {
+    let year: i64;
+    let month: i64;
+    let day: i64;
+
+    if jdn >= epoch::GREGORIAN_REFORM_JDN as i64 {
+        let mut l = jdn + 68569;
+        let n = 4 * l / 146097;
+        l -= (146097 * n + 3) / 4;
+        let i = 4000 * (l + 1) / 1461001;
+        l = l - 1461 * i / 4 + 31;
+        let j = 80 * l / 2447;
+        day = l - 2447 * j / 80;
+        l = j / 11;
+        month = j + 2 - 12 * l;
+        year = 100 * (n - 49) + i + l;
+    } else {
+        let mut j = jdn + 1402;
+        let k = (j - 1) / 1461;
+        let l = j - 1461 * k;
+        let n = (l - 1) / 365 - l / 1461;
+        let mut i = l - 365 * n + 30;
+        j = 80 * i / 2447;
+        day = i - 2447 * j / 80;
+        i = j / 11;
+        month = j + 2 - 12 * i;
+        year = 4 * k + n + i - 4716;
     }
+
+    (year as i32, month as i32 - 1, day as i32)
+}
+
+// Returns `tm` moved by `months` months, keeping its time of day and clamping its day to
+// the length of resulting month
+const fn shift_persian_months(tm: &Tm, months: i32) -> Tm {
+    let total = tm.tm_year * 12 + tm.tm_mon + months;
+    let (year, month) = (total.div_euclid(12), total.rem_euclid(12));
+    let max = get_persian_month_days(year, month);
+    let day = if tm.tm_mday < max { tm.tm_mday } else { max };
+    with_persian_jdn(tm, get_jdn(year, month + 1, day))
 }
 
+// Returns the weekday since Sunday of the weekday since Shanbeh
 fn get_gregorian_weekday(wd: i32) -> i32 {
     match wd {
         0 => 6,
@@ -500,7 +1809,20 @@ fn get_gregorian_weekday(wd: i32) -> i32 {
     }
 }
 
-fn get_persian_yday(month: i32, day: i32) -> i32 {
+fn from_persian_ordinal(year: i32, yday: i32) -> Option<Tm> {
+    if yday < 0 || yday >= get_persian_year_days(year) {
+        return None
+    }
+
+    let month = if yday < 186 {
+        yday / 31
+    } else {
+        (yday - 186) / 30 + 6
+    };
+    from_persian_date(year, month, yday - get_persian_yday(month, 1) + 1)
+}
+
+const fn get_persian_yday(month: i32, day: i32) -> i32 {
     [
         0,   // Farvardin
         31,  // Ordibehesht
@@ -517,36 +1839,15 @@ fn get_persian_yday(month: i32, day: i32) -> i32 {
     ][month as usize] + day - 1
 }
 
-fn get_gregorian_yday(year: i32, month: i32, day: i32) -> i32 {
-    [
-        [0, 0],
-        [31, 31],
-        [59, 60],
-        [90, 91],
-        [120, 121],
-        [151, 152],
-        [181, 182],
-        [212, 213],
-        [243, 244],
-        [273, 274],
-        [304, 305],
-        [334, 335],
-    ][month as usize][is_gregorian_leap(year) as usize] + day - 1
-}
-
-fn is_persian_leap(year: i32) -> bool {
-    divider(25 * year + 11, 33) < 8
+const fn is_persian_leap(year: i32) -> bool {
+    (25 * year + 11).rem_euclid(33) < 8
 }
 
-fn is_gregorian_leap(year: i32) -> bool {
+const fn is_gregorian_leap(year: i32) -> bool {
     year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
 }
 
-fn is_persian_date_valid(year: i32, month: i32, day: i32) -> bool {
-    if month < 0 || month > 11 {
-        return false
-    }
-
+const fn get_persian_month_days(year: i32, month: i32) -> i32 {
     [
         [31, 31],
         [31, 31],
@@ -560,14 +1861,18 @@ fn is_persian_date_valid(year: i32, month: i32, day: i32) -> bool {
         [30, 30],
         [30, 30],
         [29, 30],
-    ][month as usize][is_persian_leap(year) as usize] >= day
+    ][month as usize][is_persian_leap(year) as usize]
 }
 
-fn is_gregorian_date_valid(year: i32, month: i32, day: i32) -> bool {
-    if month < 0 || month > 11 {
-        return false
+const fn get_persian_year_days(year: i32) -> i32 {
+    if is_persian_leap(year) {
+        366
+    } else {
+        365
     }
+}
 
+const fn get_gregorian_month_days(year: i32, month: i32) -> i32 {
     [
         [31, 31],
         [28, 29],
@@ -581,9 +1886,6 @@ fn is_gregorian_date_valid(year: i32, month: i32, day: i32) -> bool {
         [31, 31],
         [30, 30],
         [31, 31],
-    ][month as usize][is_gregorian_leap(year) as usize] >= day
+    ][month as usize][is_gregorian_leap(year) as usize]
 }
 
-fn is_time_valid(hour: i32, minute: i32, second: i32, nanosecond: i32) -> bool {
-    !(hour < 0 || hour > 23 || minute < 0 || minute > 59 || second < 0 || second > 59 || nanosecond < 0 || nanosecond > 999999999)
-}
@@ -0,0 +1,405 @@
+//! Provides the names of months, weekdays and 12-Hour markers used by the formatter.
+
+use alloc::string::String;
+
+/// Represents a set of names used to format a `ptime::Tm`.
+pub trait Locale {
+    /// Returns the name of month since Farvardin - [0, 11]
+    fn month_name(&self, month: i32) -> &str;
+
+    /// Returns the name of weekday since Shanbeh - [0, 6]
+    fn weekday_name(&self, weekday: i32) -> &str;
+
+    /// Returns the short name of weekday since Shanbeh - [0, 6]
+    fn weekday_short_name(&self, weekday: i32) -> &str;
+
+    /// Returns the name of 12-Hour marker of hour - [0, 23]
+    fn marker_name(&self, hour: i32) -> &str;
+
+    /// Returns the short name of 12-Hour marker of hour - [0, 23]
+    fn marker_short_name(&self, hour: i32) -> &str;
+
+    /// Returns the name of the Solar Hijri era
+    fn era_name(&self) -> &str;
+
+    /// Returns the short name of the Solar Hijri era
+    fn era_short_name(&self) -> &str;
+
+    /// Returns the name of the era before the Solar Hijri epoch, in Persian by default
+    fn before_era_name(&self) -> &str {
+        PERSIAN_ERAS[1]
+    }
+
+    /// Returns the short name of the era before the Solar Hijri epoch, in Persian by default
+    fn before_era_short_name(&self) -> &str {
+        PERSIAN_SHORT_ERAS[1]
+    }
+}
+
+/// The Persian (Farsi) locale used by default
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Persian;
+
+/// The Afghan Dari locale
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Dari;
+
+/// The Kurdish (Sorani) locale
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Kurdish;
+
+/// The Tajik (Cyrillic) locale
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Tajik;
+
+/// The Persian locale transliterated to Latin script (e.g. Farvardin, Shanbeh)
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Transliterated;
+
+pub(crate) static PERSIAN_MONTHS: [&str; 12] = [
+    "فروردین",
+    "اردیبهشت",
+    "خرداد",
+    "تیر",
+    "مرداد",
+    "شهریور",
+    "مهر",
+    "آبان",
+    "آذر",
+    "دی",
+    "بهمن",
+    "اسفند",
+];
+
+pub(crate) static PERSIAN_WEEKDAYS: [&str; 7] = [
+    "شنبه",
+    "یک‌شنبه",
+    "دوشنبه",
+    "سه‌شنبه",
+    "چهارشنبه",
+    "پنج‌شنبه",
+    "جمعه",
+];
+
+pub(crate) static PERSIAN_SHORT_WEEKDAYS: [&str; 7] = ["ش", "ی", "د", "س", "چ", "پ", "ج"];
+
+pub(crate) static PERSIAN_MARKERS: [&str; 2] = ["قبل از ظهر", "بعد از ظهر"];
+
+pub(crate) static PERSIAN_SHORT_MARKERS: [&str; 2] = ["ق.ظ", "ب.ظ"];
+
+pub(crate) static PERSIAN_ERAS: [&str; 2] = ["هجری شمسی", "پیش از هجرت"];
+
+pub(crate) static PERSIAN_SHORT_ERAS: [&str; 2] = ["ه.ش.", "پ.ه."];
+
+static DARI_MONTHS: [&str; 12] = [
+    "حمل",
+    "ثور",
+    "جوزا",
+    "سرطان",
+    "اسد",
+    "سنبله",
+    "میزان",
+    "عقرب",
+    "قوس",
+    "جدی",
+    "دلو",
+    "حوت",
+];
+
+static KURDISH_MONTHS: [&str; 12] = [
+    "خاکەلێوە",
+    "گوڵان",
+    "جۆزەردان",
+    "پووشپەڕ",
+    "گەلاوێژ",
+    "خەرمانان",
+    "ڕەزبەر",
+    "گەڵاڕێزان",
+    "سەرماوەز",
+    "بەفرانبار",
+    "ڕێبەندان",
+    "ڕەشەمە",
+];
+
+static KURDISH_WEEKDAYS: [&str; 7] = [
+    "شەممە",
+    "یەکشەممە",
+    "دووشەممە",
+    "سێشەممە",
+    "چوارشەممە",
+    "پێنجشەممە",
+    "هەینی",
+];
+
+static KURDISH_SHORT_WEEKDAYS: [&str; 7] = ["ش", "ی", "د", "س", "چ", "پ", "ه"];
+
+static TAJIK_MONTHS: [&str; 12] = [
+    "Ҳамал",
+    "Савр",
+    "Ҷавзо",
+    "Саратон",
+    "Асад",
+    "Сунбула",
+    "Мизон",
+    "Ақраб",
+    "Қавс",
+    "Ҷадӣ",
+    "Далв",
+    "Ҳут",
+];
+
+static TAJIK_WEEKDAYS: [&str; 7] = [
+    "Шанбе",
+    "Якшанбе",
+    "Душанбе",
+    "Сешанбе",
+    "Чоршанбе",
+    "Панҷшанбе",
+    "Ҷумъа",
+];
+
+static TAJIK_SHORT_WEEKDAYS: [&str; 7] = ["Шнб", "Яшб", "Дшб", "Сшб", "Чшб", "Пшб", "Ҷмъ"];
+
+pub(crate) static TRANSLITERATED_MONTHS: [&str; 12] = [
+    "Farvardin",
+    "Ordibehesht",
+    "Khordad",
+    "Tir",
+    "Mordad",
+    "Shahrivar",
+    "Mehr",
+    "Aban",
+    "Azar",
+    "Dey",
+    "Bahman",
+    "Esfand",
+];
+
+pub(crate) static TRANSLITERATED_WEEKDAYS: [&str; 7] = [
+    "Shanbeh",
+    "Yekshanbeh",
+    "Doshanbeh",
+    "Seshanbeh",
+    "Chaharshanbeh",
+    "Panjshanbeh",
+    "Jomeh",
+];
+
+static TRANSLITERATED_SHORT_WEEKDAYS: [&str; 7] = ["Sh", "Ye", "Do", "Se", "Ch", "Pa", "Jo"];
+
+impl Locale for Persian {
+    fn month_name(&self, month: i32) -> &str {
+        name_of(&PERSIAN_MONTHS, month, "month")
+    }
+
+    fn weekday_name(&self, weekday: i32) -> &str {
+        name_of(&PERSIAN_WEEKDAYS, weekday, "weekday")
+    }
+
+    fn weekday_short_name(&self, weekday: i32) -> &str {
+        name_of(&PERSIAN_SHORT_WEEKDAYS, weekday, "weekday")
+    }
+
+    fn marker_name(&self, hour: i32) -> &str {
+        PERSIAN_MARKERS[(hour >= 12) as usize]
+    }
+
+    fn marker_short_name(&self, hour: i32) -> &str {
+        PERSIAN_SHORT_MARKERS[(hour >= 12) as usize]
+    }
+
+    fn era_name(&self) -> &str {
+        PERSIAN_ERAS[0]
+    }
+
+    fn era_short_name(&self) -> &str {
+        PERSIAN_SHORT_ERAS[0]
+    }
+}
+
+impl Locale for Dari {
+    fn month_name(&self, month: i32) -> &str {
+        name_of(&DARI_MONTHS, month, "month")
+    }
+
+    fn weekday_name(&self, weekday: i32) -> &str {
+        Persian.weekday_name(weekday)
+    }
+
+    fn weekday_short_name(&self, weekday: i32) -> &str {
+        Persian.weekday_short_name(weekday)
+    }
+
+    fn marker_name(&self, hour: i32) -> &str {
+        Persian.marker_name(hour)
+    }
+
+    fn marker_short_name(&self, hour: i32) -> &str {
+        Persian.marker_short_name(hour)
+    }
+
+    fn era_name(&self) -> &str {
+        Persian.era_name()
+    }
+
+    fn era_short_name(&self) -> &str {
+        Persian.era_short_name()
+    }
+}
+
+impl Locale for Kurdish {
+    fn month_name(&self, month: i32) -> &str {
+        name_of(&KURDISH_MONTHS, month, "month")
+    }
+
+    fn weekday_name(&self, weekday: i32) -> &str {
+        name_of(&KURDISH_WEEKDAYS, weekday, "weekday")
+    }
+
+    fn weekday_short_name(&self, weekday: i32) -> &str {
+        name_of(&KURDISH_SHORT_WEEKDAYS, weekday, "weekday")
+    }
+
+    fn marker_name(&self, hour: i32) -> &str {
+        if hour < 12 {
+            "پێش نیوەڕۆ"
+        } else {
+            "دوای نیوەڕۆ"
+        }
+    }
+
+    fn marker_short_name(&self, hour: i32) -> &str {
+        if hour < 12 {
+            "پ.ن"
+        } else {
+            "د.ن"
+        }
+    }
+
+    fn era_name(&self) -> &str {
+        "کۆچی هەتاوی"
+    }
+
+    fn era_short_name(&self) -> &str {
+        "ک.ه."
+    }
+
+    fn before_era_name(&self) -> &str {
+        "پێش کۆچ"
+    }
+
+    fn before_era_short_name(&self) -> &str {
+        "پ.ک."
+    }
+}
+
+impl Locale for Tajik {
+    fn month_name(&self, month: i32) -> &str {
+        name_of(&TAJIK_MONTHS, month, "month")
+    }
+
+    fn weekday_name(&self, weekday: i32) -> &str {
+        name_of(&TAJIK_WEEKDAYS, weekday, "weekday")
+    }
+
+    fn weekday_short_name(&self, weekday: i32) -> &str {
+        name_of(&TAJIK_SHORT_WEEKDAYS, weekday, "weekday")
+    }
+
+    fn marker_name(&self, hour: i32) -> &str {
+        if hour < 12 {
+            "пеш аз нисфирӯзӣ"
+        } else {
+            "пас аз нисфирӯзӣ"
+        }
+    }
+
+    fn marker_short_name(&self, hour: i32) -> &str {
+        if hour < 12 {
+            "пе. чо."
+        } else {
+            "па. чо."
+        }
+    }
+
+    fn era_name(&self) -> &str {
+        "ҳиҷрии шамсӣ"
+    }
+
+    fn era_short_name(&self) -> &str {
+        "ҳ.ш."
+    }
+
+    fn before_era_name(&self) -> &str {
+        "пеш аз ҳиҷрат"
+    }
+
+    fn before_era_short_name(&self) -> &str {
+        "п.ҳ."
+    }
+}
+
+impl Locale for Transliterated {
+    fn month_name(&self, month: i32) -> &str {
+        name_of(&TRANSLITERATED_MONTHS, month, "month")
+    }
+
+    fn weekday_name(&self, weekday: i32) -> &str {
+        name_of(&TRANSLITERATED_WEEKDAYS, weekday, "weekday")
+    }
+
+    fn weekday_short_name(&self, weekday: i32) -> &str {
+        name_of(&TRANSLITERATED_SHORT_WEEKDAYS, weekday, "weekday")
+    }
+
+    fn marker_name(&self, hour: i32) -> &str {
+        if hour < 12 {
+            "Ghabl az Zohr"
+        } else {
+            "Bad az Zohr"
+        }
+    }
+
+    fn marker_short_name(&self, hour: i32) -> &str {
+        if hour < 12 {
+            "AM"
+        } else {
+            "PM"
+        }
+    }
+
+    fn era_name(&self) -> &str {
+        "Anno Persico"
+    }
+
+    fn era_short_name(&self) -> &str {
+        "AP"
+    }
+
+    fn before_era_name(&self) -> &str {
+        "Before Persian"
+    }
+
+    fn before_era_short_name(&self) -> &str {
+        "BP"
+    }
+}
+
+/// Replaces the ASCII digits of `s` with Persian digits (e.g. 1403 to ۱۴۰۳)
+pub fn to_persian_digits(s: &str) -> String {
+    s.chars()
+        .map(|c| match c.to_digit(10) {
+            Some(digit) => PERSIAN_DIGITS[digit as usize],
+            None => c,
+        })
+        .collect()
+}
+
+static PERSIAN_DIGITS: [char; 10] = ['۰', '۱', '۲', '۳', '۴', '۵', '۶', '۷', '۸', '۹'];
+
+fn name_of<'a>(names: &[&'a str], index: i32, kind: &str) -> &'a str {
+    match names.get(index as usize) {
+        Some(name) if index >= 0 => name,
+        _ => panic!("invalid {} value of {}", kind, index),
+    }
+}
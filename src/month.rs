@@ -0,0 +1,114 @@
+// Months of Persian calendar
+
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt;
+
+/// Represents a month of Persian calendar
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub enum Month {
+    Farvardin,
+    Ordibehesht,
+    Khordad,
+    Tir,
+    Mordad,
+    Shahrivar,
+    Mehr,
+    Aban,
+    Azar,
+    Dey,
+    Bahman,
+    Esfand,
+}
+
+pub(crate) static MONTHS: [Month; 12] = [
+    Month::Farvardin,
+    Month::Ordibehesht,
+    Month::Khordad,
+    Month::Tir,
+    Month::Mordad,
+    Month::Shahrivar,
+    Month::Mehr,
+    Month::Aban,
+    Month::Azar,
+    Month::Dey,
+    Month::Bahman,
+    Month::Esfand,
+];
+
+impl Month {
+    /// Returns the month of `tm_mon` since Farvardin - [0, 11]
+    pub const fn from_index(index: i32) -> Option<Month> {
+        if index < 0 || index > 11 {
+            return None
+        }
+        Some(MONTHS[index as usize])
+    }
+
+    /// Returns the month since Farvardin as used by `tm_mon` - [0, 11]
+    pub const fn index(&self) -> i32 {
+        *self as i32
+    }
+
+    /// Returns the number of month - [1, 12]. 1 = Farvardin, ..., 12 = Esfand.
+    pub const fn number(&self) -> i32 {
+        self.index() + 1
+    }
+
+    /// Returns the Persian name of month
+    pub const fn name(&self) -> &'static str {
+        ::locale::PERSIAN_MONTHS[self.index() as usize]
+    }
+
+    /// Returns the number of days of month in `year` - [29, 31]
+    pub const fn days(&self, year: i32) -> i32 {
+        ::get_persian_month_days(year, self.index())
+    }
+
+    /// Returns the next month, where Esfand is followed by Farvardin
+    pub const fn succ(&self) -> Month {
+        MONTHS[(self.index() as usize + 1) % 12]
+    }
+
+    /// Returns the previous month, where Farvardin is preceded by Esfand
+    pub const fn pred(&self) -> Month {
+        MONTHS[(self.index() as usize + 11) % 12]
+    }
+}
+
+impl fmt::Display for Month {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Represents how calendar arithmetic handles a day missing from the resulting month (e.g. 31 of
+/// Mehr or 30 of Esfand in a common year)
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum EndOfMonth {
+    /// Moves the day back to the last day of month (e.g. 31 Farvardin + 6 months is 30 Mehr)
+    Clamp,
+    /// Carries the extra days to the next month (e.g. 31 Farvardin + 6 months is 1 Aban)
+    Overflow,
+}
+
+/// Represents the error of converting a number out of [1, 12] to `Month`
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct InvalidMonth(pub i32);
+
+impl fmt::Display for InvalidMonth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid month number of {}", self.0)
+    }
+}
+
+impl Error for InvalidMonth {}
+
+// Converts the number of month [1, 12] as returned by `Month::number`
+impl TryFrom<i32> for Month {
+    type Error = InvalidMonth;
+
+    fn try_from(number: i32) -> Result<Month, InvalidMonth> {
+        Month::from_index(number - 1).ok_or(InvalidMonth(number))
+    }
+}
@@ -0,0 +1,190 @@
+//! Provides the occasions of days (مناسبت روز) as shown by the printed calendars of Iran, i.e. the
+//! public holidays (see `holidays`), the cultural occasions, the world days and the occasions
+//! registered by `Occasions`.
+//!
+//! The evening occasions are on the date of their evening, e.g. Shab-e Yalda on 30 Azar and
+//! Charshanbe Suri on the eve of the last Chaharshanbeh before Nowruz.
+
+use alloc::vec::Vec;
+use holidays::{self, HijriMonthStarts, Holiday};
+use {PersianDate, Tm, Weekday};
+
+/// Represents the category of an occasion
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum Category {
+    /// The national days, e.g. the anniversary of the revolution
+    National,
+    /// The days of Hijri calendar, e.g. Eid al-Fitr
+    Religious,
+    /// The ancient Iranian festivals, e.g. Mehregan
+    Ancient,
+    /// The international days of Gregorian calendar, e.g. the world environment day
+    World,
+}
+
+/// Represents an occasion on a date
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Occasion {
+    date: PersianDate,
+    name: &'static str,
+    category: Category,
+    holiday: bool,
+}
+
+impl Occasion {
+    /// Creates a new occasion of `category` named `name` on `date`, which is not a holiday
+    pub const fn new(date: PersianDate, name: &'static str, category: Category) -> Occasion {
+        Occasion { date, name, category, holiday: false }
+    }
+
+    /// Returns the date of occasion
+    pub const fn date(&self) -> PersianDate {
+        self.date
+    }
+
+    /// Returns the Persian name of occasion (e.g. شب یلدا)
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the category of occasion
+    pub const fn category(&self) -> Category {
+        self.category
+    }
+
+    /// Returns true if the occasion is a public holiday
+    pub const fn is_holiday(&self) -> bool {
+        self.holiday
+    }
+}
+
+// The fixed holidays are national and the lunar ones are religious
+impl From<Holiday> for Occasion {
+    fn from(holiday: Holiday) -> Occasion {
+        let category = if holiday.is_lunar() { Category::Religious } else { Category::National };
+        Occasion { date: holiday.date(), name: holiday.name(), category, holiday: true }
+    }
+}
+
+/// Represents the occasions of days, including the occasions registered by user, e.g. of an
+/// organization
+#[derive(Clone, PartialEq, Eq, Debug, Default, Hash)]
+pub struct Occasions {
+    starts: HijriMonthStarts,
+    yearly: Vec<(i32, i32, &'static str, Category)>,
+    dates: Vec<Occasion>,
+}
+
+impl Occasions {
+    /// Creates the occasions whose lunar holidays follow the tabular Islamic calendar
+    pub fn new() -> Occasions {
+        Occasions::default()
+    }
+
+    /// Creates the occasions whose lunar holidays follow the observed Hijri months of `starts`
+    pub fn observed(starts: HijriMonthStarts) -> Occasions {
+        Occasions { starts, ..Occasions::default() }
+    }
+
+    /// Returns the occasions with the occasion of `category` named `name` on the month since
+    /// Farvardin [0, 11] and the day of every year
+    pub fn with_yearly(mut self, month: i32, day: i32, name: &'static str, category: Category) -> Occasions {
+        self.yearly.push((month, day, name, category));
+        self
+    }
+
+    /// Returns the occasions with `occasion` on its date only
+    pub fn with_occasion(mut self, occasion: Occasion) -> Occasions {
+        self.dates.push(occasion);
+        self
+    }
+
+    /// Returns the occasions on the date of `tm`, i.e. the public holidays, the cultural
+    /// occasions, the world days and the registered occasions in order
+    pub fn occasions_on(&self, tm: &Tm) -> Vec<Occasion> {
+        let date = PersianDate::from_tm(tm);
+        let mut occasions: Vec<Occasion> = holidays::holidays_in_year_observed(tm.tm_year, &self.starts).into_iter()
+            .filter(|holiday| holiday.date() == date)
+            .map(Occasion::from)
+            .collect();
+        occasions.extend(cultural_occasions_on(tm));
+        let (_, g_month, g_day) = tm.to_gregorian_date();
+        occasions.extend(WORLD_DAYS.iter()
+            .filter(|&&(month, day, _)| g_month == month && g_day == day)
+            .map(|&(_, _, name)| Occasion::new(date, name, Category::World)));
+        occasions.extend(self.yearly.iter()
+            .filter(|&&(month, day, _, _)| tm.tm_mon == month && tm.tm_mday == day)
+            .map(|&(_, _, name, category)| Occasion::new(date, name, category)));
+        occasions.extend(self.dates.iter().filter(|occasion| occasion.date == date).cloned());
+        occasions
+    }
+}
+
+// The month since Farvardin, the day and the name of the ancient festivals on fixed dates in the
+// order of the year
+static FIXED_OCCASIONS: [(i32, i32, &str); 12] = [
+    (0, 13, "سیزده‌به‌در"),
+    (1, 3, "جشن اردیبهشتگان"),
+    (2, 6, "جشن خردادگان"),
+    (3, 13, "جشن تیرگان"),
+    (4, 7, "جشن امردادگان"),
+    (5, 4, "جشن شهریورگان"),
+    (6, 16, "جشن مهرگان"),
+    (7, 10, "جشن آبانگان"),
+    (8, 9, "جشن آذرگان"),
+    (8, 30, "شب یلدا"),
+    (10, 10, "جشن سده"),
+    (10, 29, "جشن سپندارمذگان"),
+];
+
+// The month since January, the day and the name of the world days of Gregorian calendar in the
+// order of the Persian year
+static WORLD_DAYS: [(i32, i32, &str); 5] = [
+    (4, 1, "روز جهانی کارگر"),
+    (5, 5, "روز جهانی محیط زیست"),
+    (9, 4, "روز جهانی حیوانات"),
+    (0, 1, "آغاز سال نو میلادی"),
+    (2, 8, "روز جهانی زن"),
+];
+
+/// Returns the occasions on the date of `tm` (see `Occasions::occasions_on`), where the lunar
+/// holidays follow the tabular Islamic calendar
+pub fn occasions_on(tm: &Tm) -> Vec<Occasion> {
+    Occasions::new().occasions_on(tm)
+}
+
+/// Returns the cultural occasions of the Persian year in the order of date, which is empty if year
+/// is out of the supported range
+pub fn cultural_occasions_in_year(year: i32) -> Vec<Occasion> {
+    if PersianDate::new(year, 0, 1).is_none() {
+        return Vec::new()
+    }
+    let mut occasions: Vec<Occasion> = FIXED_OCCASIONS.iter()
+        .map(|&(month, day, name)| Occasion::new(PersianDate::new_unchecked(year, month, day), name, Category::Ancient))
+        .collect();
+    occasions.push(Occasion::new(charshanbe_suri(year), "چهارشنبه‌سوری", Category::Ancient));
+    occasions.sort_by_key(|occasion| occasion.date);
+    occasions
+}
+
+/// Returns the cultural occasions on the date of `tm`
+pub fn cultural_occasions_on(tm: &Tm) -> Vec<Occasion> {
+    let date = PersianDate::from_tm(tm);
+    let mut occasions: Vec<Occasion> = FIXED_OCCASIONS.iter()
+        .filter(|&&(month, day, _)| tm.tm_mon == month && tm.tm_mday == day)
+        .map(|&(_, _, name)| Occasion::new(date, name, Category::Ancient))
+        .collect();
+    if tm.tm_mon == 11 && charshanbe_suri(tm.tm_year) == date {
+        occasions.push(Occasion::new(date, "چهارشنبه‌سوری", Category::Ancient));
+    }
+    occasions
+}
+
+// Returns the date of Charshanbe Suri, i.e. the eve of the last Chaharshanbeh before Nowruz,
+// which is a week earlier if the year ends on Seshanbeh
+fn charshanbe_suri(year: i32) -> PersianDate {
+    // The months before Esfand have 336 days
+    let last = PersianDate::new_unchecked(year, 11, ::days_in_year(year) - 336);
+    let days_after = (last.weekday() as i32 - Weekday::Chaharshanbeh as i32).rem_euclid(7);
+    PersianDate::new_unchecked(year, 11, last.day() - days_after - 1)
+}
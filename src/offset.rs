@@ -0,0 +1,85 @@
+// Offsets from UTC used to convert instants to Persian time
+
+use core::fmt;
+#[cfg(feature = "std")]
+use std::sync::RwLock;
+use Timespec;
+
+// The offset used as the local timezone, where `Offset::Local` falls back to the host
+#[cfg(feature = "std")]
+static DEFAULT_OFFSET: RwLock<Offset> = RwLock::new(Offset::Local);
+
+/// Represents how the offset from UTC of a time is determined
+///
+/// `ptime::Tm` stores the resolved offset in `tm_utcoff`, so a time converted with
+/// `Offset::Local` keeps the offset of the local timezone at its instant.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum Offset {
+    /// The offset of zero
+    Utc,
+    /// The offset of the local timezone at the instant, including daylight saving time, which is
+    /// the timezone of host unless another one is set by `set_default_offset` (UTC without the
+    /// `std` feature)
+    ///
+    /// The timezone of host is read from `TZ` or `/etc/localtime` on Unix, from the settings of
+    /// Windows and from the JavaScript runtime with the `wasm` feature, and is UTC on the other
+    /// platforms (e.g. WASI), where `set_default_offset` sets the local timezone instead.
+    Local,
+    /// The fixed offset in seconds east of UTC, e.g. `Offset::Fixed(12600)` for +03:30
+    Fixed(i32),
+    /// The offset of Tehran (+03:30 or +04:30 in daylight saving time), including the history of
+    /// daylight saving time from 1977 to 2022, regardless of the local timezone
+    Tehran,
+}
+
+impl Offset {
+    /// Returns the offset in seconds east of UTC at the instant of `clock`
+    pub fn utc_offset_at(&self, clock: Timespec) -> i32 {
+        self.resolve(clock).0
+    }
+
+    // Returns the offset and the daylight saving flag at the instant of `clock`
+    pub(crate) fn resolve(&self, clock: Timespec) -> (i32, i32) {
+        match *self {
+            Offset::Utc => (0, 0),
+            #[cfg(feature = "std")]
+            Offset::Local => match default_offset() {
+                Offset::Local => ::sys::local_offset(clock),
+                offset => offset.resolve(clock),
+            },
+            #[cfg(not(feature = "std"))]
+            Offset::Local => (0, 0),
+            Offset::Fixed(utcoff) => (utcoff, 0),
+            Offset::Tehran => ::tehran::resolve(clock),
+        }
+    }
+}
+
+// Prints `UTC`, `Local`, `Asia/Tehran` or the fixed offset, e.g. `+03:30`
+impl fmt::Display for Offset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Offset::Utc => f.write_str("UTC"),
+            Offset::Local => f.write_str("Local"),
+            Offset::Tehran => f.write_str("Asia/Tehran"),
+            Offset::Fixed(utcoff) => write!(f, "{}{:02}:{:02}",
+                                            if utcoff < 0 { '-' } else { '+' },
+                                            utcoff.abs() / 3600,
+                                            utcoff.abs() % 3600 / 60),
+        }
+    }
+}
+
+/// Sets the offset used as the local timezone by `now`, `at` and `Tm::to_local` in the whole
+/// process, e.g. `Offset::Tehran` for the servers deployed in UTC (`Offset::Local` restores the
+/// timezone of host)
+#[cfg(feature = "std")]
+pub fn set_default_offset(offset: Offset) {
+    *DEFAULT_OFFSET.write().unwrap_or_else(|e| e.into_inner()) = offset;
+}
+
+/// Returns the offset used as the local timezone (see `set_default_offset`)
+#[cfg(feature = "std")]
+pub fn default_offset() -> Offset {
+    *DEFAULT_OFFSET.read().unwrap_or_else(|e| e.into_inner())
+}
@@ -0,0 +1,140 @@
+// Compact binary representations of Persian dates and times, ordered the same as chronological order
+
+use core::convert::TryFrom;
+use {DateError, PersianDate, PersianTime, Tm, MIN_YEAR};
+
+const DATE_BITS: u32 = 23;
+const MICROSECOND_BITS: u32 = 37;
+const MICROSECONDS_PER_DAY: i64 = 86_400_000_000;
+
+/// Represents a Persian date packed in 23 bits of `u32` as the year since `MIN_YEAR` (14 bits),
+/// the number of month (4 bits) and the day of month (5 bits), so comparing the bits compares
+/// the dates
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct PackedPersianDate(u32);
+
+/// Represents an instant packed in 60 bits of `i64` as the Persian date in UTC (23 bits, see
+/// `PackedPersianDate`) and the microsecond of day (37 bits), so comparing the bits compares the
+/// instants
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct PackedPersianDateTime(i64);
+
+impl PackedPersianDate {
+    /// Packs `date`
+    pub const fn new(date: PersianDate) -> PackedPersianDate {
+        let bits = ((date.year() - MIN_YEAR) as u32) << 9 | (date.month_number() as u32) << 5 | date.day() as u32;
+        PackedPersianDate(bits)
+    }
+
+    /// Creates the packed date from `bits`, returns `None` if they do not represent a date
+    pub const fn from_bits(bits: u32) -> Option<PackedPersianDate> {
+        if bits >> DATE_BITS != 0 {
+            return None
+        }
+        match PersianDate::new((bits >> 9) as i32 + MIN_YEAR, (bits >> 5 & 0xf) as i32 - 1, (bits & 0x1f) as i32) {
+            Some(_) => Some(PackedPersianDate(bits)),
+            None => None,
+        }
+    }
+
+    /// Returns the bits of packed date
+    pub const fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// Unpacks the date
+    pub const fn date(&self) -> PersianDate {
+        PersianDate::new_unchecked((self.0 >> 9) as i32 + MIN_YEAR, (self.0 >> 5 & 0xf) as i32 - 1, (self.0 & 0x1f) as i32)
+    }
+
+    /// Converts the date to Persian time at midnight in UTC
+    pub fn to_tm(&self) -> Tm {
+        self.date().to_tm()
+    }
+}
+
+impl PackedPersianDateTime {
+    /// Packs the instant of `tm` in UTC, truncating the nanosecond to microseconds, returns the
+    /// reason if the date in UTC is out of the supported range
+    pub fn new(tm: &Tm) -> Result<PackedPersianDateTime, DateError> {
+        let utc = tm.to_utc();
+        ::validate::persian_date(utc.tm_year, utc.tm_mon, utc.tm_mday)?;
+        let seconds = utc.tm_hour as i64 * 3600 + utc.tm_min as i64 * 60 + utc.tm_sec as i64;
+        let microseconds = seconds * 1_000_000 + utc.tm_nsec as i64 / 1000;
+        let date = PackedPersianDate::new(PersianDate::from_tm(&utc));
+        Ok(PackedPersianDateTime((date.bits() as i64) << MICROSECOND_BITS | microseconds))
+    }
+
+    /// Creates the packed date and time from `bits`, returns `None` if they do not represent an
+    /// instant
+    pub const fn from_bits(bits: i64) -> Option<PackedPersianDateTime> {
+        if bits < 0 || bits >> MICROSECOND_BITS > u32::MAX as i64 || bits & ((1 << MICROSECOND_BITS) - 1) >= MICROSECONDS_PER_DAY {
+            return None
+        }
+        match PackedPersianDate::from_bits((bits >> MICROSECOND_BITS) as u32) {
+            Some(_) => Some(PackedPersianDateTime(bits)),
+            None => None,
+        }
+    }
+
+    /// Returns the bits of packed date and time
+    pub const fn bits(&self) -> i64 {
+        self.0
+    }
+
+    /// Unpacks the date in UTC
+    pub const fn date(&self) -> PersianDate {
+        PackedPersianDate((self.0 >> MICROSECOND_BITS) as u32).date()
+    }
+
+    /// Unpacks the time of day in UTC
+    pub const fn time(&self) -> PersianTime {
+        let microseconds = self.0 & ((1 << MICROSECOND_BITS) - 1);
+        let seconds = (microseconds / 1_000_000) as i32;
+        PersianTime::new_unchecked(seconds / 3600, seconds / 60 % 60, seconds % 60, (microseconds % 1_000_000) as i32 * 1000)
+    }
+
+    /// Converts the instant to Persian time in UTC
+    pub fn to_tm(&self) -> Tm {
+        self.date().and_time(self.time()).to_tm()
+    }
+}
+
+impl From<PersianDate> for PackedPersianDate {
+    fn from(date: PersianDate) -> PackedPersianDate {
+        PackedPersianDate::new(date)
+    }
+}
+
+impl From<PackedPersianDate> for PersianDate {
+    fn from(packed: PackedPersianDate) -> PersianDate {
+        packed.date()
+    }
+}
+
+// Packs the date of `tm`, ignoring the time of day and the offset
+impl From<Tm> for PackedPersianDate {
+    fn from(tm: Tm) -> PackedPersianDate {
+        PackedPersianDate::new(PersianDate::from_tm(&tm))
+    }
+}
+
+impl From<PackedPersianDate> for Tm {
+    fn from(packed: PackedPersianDate) -> Tm {
+        packed.to_tm()
+    }
+}
+
+impl TryFrom<Tm> for PackedPersianDateTime {
+    type Error = DateError;
+
+    fn try_from(tm: Tm) -> Result<PackedPersianDateTime, DateError> {
+        PackedPersianDateTime::new(&tm)
+    }
+}
+
+impl From<PackedPersianDateTime> for Tm {
+    fn from(packed: PackedPersianDateTime) -> Tm {
+        packed.to_tm()
+    }
+}
@@ -0,0 +1,822 @@
+// Parsers of the textual representations of time
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use locale::Locale;
+use core::error::Error;
+use core::fmt;
+use {EndOfMonth, Era, PersianDate, PersianDateTime, PersianPeriod, PersianTime, Tm, FORMAT_TOKENS, GREGORIAN_MONTHS, GREGORIAN_WEEKDAYS};
+
+/// Represents a component of time reported by `ParseError` and `DateError`
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum Component {
+    Year,
+    Month,
+    Day,
+    DayOfYear,
+    Week,
+    Weekday,
+    Hour,
+    Minute,
+    Second,
+    Nanosecond,
+    Offset,
+}
+
+impl fmt::Display for Component {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            Component::Year => "year",
+            Component::Month => "month",
+            Component::Day => "day",
+            Component::DayOfYear => "day of year",
+            Component::Week => "week",
+            Component::Weekday => "weekday",
+            Component::Hour => "hour",
+            Component::Minute => "minute",
+            Component::Second => "second",
+            Component::Nanosecond => "nanosecond",
+            Component::Offset => "offset",
+        })
+    }
+}
+
+/// Represents the reason of failure in parsing time, where positions are byte offsets in the input
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub enum ParseError {
+    /// The input ended at `position` while `expected` was expected
+    TooShort { position: usize, expected: String },
+
+    /// The input at `position` does not match `expected`
+    Invalid { position: usize, expected: String },
+
+    /// The `component` at `position` is out of its range (e.g. the month of 13)
+    OutOfRange { position: usize, component: Component },
+
+    /// The `component` at `position` contradicts the rest of the input (e.g. a wrong weekday)
+    Inconsistent { position: usize, component: Component },
+
+    /// The input has extra characters starting from `position`
+    TrailingCharacters { position: usize },
+
+    /// The input does not provide `component`
+    Missing { component: Component },
+}
+
+impl ParseError {
+    /// Returns the byte offset in the input where the error occurred
+    pub fn position(&self) -> Option<usize> {
+        match *self {
+            ParseError::TooShort { position, .. }
+            | ParseError::Invalid { position, .. }
+            | ParseError::OutOfRange { position, .. }
+            | ParseError::Inconsistent { position, .. }
+            | ParseError::TrailingCharacters { position } => Some(position),
+            ParseError::Missing { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseError::TooShort { position, ref expected } => write!(f, "unexpected end of input at {}, expected {}", position, expected),
+            ParseError::Invalid { position, ref expected } => write!(f, "invalid input at {}, expected {}", position, expected),
+            ParseError::OutOfRange { position, component } => write!(f, "{} out of range at {}", component, position),
+            ParseError::Inconsistent { position, component } => write!(f, "inconsistent {} at {}", component, position),
+            ParseError::TrailingCharacters { position } => write!(f, "trailing characters at {}", position),
+            ParseError::Missing { component } => write!(f, "missing {}", component),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// A parsed number along with its position in the input
+#[derive(Copy, Clone)]
+struct Field {
+    value: i32,
+    position: usize,
+}
+
+impl Field {
+    fn check(self, min: i32, max: i32, component: Component) -> Result<i32, ParseError> {
+        if self.value < min || self.value > max {
+            return Err(ParseError::OutOfRange { position: self.position, component })
+        }
+        Ok(self.value)
+    }
+}
+
+/// A cursor over the input being parsed
+struct Parser<'a> {
+    s: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(s: &'a str) -> Parser<'a> {
+        Parser { s, pos: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.s[self.pos..]
+    }
+
+    fn is_empty(&self) -> bool {
+        self.rest().is_empty()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    /// Returns the error of not finding `expected` at the current position
+    fn error(&self, expected: &str) -> ParseError {
+        if self.is_empty() {
+            ParseError::TooShort { position: self.pos, expected: expected.to_string() }
+        } else {
+            ParseError::Invalid { position: self.pos, expected: expected.to_string() }
+        }
+    }
+
+    /// Consumes `literal` if the input starts with it
+    fn eat(&mut self, literal: &str) -> bool {
+        if self.rest().starts_with(literal) {
+            self.pos += literal.len();
+            return true
+        }
+        false
+    }
+
+    fn expect(&mut self, literal: &str) -> Result<(), ParseError> {
+        if self.eat(literal) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("{:?}", literal)))
+        }
+    }
+
+    /// Consumes the trailing whitespace and fails if anything else remains
+    fn finish(&mut self) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        if !self.is_empty() {
+            return Err(ParseError::TrailingCharacters { position: self.pos })
+        }
+        Ok(())
+    }
+
+    /// Consumes a number of `min` to `max` (at most 9) digits
+    fn number(&mut self, min: usize, max: usize) -> Result<Field, ParseError> {
+        let start = self.pos;
+        let mut value = 0;
+        let mut digits = 0;
+        while digits < max {
+            match self.peek().and_then(digit_value) {
+                Some(digit) => {
+                    value = value * 10 + digit as i32;
+                    digits += 1;
+                    self.bump();
+                }
+                None => break,
+            }
+        }
+        if digits < min {
+            return Err(self.error("digit"))
+        }
+        Ok(Field { value, position: start })
+    }
+
+    /// Consumes a number of `min` to `max` digits optionally preceded by a minus sign
+    fn signed_number(&mut self, min: usize, max: usize) -> Result<Field, ParseError> {
+        let start = self.pos;
+        let negative = self.eat("-");
+        let number = self.number(min, max)?;
+        Ok(Field { value: if negative { -number.value } else { number.value }, position: start })
+    }
+
+    /// Consumes the longest of `names` (ignoring ZWNJ, spaces and Arabic forms of ی and ک) and
+    /// returns its index
+    fn name(&mut self, names: &[&str], expected: &str) -> Result<Field, ParseError> {
+        let mut best: Option<(usize, usize)> = None;
+        for (index, name) in names.iter().enumerate() {
+            if let Some(len) = loose_prefix(self.rest(), name) {
+                if best.is_none_or(|(_, best_len)| len > best_len) {
+                    best = Some((index, len));
+                }
+            }
+        }
+        let (index, len) = best.ok_or_else(|| self.error(expected))?;
+        let position = self.pos;
+        self.pos += len;
+        Ok(Field { value: index as i32, position })
+    }
+
+    /// Consumes a run of alphabetic characters
+    fn word(&mut self) -> &'a str {
+        let start = self.pos;
+        while self.peek().is_some_and(char::is_alphabetic) {
+            self.bump();
+        }
+        &self.s[start..self.pos]
+    }
+
+    /// Consumes one of `names` as a whole word ignoring ASCII case
+    fn word_of(&mut self, names: &[&str], expected: &str) -> Result<Field, ParseError> {
+        let position = self.pos;
+        let word = self.word();
+        match names.iter().position(|name| name.eq_ignore_ascii_case(word)) {
+            Some(index) => Ok(Field { value: index as i32, position }),
+            None => {
+                self.pos = position;
+                Err(self.error(expected))
+            }
+        }
+    }
+}
+
+fn normalize_letter(c: char) -> char {
+    match c {
+        'ي' | 'ى' => 'ی',
+        'ك' => 'ک',
+        _ => c,
+    }
+}
+
+/// Returns the length of the prefix of `s` matching `name`, where ZWNJ and spaces are optional
+fn loose_prefix(s: &str, name: &str) -> Option<usize> {
+    let is_joiner = |c: char| c == '\u{200c}' || c == ' ';
+    let mut input = s.char_indices().peekable();
+    let mut first = true;
+    for expected in name.chars().filter(|c| !is_joiner(*c)) {
+        if !first {
+            while input.peek().is_some_and(|&(_, c)| is_joiner(c)) {
+                input.next();
+            }
+        }
+        first = false;
+        match input.next() {
+            Some((_, c)) if normalize_letter(c).to_lowercase().eq(normalize_letter(expected).to_lowercase()) => {}
+            _ => return None,
+        }
+    }
+    Some(input.peek().map_or(s.len(), |&(i, _)| i))
+}
+
+/// Returns the value of ASCII, Persian or Arabic-Indic digit
+fn digit_value(c: char) -> Option<u32> {
+    match c {
+        '0'..='9' => c.to_digit(10),
+        '۰'..='۹' => Some(c as u32 - '۰' as u32),
+        '٠'..='٩' => Some(c as u32 - '٠' as u32),
+        _ => None,
+    }
+}
+
+/// Creates the Persian date of the parsed year, month [1, 12] and day
+fn persian_date(year: Field, month: Field, day: Field) -> Result<Tm, ParseError> {
+    let month = month.check(1, 12, Component::Month)? - 1;
+    let day = day.check(1, ::get_persian_month_days(year.value, month), Component::Day)?;
+    ::from_persian_date(year.value, month, day).ok_or(ParseError::OutOfRange { position: year.position, component: Component::Year })
+}
+
+/// Creates the Persian date of the parsed Gregorian year, month [1, 12] and day
+fn gregorian_date(year: Field, month: Field, day: Field) -> Result<Tm, ParseError> {
+    let month = month.check(1, 12, Component::Month)? - 1;
+    let day = day.check(1, ::get_gregorian_month_days(year.value, month), Component::Day)?;
+    ::from_gregorian_date(year.value, month, day).ok_or(ParseError::OutOfRange { position: year.position, component: Component::Year })
+}
+
+/// Creates the Persian date of the parsed year and day of year [1, 366]
+fn persian_ordinal(year: Field, yday: Field) -> Result<Tm, ParseError> {
+    let yday = yday.check(1, ::get_persian_year_days(year.value), Component::DayOfYear)?;
+    ::from_persian_ordinal(year.value, yday - 1).ok_or(ParseError::OutOfRange { position: year.position, component: Component::Year })
+}
+
+fn with_time(tm: Tm, hour: i32, minute: i32, second: i32, nanosecond: i32) -> Tm {
+    Tm {
+        tm_hour: hour,
+        tm_min: minute,
+        tm_sec: second,
+        tm_nsec: nanosecond,
+        ..tm
+    }
+}
+
+/// Parses the time zone of RFC 2822 and returns its offset in seconds
+fn rfc2822_zone(parser: &mut Parser) -> Result<i32, ParseError> {
+    if parser.eat("+") || parser.eat("-") {
+        let sign = if parser.s[..parser.pos].ends_with('-') { -1 } else { 1 };
+        let hours = parser.number(2, 2)?.check(0, 23, Component::Offset)?;
+        let minutes = parser.number(2, 2)?.check(0, 59, Component::Offset)?;
+        return Ok(sign * (hours * 3600 + minutes * 60))
+    }
+
+    static ZONES: [&str; 11] = ["UT", "GMT", "Z", "EDT", "EST", "CDT", "CST", "MDT", "MST", "PDT", "PST"];
+    static HOURS: [i32; 11] = [0, 0, 0, -4, -5, -5, -6, -6, -7, -7, -8];
+    let zone = parser.word_of(&ZONES, "time zone")?;
+    Ok(HOURS[zone.value as usize] * 3600)
+}
+
+/// Parses the fraction of second following `.` or `,` and returns it in nanoseconds
+fn fraction(parser: &mut Parser) -> Result<i32, ParseError> {
+    if !parser.eat(".") && !parser.eat(",") {
+        return Ok(0)
+    }
+
+    let mut nanosecond = 0;
+    let mut digits = 0;
+    while let Some(digit) = parser.peek().and_then(digit_value) {
+        if digits < 9 {
+            nanosecond = nanosecond * 10 + digit as i32;
+            digits += 1;
+        }
+        parser.bump();
+    }
+    if digits == 0 {
+        return Err(parser.error("digit"))
+    }
+    Ok(nanosecond * 10i32.pow(9 - digits))
+}
+
+/// Parses the time zone of RFC 3339 (e.g. Z, +03:30 or +0330) and returns its offset in seconds
+fn rfc3339_zone(parser: &mut Parser) -> Result<i32, ParseError> {
+    if parser.eat("Z") || parser.eat("z") {
+        return Ok(0)
+    }
+
+    let sign = if parser.eat("+") {
+        1
+    } else if parser.eat("-") {
+        -1
+    } else {
+        return Err(parser.error("offset"))
+    };
+    let hours = parser.number(2, 2)?.check(0, 23, Component::Offset)?;
+    let minutes = if parser.eat(":") || parser.peek().and_then(digit_value).is_some() {
+        parser.number(2, 2)?.check(0, 59, Component::Offset)?
+    } else {
+        0
+    };
+    Ok(sign * (hours * 3600 + minutes * 60))
+}
+
+/// Parses the RFC 2822 representation of time (e.g. Mon, 21 Mar 2016 10:30:05 +0330)
+pub fn rfc2822(s: &str) -> Result<Tm, ParseError> {
+    let mut parser = Parser::new(s);
+    parser.skip_whitespace();
+
+    let weekday = if parser.peek().is_some_and(char::is_alphabetic) {
+        let weekday = parser.word_of(&GREGORIAN_WEEKDAYS, "weekday name")?;
+        parser.expect(",")?;
+        parser.skip_whitespace();
+        Some(weekday)
+    } else {
+        None
+    };
+
+    let day = parser.number(1, 2)?;
+    parser.skip_whitespace();
+    let month = parser.word_of(&GREGORIAN_MONTHS, "month name")?;
+    parser.skip_whitespace();
+    let year = parser.number(4, 4)?;
+    parser.skip_whitespace();
+    let hour = parser.number(2, 2)?.check(0, 23, Component::Hour)?;
+    parser.expect(":")?;
+    let minute = parser.number(2, 2)?.check(0, 59, Component::Minute)?;
+    let second = if parser.eat(":") {
+        parser.number(2, 2)?.check(0, 59, Component::Second)?
+    } else {
+        0
+    };
+    parser.skip_whitespace();
+    let utcoff = rfc2822_zone(&mut parser)?;
+    parser.finish()?;
+
+    let month = Field { value: month.value + 1, ..month };
+    let mut tm = with_time(gregorian_date(year, month, day)?, hour, minute, second, 0);
+    tm.tm_utcoff = utcoff;
+    match weekday {
+        Some(weekday) if ::get_gregorian_weekday(tm.tm_wday) != weekday.value => {
+            Err(ParseError::Inconsistent { position: weekday.position, component: Component::Weekday })
+        }
+        _ => Ok(tm),
+    }
+}
+
+/// Parses the ordinal representation of Persian date (e.g. 1403-123)
+pub fn ordinal(s: &str) -> Result<Tm, ParseError> {
+    let mut parser = Parser::new(s);
+    parser.skip_whitespace();
+    let year = parser.number(4, 4)?;
+    parser.expect("-")?;
+    let yday = parser.number(3, 3)?;
+    parser.finish()?;
+    persian_ordinal(year, yday)
+}
+
+/// Parses the week-date representation of Persian date (e.g. 1403-W18-3)
+pub fn week_date(s: &str) -> Result<Tm, ParseError> {
+    let mut parser = Parser::new(s);
+    parser.skip_whitespace();
+    let year = parser.number(4, 4)?;
+    parser.expect("-W")?;
+    let week = parser.number(2, 2)?;
+    parser.expect("-")?;
+    let weekday = parser.number(1, 1)?.check(1, 7, Component::Weekday)?;
+    parser.finish()?;
+
+    let week = week.check(1, ::PersianIsoWeek::weeks_in_year(year.value), Component::Week)?;
+    ::PersianIsoWeek::new(year.value, week, weekday)
+        .map(|week| week.to_tm())
+        .ok_or(ParseError::OutOfRange { position: year.position, component: Component::Year })
+}
+
+/// Parses a Persian date with flexible separators and digits, optionally followed by time and
+/// offset (e.g. 1403/1/1, 1403-01-01, ۱۴۰۳٫۱٫۱, 1/1/1403 or 1403/1/1 12:30+03:30)
+pub fn lenient(s: &str) -> Result<Tm, ParseError> {
+    let mut parser = Parser::new(s);
+    parser.skip_whitespace();
+    let mut fields = Vec::new();
+    let mut widths = Vec::new();
+    for i in 0..3 {
+        if i > 0 && !['/', '-', '.', '٫'].iter().any(|separator| parser.eat(&separator.to_string())) {
+            return Err(parser.error("separator"))
+        }
+        let start = parser.pos;
+        fields.push(parser.number(1, 4)?);
+        widths.push(parser.s[start..parser.pos].chars().count());
+    }
+
+    // The year comes last only if it has more than two digits and the day has at most two
+    let (year, month, day) = if widths[0] <= 2 && widths[2] > 2 {
+        (fields[2], fields[1], fields[0])
+    } else if widths[0] == 2 {
+        (Field { value: expand_year(fields[0].value, ::DEFAULT_YEAR_PIVOT), ..fields[0] }, fields[1], fields[2])
+    } else {
+        (fields[0], fields[1], fields[2])
+    };
+    let tm = persian_date(year, month, day)?;
+
+    parser.skip_whitespace();
+    if parser.is_empty() {
+        return Ok(tm)
+    }
+
+    parser.eat("T");
+    parser.skip_whitespace();
+    let hour = parser.number(1, 2)?.check(0, 23, Component::Hour)?;
+    parser.expect(":")?;
+    let minute = parser.number(1, 2)?.check(0, 59, Component::Minute)?;
+    let second = if parser.eat(":") {
+        parser.number(1, 2)?.check(0, 59, Component::Second)?
+    } else {
+        0
+    };
+    parser.skip_whitespace();
+    let utcoff = if parser.is_empty() {
+        0
+    } else {
+        rfc3339_zone(&mut parser)?
+    };
+    parser.finish()?;
+
+    let mut tm = with_time(tm, hour, minute, second, 0);
+    tm.tm_utcoff = utcoff;
+    Ok(tm)
+}
+
+/// Returns the value of number written in Persian words or digits
+fn number_value(word: &str) -> Option<i32> {
+    static WORDS: [&str; 12] = ["یک", "دو", "سه", "چهار", "پنج", "شش", "هفت", "هشت", "نه", "ده", "یازده", "دوازده"];
+    if let Some(index) = WORDS.iter().position(|w| *w == word) {
+        return Some(index as i32 + 1)
+    }
+
+    let mut parser = Parser::new(word);
+    let value = parser.number(1, 9).ok()?;
+    if !parser.is_empty() {
+        return None
+    }
+    Some(value.value)
+}
+
+/// Parses a Persian relative date phrase (e.g. سه روز پیش) relative to `anchor`
+pub fn relative(s: &str, anchor: &Tm) -> Result<Tm, ParseError> {
+    // Splits the phrase into words and their positions, where ZWNJ separates words too
+    let mut words: Vec<(usize, &str)> = Vec::new();
+    let mut start = None;
+    for (i, c) in s.char_indices().chain(Some((s.len(), ' '))) {
+        match (c.is_whitespace() || c == '\u{200c}', start) {
+            (true, Some(begin)) => {
+                if &s[begin..i] != "ی" {
+                    words.push((begin, &s[begin..i]));
+                }
+                start = None;
+            }
+            (false, None) => start = Some(i),
+            _ => {}
+        }
+    }
+
+    let phrase: Vec<&str> = words.iter().map(|&(_, word)| word).collect();
+    let days = match phrase.as_slice() {
+        ["امروز"] => Some(0),
+        ["دیروز"] => Some(-1),
+        ["پریروز"] => Some(-2),
+        ["فردا"] => Some(1),
+        ["پس", "فردا"] => Some(2),
+        _ => None,
+    };
+    if let Some(days) = days {
+        return anchor.checked_add_days(days).ok_or(ParseError::OutOfRange { position: 0, component: Component::Day })
+    }
+
+    let invalid = |index: usize, expected: &str| match words.get(index) {
+        Some(&(position, _)) => ParseError::Invalid { position, expected: expected.to_string() },
+        None => ParseError::TooShort { position: s.len(), expected: expected.to_string() },
+    };
+    let (count, unit, direction) = match words.len() {
+        0 | 1 => return Err(invalid(words.len(), "unit")),
+        2 => (1, 0, 1),
+        3 => (number_value(words[0].1).ok_or_else(|| invalid(0, "number"))?, 1, 2),
+        _ => return Err(ParseError::TrailingCharacters { position: words[3].0 }),
+    };
+    let count = match words[direction].1 {
+        "پیش" | "قبل" | "گذشته" => -count,
+        "بعد" | "دیگر" | "آینده" => count,
+        _ => return Err(invalid(direction, "direction")),
+    };
+
+    let (shifted, component) = match words[unit].1 {
+        "روز" => (anchor.checked_add_days(count), Component::Day),
+        "هفته" => (count.checked_mul(7).and_then(|days| anchor.checked_add_days(days)), Component::Week),
+        "ماه" => (anchor.checked_add_months(count, EndOfMonth::Clamp), Component::Month),
+        "سال" => (count.checked_mul(12).and_then(|months| anchor.checked_add_months(months, EndOfMonth::Clamp)), Component::Year),
+        _ => return Err(invalid(unit, "unit")),
+    };
+    shifted.ok_or(ParseError::OutOfRange { position: words[0].0, component })
+}
+
+/// Parses the period written as signed numbers followed by units, which are y, m, w and d or
+/// سال, ماه, هفته and روز optionally joined by و (e.g. 1y 2m 3d or ۱ سال و منفی ۲ ماه)
+pub fn period(s: &str) -> Result<PersianPeriod, ParseError> {
+    let units = ["y", "m", "w", "d", "سال", "ماه", "هفته", "روز"];
+    let components = [Component::Year, Component::Month, Component::Week, Component::Day];
+    let mut values = [None; 4];
+    let mut parser = Parser::new(s);
+    parser.skip_whitespace();
+    if parser.is_empty() {
+        return Err(ParseError::Missing { component: Component::Day })
+    }
+
+    while !parser.is_empty() {
+        let negative = parser.eat("-") || parser.eat("منفی");
+        parser.skip_whitespace();
+        let number = parser.number(1, 9)?;
+        parser.skip_whitespace();
+        let unit = parser.name(&units, "unit")?;
+        let index = unit.value as usize % 4;
+        if values[index].is_some() {
+            return Err(ParseError::Inconsistent { position: unit.position, component: components[index] })
+        }
+        values[index] = Some(if negative { -number.value } else { number.value });
+
+        parser.skip_whitespace();
+        if parser.eat("و") {
+            parser.skip_whitespace();
+            if parser.is_empty() {
+                return Err(parser.error("digit"))
+            }
+        }
+    }
+
+    let weeks = values[2].unwrap_or(0);
+    let days = weeks.checked_mul(7)
+        .and_then(|days| days.checked_add(values[3].unwrap_or(0)))
+        .ok_or(ParseError::OutOfRange { position: 0, component: Component::Week })?;
+    Ok(PersianPeriod::new(values[0].unwrap_or(0), values[1].unwrap_or(0), days))
+}
+
+/// Parses the ISO 8601 representation of Gregorian time (e.g. 2016-03-21T10:30:05.250+03:30)
+pub fn iso8601(s: &str) -> Result<Tm, ParseError> {
+    timestamp(s, gregorian_date)
+}
+
+/// Parses the RFC 3339 representation of Persian time (e.g. 1395-01-02T10:30:05.250+03:30)
+pub fn rfc3339_persian(s: &str) -> Result<Tm, ParseError> {
+    timestamp(s, persian_date)
+}
+
+/// Parses the date of `date` in the form of ISO 8601, optionally followed by time and offset
+fn timestamp(s: &str, date: fn(Field, Field, Field) -> Result<Tm, ParseError>) -> Result<Tm, ParseError> {
+    let mut parser = Parser::new(s);
+    parser.skip_whitespace();
+    let tm = calendar_date(&mut parser, date)?;
+    if parser.rest().trim().is_empty() {
+        return Ok(tm)
+    }
+
+    if !parser.eat("T") && !parser.eat("t") && !parser.eat(" ") {
+        return Err(parser.error("\"T\""))
+    }
+    let time = clock(&mut parser)?;
+    let utcoff = if parser.rest().trim().is_empty() {
+        0
+    } else {
+        rfc3339_zone(&mut parser)?
+    };
+    parser.finish()?;
+
+    let mut tm = with_time(tm, time.hour(), time.minute(), time.second(), time.nanosecond());
+    tm.tm_utcoff = utcoff;
+    Ok(tm)
+}
+
+/// Parses the Persian date as printed by `PersianDate` (e.g. 1395-01-02)
+pub fn date(s: &str) -> Result<PersianDate, ParseError> {
+    let mut parser = Parser::new(s);
+    parser.skip_whitespace();
+    let tm = calendar_date(&mut parser, persian_date)?;
+    parser.finish()?;
+    Ok(PersianDate::from_tm(&tm))
+}
+
+/// Parses the time of day as printed by `PersianTime` (e.g. 10:30:05.250), where the second and
+/// its fraction are optional
+pub fn time_of_day(s: &str) -> Result<PersianTime, ParseError> {
+    let mut parser = Parser::new(s);
+    parser.skip_whitespace();
+    let time = clock(&mut parser)?;
+    parser.finish()?;
+    Ok(time)
+}
+
+/// Parses the Persian date and the time of day as printed by `PersianDateTime` (e.g.
+/// 1395-01-02T10:30:05.250)
+pub fn date_time(s: &str) -> Result<PersianDateTime, ParseError> {
+    let mut parser = Parser::new(s);
+    parser.skip_whitespace();
+    let tm = calendar_date(&mut parser, persian_date)?;
+    if !parser.eat("T") && !parser.eat("t") && !parser.eat(" ") {
+        return Err(parser.error("\"T\""))
+    }
+    let time = clock(&mut parser)?;
+    parser.finish()?;
+    Ok(PersianDate::from_tm(&tm).and_time(time))
+}
+
+// Consumes the date written as `yyyy-MM-dd`
+fn calendar_date(parser: &mut Parser, date: fn(Field, Field, Field) -> Result<Tm, ParseError>) -> Result<Tm, ParseError> {
+    let year = parser.number(4, 4)?;
+    parser.expect("-")?;
+    let month = parser.number(2, 2)?;
+    parser.expect("-")?;
+    let day = parser.number(2, 2)?;
+    date(year, month, day)
+}
+
+// Consumes the time of day written as `HH:mm`, optionally followed by `:ss` and the fraction
+fn clock(parser: &mut Parser) -> Result<PersianTime, ParseError> {
+    let hour = parser.number(2, 2)?.check(0, 23, Component::Hour)?;
+    parser.expect(":")?;
+    let minute = parser.number(2, 2)?.check(0, 59, Component::Minute)?;
+    let (second, nanosecond) = if parser.eat(":") {
+        (parser.number(2, 2)?.check(0, 59, Component::Second)?, fraction(parser)?)
+    } else {
+        (0, 0)
+    };
+    Ok(PersianTime::new_unchecked(hour, minute, second, nanosecond))
+}
+
+/// Returns the year in `[pivot, pivot + 99]` whose last two digits are `yy`
+pub fn expand_year(yy: i32, pivot: i32) -> i32 {
+    pivot + (yy - pivot % 100).rem_euclid(100)
+}
+
+/// Parses time formatted with `format` (see `Tm::to_string` for the list of supported tokens)
+/// using the names of `locale`, where two-digit years are expanded using `pivot`
+pub fn pattern<L: Locale>(s: &str, format: &str, locale: &L, pivot: i32) -> Result<Tm, ParseError> {
+    let mut parser = Parser::new(s);
+    let mut year = None;
+    let mut month = Field { value: 1, position: 0 };
+    let mut day = Field { value: 1, position: 0 };
+    let mut yday = None;
+    let mut weekday = None;
+    let mut era = None;
+    let mut year_of_era = false;
+    let mut hour = 0;
+    let mut hour12 = None;
+    let mut after_noon = None;
+    let mut minute = 0;
+    let mut second = 0;
+    let mut nanosecond = 0;
+    let mut utcoff = 0;
+
+    let mut rest = format;
+    while let Some(c) = rest.chars().next() {
+        let token = match FORMAT_TOKENS.iter().find(|token| rest.starts_with(*token)) {
+            Some(token) => *token,
+            None => {
+                let literal = &rest[..c.len_utf8()];
+                parser.expect(literal)?;
+                rest = &rest[literal.len()..];
+                continue
+            }
+        };
+        rest = &rest[token.len()..];
+
+        match token {
+            "yyyy" => year = Some(parser.signed_number(4, 4)?),
+            "yyy" | "y" => year = Some(parser.signed_number(1, 4)?),
+            "Y" => {
+                year = Some(parser.number(1, 4)?);
+                year_of_era = true;
+            }
+            "yy" => {
+                let yy = parser.number(2, 2)?;
+                year = Some(Field { value: expand_year(yy.value, pivot), ..yy });
+            }
+            "MMM" => {
+                let name = parser.name(&(0..12).map(|m| locale.month_name(m)).collect::<Vec<_>>(), "month name")?;
+                month = Field { value: name.value + 1, ..name };
+            }
+            "MM" => month = parser.number(2, 2)?,
+            "M" => month = parser.number(1, 2)?,
+            "DD" => yday = Some(parser.number(1, 3)?),
+            "D" => {
+                let field = parser.number(1, 3)?;
+                yday = Some(Field { value: field.value + 1, ..field });
+            }
+            "dd" => day = parser.number(2, 2)?,
+            "d" => day = parser.number(1, 2)?,
+            "E" => weekday = Some(parser.name(&(0..7).map(|w| locale.weekday_name(w)).collect::<Vec<_>>(), "weekday name")?),
+            "e" => weekday = Some(parser.name(&(0..7).map(|w| locale.weekday_short_name(w)).collect::<Vec<_>>(), "weekday name")?),
+            "G" => era = Some(parser.name(&[locale.before_era_name(), locale.era_name()], "era name")?),
+            "g" => era = Some(parser.name(&[locale.before_era_short_name(), locale.era_short_name()], "era name")?),
+            "A" => after_noon = Some(parser.name(&[locale.marker_name(0), locale.marker_name(12)], "12-Hour marker")?),
+            "a" => after_noon = Some(parser.name(&[locale.marker_short_name(0), locale.marker_short_name(12)], "12-Hour marker")?),
+            "HH" => hour = parser.number(2, 2)?.check(0, 23, Component::Hour)?,
+            "H" => hour = parser.number(1, 2)?.check(0, 23, Component::Hour)?,
+            "kk" => hour = parser.number(2, 2)?.check(1, 24, Component::Hour)? - 1,
+            "k" => hour = parser.number(1, 2)?.check(1, 24, Component::Hour)? - 1,
+            "hh" => hour12 = Some(parser.number(2, 2)?.check(1, 12, Component::Hour)? - 1),
+            "h" => hour12 = Some(parser.number(1, 2)?.check(1, 12, Component::Hour)? - 1),
+            "KK" => hour12 = Some(parser.number(2, 2)?.check(0, 11, Component::Hour)?),
+            "K" => hour12 = Some(parser.number(1, 2)?.check(0, 11, Component::Hour)?),
+            "mm" => minute = parser.number(2, 2)?.check(0, 59, Component::Minute)?,
+            "m" => minute = parser.number(1, 2)?.check(0, 59, Component::Minute)?,
+            "ns" => nanosecond = parser.number(1, 9)?.value,
+            "ss" => second = parser.number(2, 2)?.check(0, 59, Component::Second)?,
+            "s" => second = parser.number(1, 2)?.check(0, 59, Component::Second)?,
+            "Z" => utcoff = rfc3339_zone(&mut parser)?,
+            _ => unreachable!(),
+        }
+    }
+    if !parser.is_empty() {
+        return Err(ParseError::TrailingCharacters { position: parser.pos })
+    }
+
+    if let Some(hour12) = hour12 {
+        hour = hour12 + if after_noon.is_some_and(|marker| marker.value == 1) { 12 } else { 0 };
+    } else if let Some(marker) = after_noon {
+        if (marker.value == 1) != (hour >= 12) {
+            return Err(ParseError::Inconsistent { position: marker.position, component: Component::Hour })
+        }
+    }
+
+    let mut year = year.ok_or(ParseError::Missing { component: Component::Year })?;
+    match era {
+        Some(era) if year_of_era && era.value == 0 => year.value = Era::BP.to_year(year.value),
+        Some(era) if !year_of_era && (era.value == 0) != (Era::of_year(year.value) == Era::BP) => {
+            return Err(ParseError::Inconsistent { position: era.position, component: Component::Year })
+        }
+        _ => {}
+    }
+    let tm = match yday {
+        Some(yday) => persian_ordinal(year, yday)?,
+        None => persian_date(year, month, day)?,
+    };
+    match weekday {
+        Some(weekday) if weekday.value != tm.tm_wday => {
+            Err(ParseError::Inconsistent { position: weekday.position, component: Component::Weekday })
+        }
+        _ => {
+            let mut tm = with_time(tm, hour, minute, second, nanosecond);
+            tm.tm_utcoff = utcoff;
+            Ok(tm)
+        }
+    }
+}
@@ -0,0 +1,123 @@
+// Calendar periods of Persian calendar
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+use core::str::FromStr;
+use {locale, EndOfMonth, ParseError, Tm};
+
+/// Represents a period of Persian calendar in years, months and days, unlike `Duration`
+/// whose length does not depend on the months it spans
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+pub struct PersianPeriod {
+    years: i32,
+    months: i32,
+    days: i32,
+}
+
+impl PersianPeriod {
+    /// The empty period
+    pub const ZERO: PersianPeriod = PersianPeriod { years: 0, months: 0, days: 0 };
+
+    /// Creates a new instance of period from the years, the months and the days
+    pub const fn new(years: i32, months: i32, days: i32) -> PersianPeriod {
+        PersianPeriod { years, months, days }
+    }
+
+    /// Creates the period from the date of `from` to the date of `to` (see
+    /// `Tm::years_months_days_since`)
+    pub fn between(from: &Tm, to: &Tm) -> PersianPeriod {
+        let (years, months, days) = to.years_months_days_since(from);
+        PersianPeriod::new(years, months, days)
+    }
+
+    /// Returns the years
+    pub const fn years(&self) -> i32 {
+        self.years
+    }
+
+    /// Returns the months
+    pub const fn months(&self) -> i32 {
+        self.months
+    }
+
+    /// Returns the days
+    pub const fn days(&self) -> i32 {
+        self.days
+    }
+
+    /// Returns true if all components are zero
+    pub const fn is_zero(&self) -> bool {
+        self.years == 0 && self.months == 0 && self.days == 0
+    }
+}
+
+// Prints e.g. `۱ سال و ۲ ماه و ۳ روز`
+impl fmt::Display for PersianPeriod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return f.write_str("۰ روز")
+        }
+
+        let parts: Vec<String> = [(self.years, "سال"), (self.months, "ماه"), (self.days, "روز")]
+            .iter()
+            .filter(|&&(count, _)| count != 0)
+            .map(|&(count, unit)| {
+                let digits = locale::to_persian_digits(&count.unsigned_abs().to_string());
+                if count < 0 {
+                    format!("منفی {} {}", digits, unit)
+                } else {
+                    format!("{} {}", digits, unit)
+                }
+            })
+            .collect();
+        f.write_str(&parts.join(" و "))
+    }
+}
+
+// Parses e.g. `1y 2m 3d`, `-2w` or `۱ سال و ۲ ماه و ۳ روز`
+impl FromStr for PersianPeriod {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<PersianPeriod, ParseError> {
+        ::parse::period(s)
+    }
+}
+
+impl Neg for PersianPeriod {
+    type Output = PersianPeriod;
+
+    fn neg(self) -> PersianPeriod {
+        PersianPeriod::new(-self.years, -self.months, -self.days)
+    }
+}
+
+// Adds the years and the months clamping the day to the resulting month, then adds the days
+impl Add<PersianPeriod> for Tm {
+    type Output = Tm;
+
+    fn add(self, other: PersianPeriod) -> Tm {
+        self.add_months(other.years * 12 + other.months, EndOfMonth::Clamp).add_days(other.days)
+    }
+}
+
+impl Sub<PersianPeriod> for Tm {
+    type Output = Tm;
+
+    fn sub(self, other: PersianPeriod) -> Tm {
+        self + -other
+    }
+}
+
+impl AddAssign<PersianPeriod> for Tm {
+    fn add_assign(&mut self, other: PersianPeriod) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign<PersianPeriod> for Tm {
+    fn sub_assign(&mut self, other: PersianPeriod) {
+        *self = *self - other;
+    }
+}
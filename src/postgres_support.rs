@@ -0,0 +1,49 @@
+// Mappings of PostgreSQL types of the crate postgres-types, enabled by the `postgres-types`
+// feature, where the types are encoded the same as their chrono counterparts:
+//
+// - `PersianDate`: `NaiveDate` as `DATE`
+// - `PersianDateTime`: `NaiveDateTime` as `TIMESTAMP`
+// - `Tm`: `DateTime<Utc>` as `TIMESTAMPTZ`, which decodes to UTC
+
+use bytes::BytesMut;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use postgres_types::{FromSql, IsNull, ToSql, Type};
+use std::convert::TryFrom;
+use std::error::Error;
+use {PersianDate, PersianDateTime, Tm};
+
+macro_rules! postgres_via_chrono {
+    ($type:ty, $chrono:ty, $to_chrono:expr, $from_chrono:expr) => {
+        impl ToSql for $type {
+            fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+                ToSql::to_sql(&$to_chrono(self), ty, out)
+            }
+
+            fn accepts(ty: &Type) -> bool {
+                <$chrono as ToSql>::accepts(ty)
+            }
+
+            ::postgres_types::to_sql_checked!();
+        }
+
+        impl<'a> FromSql<'a> for $type {
+            fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<$type, Box<dyn Error + Sync + Send>> {
+                Ok($from_chrono(<$chrono as FromSql>::from_sql(ty, raw)?)?)
+            }
+
+            fn accepts(ty: &Type) -> bool {
+                <$chrono as FromSql>::accepts(ty)
+            }
+        }
+    };
+}
+
+postgres_via_chrono!(PersianDate, NaiveDate,
+                     |date: &PersianDate| NaiveDate::from(date.to_tm()),
+                     |date| Tm::try_from(date).map(|tm| PersianDate::from_tm(&tm)));
+postgres_via_chrono!(PersianDateTime, NaiveDateTime,
+                     |date_time: &PersianDateTime| NaiveDateTime::from(date_time.to_tm()),
+                     |date_time| Tm::try_from(date_time).map(|tm| PersianDateTime::from_tm(&tm)));
+postgres_via_chrono!(Tm, DateTime<Utc>,
+                     |tm: &Tm| DateTime::<Utc>::from(*tm),
+                     Tm::try_from);
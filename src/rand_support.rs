@@ -0,0 +1,51 @@
+// Sampling of random dates with the crate rand, enabled by the `rand` feature
+
+use rand::distr::Distribution;
+use rand::Rng;
+use {PersianDate, Tm};
+
+/// Samples the dates uniform over the days of a range, as `Tm` at midnight in UTC or as
+/// `PersianDate`
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct UniformDate {
+    start: i32,
+    end: i32,
+}
+
+impl UniformDate {
+    /// Creates the distribution over the dates of [`start`, `end`], ignoring the time of day and
+    /// the offset, returns `None` if `start` is after `end`
+    pub fn new(start: &Tm, end: &Tm) -> Option<UniformDate> {
+        UniformDate::from_jdn(start.to_jdn(), end.to_jdn())
+    }
+
+    /// Creates the distribution over the dates of [`start`, `end`], returns `None` if `start` is
+    /// after `end`
+    pub fn from_dates(start: PersianDate, end: PersianDate) -> Option<UniformDate> {
+        UniformDate::from_jdn(start.to_tm().to_jdn(), end.to_tm().to_jdn())
+    }
+
+    /// Creates the distribution over the dates of the supported range
+    pub fn full() -> UniformDate {
+        UniformDate { start: Tm::MIN.to_jdn(), end: Tm::MAX.to_jdn() }
+    }
+
+    fn from_jdn(start: i32, end: i32) -> Option<UniformDate> {
+        if start > end {
+            return None
+        }
+        Some(UniformDate { start, end })
+    }
+}
+
+impl Distribution<Tm> for UniformDate {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Tm {
+        ::from_jdn(rng.random_range(self.start..=self.end)).expect("day in supported range")
+    }
+}
+
+impl Distribution<PersianDate> for UniformDate {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PersianDate {
+        PersianDate::from_tm(&Distribution::<Tm>::sample(self, rng))
+    }
+}
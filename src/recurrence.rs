@@ -0,0 +1,202 @@
+//! Provides the recurrence rules evaluated in Persian calendar, similar to the RRULE of iCalendar.
+
+use alloc::vec::Vec;
+use alloc::collections::VecDeque;
+use {EndOfMonth, Month, Tm, Weekday};
+
+/// Represents how often a rule recurs
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// Represents a rule of times recurring from a start time in Persian calendar, where the days of
+/// rule (e.g. the day of month of yearly rules) default to the ones of start time, e.g. yearly on
+/// 13 Farvardin with `Recurrence::new(start, Frequency::Yearly)` if `start` is 13 Farvardin
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Recurrence {
+    start: Tm,
+    frequency: Frequency,
+    interval: i32,
+    by_month: Vec<Month>,
+    by_month_day: Vec<i32>,
+    by_day: Vec<Weekday>,
+    count: Option<usize>,
+    until: Option<Tm>,
+}
+
+impl Recurrence {
+    /// Creates a new rule recurring every period of `frequency` from `start`, whose time of day
+    /// and offset are kept by occurrences
+    pub fn new(start: Tm, frequency: Frequency) -> Recurrence {
+        Recurrence {
+            start,
+            frequency,
+            interval: 1,
+            by_month: Vec::new(),
+            by_month_day: Vec::new(),
+            by_day: Vec::new(),
+            count: None,
+            until: None,
+        }
+    }
+
+    /// Sets the number of periods between occurrences (as `INTERVAL`), where values less than 1
+    /// are treated as 1
+    pub fn interval(mut self, interval: i32) -> Recurrence {
+        self.interval = interval.max(1);
+        self
+    }
+
+    /// Limits occurrences to `months` (as `BYMONTH`)
+    pub fn by_month(mut self, months: &[Month]) -> Recurrence {
+        self.by_month = months.to_vec();
+        self
+    }
+
+    /// Limits occurrences to the days of month [1, 31], where negative days count from the end of
+    /// month (as `BYMONTHDAY`, e.g. -1 for 29 or 30 Esfand)
+    pub fn by_month_day(mut self, days: &[i32]) -> Recurrence {
+        self.by_month_day = days.to_vec();
+        self
+    }
+
+    /// Limits occurrences to `weekdays` (as `BYDAY`)
+    pub fn by_day(mut self, weekdays: &[Weekday]) -> Recurrence {
+        self.by_day = weekdays.to_vec();
+        self
+    }
+
+    /// Limits the number of occurrences (as `COUNT`)
+    pub fn count(mut self, count: usize) -> Recurrence {
+        self.count = Some(count);
+        self
+    }
+
+    /// Limits occurrences to the ones not later than `until` (as `UNTIL`)
+    pub fn until(mut self, until: Tm) -> Recurrence {
+        self.until = Some(until);
+        self
+    }
+
+    /// Returns the iterator over the occurrences of rule starting from its start time
+    pub fn iter(&self) -> Occurrences {
+        Occurrences {
+            rule: self.clone(),
+            period: 0,
+            pending: VecDeque::new(),
+            emitted: 0,
+            done: false,
+        }
+    }
+
+    // Returns the start of `period`th period keeping the time of day, or `None` if it is out of
+    // the supported range
+    fn period_start(&self, period: i32) -> Option<Tm> {
+        let start = &self.start;
+        let steps = period.checked_mul(self.interval)?;
+        match self.frequency {
+            Frequency::Daily => start.checked_add_days(steps),
+            Frequency::Weekly => start.add_days(-start.tm_wday).checked_add_days(steps.checked_mul(7)?),
+            Frequency::Monthly => start.add_days(1 - start.tm_mday).checked_add_months(steps, EndOfMonth::Clamp),
+            Frequency::Yearly => start.add_days(-start.tm_yday).checked_add_years(steps, EndOfMonth::Clamp),
+        }
+    }
+
+    // Returns the occurrences of the period starting from `base` in order
+    fn expand(&self, base: Tm) -> Vec<Tm> {
+        let mut days = match self.frequency {
+            Frequency::Daily => vec![base],
+            Frequency::Weekly => {
+                if self.by_day.is_empty() {
+                    vec![base.add_days(self.start.tm_wday)]
+                } else {
+                    self.by_day.iter().map(|weekday| base.add_days(weekday.num_days_from_shanbeh())).collect()
+                }
+            }
+            Frequency::Monthly => self.month_days(base),
+            Frequency::Yearly => {
+                let months: Vec<i32> = if !self.by_month.is_empty() {
+                    self.by_month.iter().map(Month::index).collect()
+                } else if !self.by_day.is_empty() && self.by_month_day.is_empty() {
+                    (0..12).collect()
+                } else {
+                    vec![self.start.tm_mon]
+                };
+                months.into_iter().flat_map(|month| self.month_days(base.add_months(month, EndOfMonth::Clamp))).collect()
+            }
+        };
+        days.retain(|tm| self.matches(tm));
+        days.sort_by_key(Tm::to_jdn);
+        days.dedup();
+        days
+    }
+
+    // Returns the days of the month starting from `base`
+    fn month_days(&self, base: Tm) -> Vec<Tm> {
+        let max = base.days_in_month();
+        let days: Vec<i32> = if !self.by_month_day.is_empty() {
+            self.by_month_day.iter().map(|&day| if day < 0 { max + 1 + day } else { day }).collect()
+        } else if !self.by_day.is_empty() {
+            (1..=max).collect()
+        } else {
+            vec![self.start.tm_mday]
+        };
+        days.into_iter()
+            .filter(|day| (1..=max).contains(day))
+            .map(|day| base.add_days(day - 1))
+            .collect()
+    }
+
+    // Returns true if `tm` satisfies all limits of rule
+    fn matches(&self, tm: &Tm) -> bool {
+        let max = tm.days_in_month();
+        (self.by_month.is_empty() || self.by_month.contains(&tm.month()))
+            && (self.by_month_day.is_empty()
+                || self.by_month_day.iter().any(|&day| day == tm.tm_mday || max + 1 + day == tm.tm_mday))
+            && (self.by_day.is_empty() || self.by_day.contains(&tm.weekday()))
+    }
+}
+
+/// Iterates over the occurrences of a `Recurrence` in order
+#[derive(Clone, Debug)]
+pub struct Occurrences {
+    rule: Recurrence,
+    period: i32,
+    pending: VecDeque<Tm>,
+    emitted: usize,
+    done: bool,
+}
+
+impl Iterator for Occurrences {
+    type Item = Tm;
+
+    fn next(&mut self) -> Option<Tm> {
+        while !self.done {
+            if self.rule.count.is_some_and(|count| self.emitted >= count) {
+                break
+            }
+            if let Some(tm) = self.pending.pop_front() {
+                if self.rule.until.is_some_and(|until| tm > until) {
+                    break
+                }
+                self.emitted += 1;
+                return Some(tm)
+            }
+
+            match self.rule.period_start(self.period) {
+                Some(base) => {
+                    let start = self.rule.start;
+                    self.pending.extend(self.rule.expand(base).into_iter().filter(|tm| *tm >= start));
+                    self.period += 1;
+                }
+                None => break,
+            }
+        }
+        self.done = true;
+        None
+    }
+}
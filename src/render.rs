@@ -0,0 +1,175 @@
+//! Provides the month and year views of Persian calendar for printing (similar to `jcal`).
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use locale::{self, Locale};
+
+/// Represents the days of a Persian month arranged in weeks from Shanbeh to Jomeh
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct MonthGrid {
+    /// Year
+    pub year: i32,
+
+    /// Month since Farvardin - [0, 11]
+    pub month: i32,
+
+    /// Weeks of month, each containing the days of month placed at their weekday since Shanbeh
+    pub weeks: Vec<[Option<i32>; 7]>,
+}
+
+/// Represents the months of a Persian year
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct YearGrid {
+    /// Year
+    pub year: i32,
+
+    /// Months of year from Farvardin to Esfand
+    pub months: Vec<MonthGrid>,
+}
+
+impl MonthGrid {
+    /// Returns the plain-text representation of month using Persian names
+    pub fn to_text(&self) -> String {
+        self.to_locale_text(&locale::Persian)
+    }
+
+    /// Returns the plain-text representation of month using the names of `locale`
+    pub fn to_locale_text<L: Locale>(&self, locale: &L) -> String {
+        self.to_highlighted_text(locale, |_, _, cell| cell.to_string())
+    }
+
+    /// Returns the plain-text representation of month using the names of `locale`,
+    /// where `highlight` receives the month, the day and the padded cell of each day
+    /// and returns its replacement (e.g. to highlight the current day)
+    pub fn to_highlighted_text<L, F>(&self, locale: &L, highlight: F) -> String
+        where L: Locale, F: Fn(i32, i32, &str) -> String
+    {
+        self.lines(locale, &highlight)
+            .into_iter()
+            .map(|(line, _)| line)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    // Returns the lines of text along with their width before highlighting
+    fn lines<L, F>(&self, locale: &L, highlight: &F) -> Vec<(String, usize)>
+        where L: Locale, F: Fn(i32, i32, &str) -> String
+    {
+        let width = (0..7)
+            .map(|wday| locale.weekday_short_name(wday).chars().count())
+            .fold(2, ::core::cmp::max);
+        let line_width = 7 * width + 6;
+
+        let title = center(&format!("{} {}", locale.month_name(self.month), self.year), line_width);
+        let title_width = title.chars().count();
+        let mut lines = vec![
+            (title, title_width),
+            ((0..7)
+                .map(|wday| pad(locale.weekday_short_name(wday), width))
+                .collect::<Vec<_>>()
+                .join(" "), line_width),
+        ];
+        for week in &self.weeks {
+            lines.push((week
+                .iter()
+                .map(|day| match *day {
+                    Some(day) => highlight(self.month, day, &pad(&day.to_string(), width)),
+                    None => pad("", width),
+                })
+                .collect::<Vec<_>>()
+                .join(" "), line_width));
+        }
+        lines
+    }
+}
+
+impl YearGrid {
+    /// Returns the plain-text representation of year with `months_per_row` months side by side
+    pub fn to_text(&self, months_per_row: usize) -> String {
+        self.to_locale_text(months_per_row, &locale::Persian)
+    }
+
+    /// Returns the plain-text representation of year using the names of `locale`
+    pub fn to_locale_text<L: Locale>(&self, months_per_row: usize, locale: &L) -> String {
+        self.to_highlighted_text(months_per_row, locale, |_, _, cell| cell.to_string())
+    }
+
+    /// Returns the plain-text representation of year using the names of `locale`
+    /// (see `MonthGrid::to_highlighted_text` for `highlight`)
+    pub fn to_highlighted_text<L, F>(&self, months_per_row: usize, locale: &L, highlight: F) -> String
+        where L: Locale, F: Fn(i32, i32, &str) -> String
+    {
+        let months: Vec<_> = self.months.iter().map(|grid| grid.lines(locale, &highlight)).collect();
+        let month_width = months.iter().flat_map(|lines| lines.iter().map(|line| line.1)).max().unwrap_or(0);
+        let height = months.iter().map(|lines| lines.len()).max().unwrap_or(0);
+        let months_per_row = ::core::cmp::max(months_per_row, 1);
+
+        let mut result = vec![center(&self.year.to_string(), months_per_row * (month_width + 3) - 3)];
+        for row in months.chunks(months_per_row) {
+            result.push(String::new());
+            for i in 0..height {
+                let line = row
+                    .iter()
+                    .map(|lines| match lines.get(i) {
+                        Some(&(ref line, width)) => format!("{}{}", line, " ".repeat(month_width - width)),
+                        None => " ".repeat(month_width),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("   ");
+                result.push(line.trim_end().to_string());
+            }
+        }
+        result.join("\n")
+    }
+}
+
+/// Creates the month view of Persian date, returns `None` if month is invalid
+pub fn month(p_year: i32, p_month: i32) -> Option<MonthGrid> {
+    let first_day = ::from_persian_date(p_year, p_month, 1)?;
+
+    let mut weeks = Vec::new();
+    let mut week = [None; 7];
+    let mut wday = first_day.tm_wday as usize;
+    for day in 1..=::get_persian_month_days(p_year, p_month) {
+        week[wday] = Some(day);
+        wday += 1;
+        if wday == 7 {
+            weeks.push(week);
+            week = [None; 7];
+            wday = 0;
+        }
+    }
+    if wday != 0 {
+        weeks.push(week);
+    }
+
+    Some(MonthGrid {
+        year: p_year,
+        month: p_month,
+        weeks,
+    })
+}
+
+/// Creates the year view of Persian calendar
+pub fn year(p_year: i32) -> YearGrid {
+    YearGrid {
+        year: p_year,
+        months: (0..12).filter_map(|p_month| month(p_year, p_month)).collect(),
+    }
+}
+
+fn pad(s: &str, width: usize) -> String {
+    let len = s.chars().count();
+    if len >= width {
+        return s.to_string();
+    }
+    format!("{}{}", " ".repeat(width - len), s)
+}
+
+fn center(s: &str, width: usize) -> String {
+    let len = s.chars().count();
+    if len >= width {
+        return s.to_string();
+    }
+    format!("{}{}", " ".repeat((width - len) / 2), s)
+}
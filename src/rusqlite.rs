@@ -0,0 +1,129 @@
+//! Mappings of SQLite values of the crate rusqlite, enabled by the `rusqlite` feature, where
+//! `Tm`, `PersianDate` and `PersianDateTime` are stored as `TEXT` of the Gregorian date and time in
+//! UTC (e.g. `2016-03-21` or `2016-03-21 07:00:05.250`), and are read from either `TEXT` of ISO
+//! 8601 or `INTEGER` of the number of seconds since January 1, 1970 UTC, while `Stored` chooses
+//! the storage when writing (e.g. `Stored(tm, Storage::Integer)`)
+
+use rusqlite_crate::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+use rusqlite_crate::Result;
+use {PersianDate, PersianDateTime, Timespec, Tm};
+
+/// The storage class of a Persian date or time in SQLite
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+pub enum Storage {
+    /// `TEXT` of the Gregorian date and time in UTC, which works with the date functions of SQLite
+    #[default]
+    Text,
+    /// `INTEGER` of the number of seconds since January 1, 1970 UTC, truncating the fraction of
+    /// second
+    Integer,
+}
+
+/// Represents a value along with the storage used when it is written, which is the storage it is
+/// read from
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Stored<T>(pub T, pub Storage);
+
+// Converts a value from and to the instant in UTC
+trait Instant: Sized {
+    fn to_utc_tm(&self) -> Tm;
+
+    fn from_utc_tm(tm: &Tm) -> Self;
+
+    fn to_text(&self) -> String {
+        let rfc3339 = self.to_utc_tm().to_rfc3339();
+        rfc3339.trim_end_matches('Z').replacen('T', " ", 1)
+    }
+}
+
+impl Instant for Tm {
+    fn to_utc_tm(&self) -> Tm {
+        self.to_utc()
+    }
+
+    fn from_utc_tm(tm: &Tm) -> Tm {
+        *tm
+    }
+}
+
+impl Instant for PersianDate {
+    fn to_utc_tm(&self) -> Tm {
+        self.to_tm()
+    }
+
+    fn from_utc_tm(tm: &Tm) -> PersianDate {
+        PersianDate::from_tm(tm)
+    }
+
+    fn to_text(&self) -> String {
+        let (year, month, day) = self.to_tm().to_gregorian_date();
+        format!("{:04}-{:02}-{:02}", year, month + 1, day)
+    }
+}
+
+impl Instant for PersianDateTime {
+    fn to_utc_tm(&self) -> Tm {
+        self.to_tm()
+    }
+
+    fn from_utc_tm(tm: &Tm) -> PersianDateTime {
+        PersianDateTime::from_tm(tm)
+    }
+}
+
+// Writes the value to the storage
+fn to_sql<T: Instant>(value: &T, storage: Storage) -> ToSqlOutput<'static> {
+    match storage {
+        Storage::Text => ToSqlOutput::from(value.to_text()),
+        Storage::Integer => ToSqlOutput::from(value.to_utc_tm().to_timespec().sec),
+    }
+}
+
+// Reads the value and the storage it is read from
+fn column_result<T: Instant>(value: ValueRef<'_>) -> FromSqlResult<Stored<T>> {
+    match value {
+        ValueRef::Text(_) => {
+            let tm = ::from_iso8601(value.as_str()?).map_err(|e| FromSqlError::Other(Box::new(e)))?;
+            Ok(Stored(T::from_utc_tm(&tm.to_utc()), Storage::Text))
+        },
+        ValueRef::Integer(sec) => {
+            if sec < Tm::MIN.to_timespec().sec || sec > Tm::MAX.to_timespec().sec {
+                return Err(FromSqlError::OutOfRange(sec))
+            }
+            Ok(Stored(T::from_utc_tm(&::at_utc(Timespec::new(sec, 0))), Storage::Integer))
+        },
+        _ => Err(FromSqlError::InvalidType),
+    }
+}
+
+macro_rules! rusqlite_instant {
+    ($type:ty) => {
+        impl ToSql for $type {
+            fn to_sql(&self) -> Result<ToSqlOutput<'_>> {
+                Ok(to_sql(self, Storage::Text))
+            }
+        }
+
+        impl FromSql for $type {
+            fn column_result(value: ValueRef<'_>) -> FromSqlResult<$type> {
+                column_result(value).map(|stored: Stored<$type>| stored.0)
+            }
+        }
+
+        impl ToSql for Stored<$type> {
+            fn to_sql(&self) -> Result<ToSqlOutput<'_>> {
+                Ok(to_sql(&self.0, self.1))
+            }
+        }
+
+        impl FromSql for Stored<$type> {
+            fn column_result(value: ValueRef<'_>) -> FromSqlResult<Stored<$type>> {
+                column_result(value)
+            }
+        }
+    };
+}
+
+rusqlite_instant!(Tm);
+rusqlite_instant!(PersianDate);
+rusqlite_instant!(PersianDateTime);
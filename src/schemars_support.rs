@@ -0,0 +1,73 @@
+// JSON schemas of the serde representations (see `serde_support`) for the crate schemars, enabled
+// by the `schemars` feature, where the strings do not use the `date` and `date-time` formats of
+// JSON Schema, which are Gregorian
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use std::borrow::Cow;
+use {PersianDate, PersianDateTime, PersianPeriod, PersianTime, Tm};
+
+macro_rules! string_schema {
+    ($type:ty, $name:expr, $description:expr, $pattern:expr, $example:expr) => {
+        impl JsonSchema for $type {
+            fn inline_schema() -> bool {
+                true
+            }
+
+            fn schema_name() -> Cow<'static, str> {
+                $name.into()
+            }
+
+            fn schema_id() -> Cow<'static, str> {
+                concat!("ptime::", $name).into()
+            }
+
+            fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                json_schema!({
+                    "type": "string",
+                    "description": $description,
+                    "pattern": $pattern,
+                    "examples": [$example],
+                })
+            }
+        }
+    };
+}
+
+string_schema!(Tm, "Tm",
+               "The RFC 3339 representation of Persian time",
+               r"^-?\d{3,4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(\.\d{1,9})?(Z|[+-]\d{2}:\d{2})$",
+               "1395-01-02T10:30:05+03:30");
+string_schema!(PersianDate, "PersianDate",
+               "The Persian date",
+               r"^-?\d{3,4}-\d{2}-\d{2}$",
+               "1395-01-02");
+string_schema!(PersianTime, "PersianTime",
+               "The time of day",
+               r"^\d{2}:\d{2}:\d{2}(\.\d{9})?$",
+               "10:30:05");
+string_schema!(PersianDateTime, "PersianDateTime",
+               "The Persian date and time of day",
+               r"^-?\d{3,4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(\.\d{9})?$",
+               "1395-01-02T10:30:05");
+
+impl JsonSchema for PersianPeriod {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "PersianPeriod".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "ptime::PersianPeriod".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "The period of years, months, weeks and days, e.g. `1y 2m 3d`",
+            "examples": ["۱ سال و ۲ ماه و ۳ روز"],
+        })
+    }
+}
@@ -0,0 +1,125 @@
+//! Modules to serialize `Tm` in other forms with `#[serde(with = "...")]`, enabled by the `serde`
+//! feature (e.g. `#[serde(with = "ptime::serde::ts_seconds")]`), where `serde_format!` creates
+//! such a module for any format (see `Tm::to_string` for the list of supported tokens)
+
+use serde_crate::de::{self, Visitor};
+pub use serde_crate::{Deserializer, Serializer};
+use std::fmt;
+use Tm;
+
+/// Creates a module to serialize `Tm` with `#[serde(with = "...")]` formatted with the format
+/// pattern, e.g. `serde_format!(pub mod short_date, "yy/M/d");`
+#[macro_export]
+macro_rules! serde_format {
+    ($(#[$attr:meta])* $vis:vis mod $name:ident, $format:expr) => {
+        $(#[$attr])*
+        $vis mod $name {
+            /// The format of `Tm`
+            pub const FORMAT: &str = $format;
+
+            /// Serializes `tm` formatted with `FORMAT`
+            pub fn serialize<S: $crate::serde::Serializer>(tm: &$crate::Tm, serializer: S) -> Result<S::Ok, S::Error> {
+                $crate::serde::serialize_with_format(tm, FORMAT, serializer)
+            }
+
+            /// Deserializes the Persian time formatted with `FORMAT`
+            pub fn deserialize<'de, D: $crate::serde::Deserializer<'de>>(deserializer: D) -> Result<$crate::Tm, D::Error> {
+                $crate::serde::deserialize_with_format(deserializer, FORMAT)
+            }
+        }
+    };
+}
+
+/// Serializes `tm` formatted with `format`
+pub fn serialize_with_format<S: Serializer>(tm: &Tm, format: &str, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&tm.to_string(format))
+}
+
+/// Deserializes the Persian time formatted with `format` (see `strptime`)
+pub fn deserialize_with_format<'de, D: Deserializer<'de>>(deserializer: D, format: &str) -> Result<Tm, D::Error> {
+    deserializer.deserialize_str(FormatVisitor(format))
+}
+
+// Deserializes a string formatted with the format
+struct FormatVisitor<'a>(&'a str);
+
+impl<'de, 'a> Visitor<'de> for FormatVisitor<'a> {
+    type Value = Tm;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a Persian time formatted as {}", self.0)
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Tm, E> {
+        ::strptime(s, self.0).map_err(E::custom)
+    }
+}
+
+/// Serializes `Tm` as the number of seconds since January 1, 1970 UTC (e.g. 1458543600), which
+/// deserializes to UTC
+pub mod ts_seconds {
+    use serde_crate::de::{Deserialize, Deserializer, Error};
+    use serde_crate::ser::Serializer;
+    use {Timespec, Tm};
+
+    /// Serializes the number of seconds of `tm`, truncating the fraction of second
+    pub fn serialize<S: Serializer>(tm: &Tm, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(tm.to_timespec().sec)
+    }
+
+    /// Deserializes the number of seconds to the Persian time in UTC
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Tm, D::Error> {
+        let sec = i64::deserialize(deserializer)?;
+        if sec < Tm::MIN.to_timespec().sec || sec > Tm::MAX.to_timespec().sec {
+            return Err(D::Error::custom(format!("timestamp {} out of supported range", sec)))
+        }
+        Ok(::at_utc(Timespec::new(sec, 0)))
+    }
+}
+
+/// Serializes `Tm` as the RFC 3339 representation of the equivalent Gregorian time (e.g.
+/// 2016-03-21T10:30:05+03:30), keeping the offset
+pub mod rfc3339 {
+    use serde_crate::de::Deserializer;
+    use serde_crate::ser::Serializer;
+    use serde_support::StrVisitor;
+    use Tm;
+
+    /// Serializes the Gregorian representation of `tm`
+    pub fn serialize<S: Serializer>(tm: &Tm, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&tm.to_rfc3339())
+    }
+
+    /// Deserializes the Gregorian time to Persian time
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Tm, D::Error> {
+        deserializer.deserialize_str(StrVisitor(::from_iso8601, "an RFC 3339 Gregorian time"))
+    }
+}
+
+/// Serializes `Tm` as `bson::DateTime`, which is the native date of BSON and the `$date` of
+/// extended JSON elsewhere (e.g. {"$date":{"$numberLong":"1458543605000"}}), truncating the
+/// nanosecond to milliseconds, which deserializes to UTC
+#[cfg(feature = "bson")]
+pub mod bson_datetime {
+    use bson::DateTime;
+    use serde_crate::de::{Deserialize, Deserializer, Error};
+    use serde_crate::ser::{Serialize, Serializer};
+    use std::convert::TryFrom;
+    use Tm;
+
+    /// Serializes `tm` as `bson::DateTime`
+    pub fn serialize<S: Serializer>(tm: &Tm, serializer: S) -> Result<S::Ok, S::Error> {
+        DateTime::from(*tm).serialize(serializer)
+    }
+
+    /// Deserializes `bson::DateTime` to the Persian time in UTC
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Tm, D::Error> {
+        Tm::try_from(DateTime::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+}
+
+serde_format! {
+    /// Serializes `Tm` as the Persian date and the wall clock written as `yyyy/MM/dd HH:mm` (e.g.
+    /// 1403/01/01 12:30), dropping the seconds and the offset, which deserializes to UTC
+    pub mod persian_string, "yyyy/MM/dd HH:mm"
+}
@@ -0,0 +1,64 @@
+// Serialization with the crate serde, enabled by the `serde` feature
+//
+// All types are represented as strings:
+//
+// - `Tm`: the RFC 3339 representation of Persian time (e.g. 1395-01-02T10:30:05.250+03:30), which
+//   keeps the offset in minutes but not `tm_isdst`
+// - `PersianDate`: e.g. 1395-01-02
+// - `PersianTime`: e.g. 10:30:05, followed by the fraction of second if it is not zero
+// - `PersianDateTime`: e.g. 1395-01-02T10:30:05
+// - `PersianPeriod`: e.g. ۱ سال و ۲ ماه و ۳ روز, which also deserializes from e.g. 1y 2m 3d
+
+use serde_crate::de::{self, Deserialize, Deserializer, Visitor};
+use serde_crate::ser::{Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+use {ParseError, PersianDate, PersianDateTime, PersianPeriod, PersianTime, Tm};
+
+impl Serialize for Tm {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_rfc3339_persian())
+    }
+}
+
+impl<'de> Deserialize<'de> for Tm {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Tm, D::Error> {
+        deserializer.deserialize_str(StrVisitor(::from_rfc3339_persian, "an RFC 3339 Persian time"))
+    }
+}
+
+macro_rules! serde_from_str {
+    ($type:ty, $expecting:expr) => {
+        impl Serialize for $type {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $type {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<$type, D::Error> {
+                deserializer.deserialize_str(StrVisitor(<$type>::from_str, $expecting))
+            }
+        }
+    };
+}
+
+serde_from_str!(PersianDate, "a Persian date");
+serde_from_str!(PersianTime, "a time of day");
+serde_from_str!(PersianDateTime, "a Persian date and time");
+serde_from_str!(PersianPeriod, "a period");
+
+// Deserializes a string with the parser and the description of expected input
+pub(crate) struct StrVisitor<T>(pub(crate) fn(&str) -> Result<T, ParseError>, pub(crate) &'static str);
+
+impl<'de, T> Visitor<'de> for StrVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.1)
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<T, E> {
+        (self.0)(s).map_err(E::custom)
+    }
+}
@@ -0,0 +1,54 @@
+// Mappings of SQL types of the crate sqlx, enabled by the `sqlx` feature, where the types are
+// encoded the same as their chrono counterparts for any database supported by sqlx (e.g.
+// PostgreSQL, MySQL or SQLite):
+//
+// - `PersianDate`: `NaiveDate`, e.g. `DATE`
+// - `PersianDateTime`: `NaiveDateTime`, e.g. `TIMESTAMP` or `DATETIME`
+// - `Tm`: `DateTime<Utc>`, e.g. `TIMESTAMPTZ` of PostgreSQL, which decodes to UTC
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::{Database, Decode, Encode, Type};
+use std::convert::TryFrom;
+use {PersianDate, PersianDateTime, Tm};
+
+macro_rules! sqlx_via_chrono {
+    ($type:ty, $chrono:ty, $to_chrono:expr, $from_chrono:expr) => {
+        impl<DB: Database> Type<DB> for $type where $chrono: Type<DB> {
+            fn type_info() -> DB::TypeInfo {
+                <$chrono as Type<DB>>::type_info()
+            }
+
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <$chrono as Type<DB>>::compatible(ty)
+            }
+        }
+
+        impl<'q, DB: Database> Encode<'q, DB> for $type where $chrono: Encode<'q, DB> {
+            fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+                Encode::<DB>::encode($to_chrono(self), buf)
+            }
+
+            fn produces(&self) -> Option<DB::TypeInfo> {
+                Encode::<DB>::produces(&$to_chrono(self))
+            }
+        }
+
+        impl<'r, DB: Database> Decode<'r, DB> for $type where $chrono: Decode<'r, DB> {
+            fn decode(value: DB::ValueRef<'r>) -> Result<$type, BoxDynError> {
+                Ok($from_chrono(<$chrono as Decode<DB>>::decode(value)?)?)
+            }
+        }
+    };
+}
+
+sqlx_via_chrono!(PersianDate, NaiveDate,
+                 |date: &PersianDate| NaiveDate::from(date.to_tm()),
+                 |date| Tm::try_from(date).map(|tm| PersianDate::from_tm(&tm)));
+sqlx_via_chrono!(PersianDateTime, NaiveDateTime,
+                 |date_time: &PersianDateTime| NaiveDateTime::from(date_time.to_tm()),
+                 |date_time| Tm::try_from(date_time).map(|tm| PersianDateTime::from_tm(&tm)));
+sqlx_via_chrono!(Tm, DateTime<Utc>,
+                 |tm: &Tm| DateTime::<Utc>::from(*tm),
+                 Tm::try_from);
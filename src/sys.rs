@@ -0,0 +1,135 @@
+// The clock and the local timezone of host
+
+use Timespec;
+
+// Returns the current time of host
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32", target_os = "unknown")))]
+pub(crate) fn now() -> Timespec {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(elapsed) => Timespec::new(elapsed.as_secs() as i64, elapsed.subsec_nanos() as i32),
+        Err(error) => {
+            let before = error.duration();
+            match before.subsec_nanos() {
+                0 => Timespec::new(-(before.as_secs() as i64), 0),
+                nsec => Timespec::new(-(before.as_secs() as i64) - 1, 1_000_000_000 - nsec as i32),
+            }
+        }
+    }
+}
+
+// Returns the current time of browser or JavaScript runtime, where the clock of `std` panics
+#[cfg(all(feature = "wasm", target_arch = "wasm32", target_os = "unknown"))]
+pub(crate) fn now() -> Timespec {
+    let millis = ::js_sys::Date::now() as i64;
+    Timespec::new(millis.div_euclid(1000), millis.rem_euclid(1000) as i32 * 1_000_000)
+}
+
+// Returns the offset and the daylight saving flag of the local timezone of host at the instant
+// of `clock`, read from `TZ` or `/etc/localtime`
+#[cfg(unix)]
+pub(crate) fn local_offset(clock: Timespec) -> (i32, i32) {
+    ::tzfile::local_offset(clock.sec)
+}
+
+// Returns the offset and the daylight saving flag of the local timezone of host at the instant
+// of `clock`, by the current rules of daylight saving time of Windows
+#[cfg(windows)]
+pub(crate) fn local_offset(clock: Timespec) -> (i32, i32) {
+    use sys::windows::*;
+
+    // SAFETY: the structures are plain data and the functions only write to the pointers
+    unsafe {
+        let mut tzi: TimeZoneInformation = ::core::mem::zeroed();
+        if GetTimeZoneInformation(&mut tzi) == TIME_ZONE_ID_INVALID {
+            return (0, 0)
+        }
+        let standard = -(tzi.bias + tzi.standard_bias) * 60;
+        // FILETIME counts the intervals of 100 nanoseconds since January 1, 1601 UTC
+        let ticks = match clock.sec.checked_add(11644473600) {
+            Some(sec) if sec >= 0 => sec as u64 * 10_000_000,
+            _ => return (standard, 0),
+        };
+        let utc_ft = FileTime { low: ticks as u32, high: (ticks >> 32) as u32 };
+        let mut utc: SystemTime = ::core::mem::zeroed();
+        let mut local: SystemTime = ::core::mem::zeroed();
+        let mut local_ft = FileTime { low: 0, high: 0 };
+        if FileTimeToSystemTime(&utc_ft, &mut utc) == 0 ||
+            SystemTimeToTzSpecificLocalTime(&tzi, &utc, &mut local) == 0 ||
+            SystemTimeToFileTime(&local, &mut local_ft) == 0 {
+            return (standard, 0)
+        }
+        let local_ticks = (local_ft.high as u64) << 32 | local_ft.low as u64;
+        let offset = ((local_ticks as i64 - ticks as i64) / 10_000_000) as i32;
+        (offset, (offset != standard) as i32)
+    }
+}
+
+// The declarations of kernel32 used to read the local timezone of Windows
+#[cfg(windows)]
+mod windows {
+    pub const TIME_ZONE_ID_INVALID: u32 = 0xFFFF_FFFF;
+
+    #[repr(C)]
+    pub struct FileTime {
+        pub low: u32,
+        pub high: u32,
+    }
+
+    #[repr(C)]
+    pub struct SystemTime {
+        pub year: u16,
+        pub month: u16,
+        pub day_of_week: u16,
+        pub day: u16,
+        pub hour: u16,
+        pub minute: u16,
+        pub second: u16,
+        pub milliseconds: u16,
+    }
+
+    #[repr(C)]
+    pub struct TimeZoneInformation {
+        pub bias: i32,
+        pub standard_name: [u16; 32],
+        pub standard_date: SystemTime,
+        pub standard_bias: i32,
+        pub daylight_name: [u16; 32],
+        pub daylight_date: SystemTime,
+        pub daylight_bias: i32,
+    }
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        pub fn GetTimeZoneInformation(tzi: *mut TimeZoneInformation) -> u32;
+        pub fn FileTimeToSystemTime(ft: *const FileTime, st: *mut SystemTime) -> i32;
+        pub fn SystemTimeToTzSpecificLocalTime(tzi: *const TimeZoneInformation, utc: *const SystemTime,
+                                               local: *mut SystemTime) -> i32;
+        pub fn SystemTimeToFileTime(st: *const SystemTime, ft: *mut FileTime) -> i32;
+    }
+}
+
+// Returns the offset and the daylight saving flag of the local timezone of JavaScript runtime at
+// the instant of `clock`, where daylight saving is in effect if the offset is greater than the
+// offset of either January or July of the year
+#[cfg(all(feature = "wasm", target_arch = "wasm32", target_os = "unknown"))]
+pub(crate) fn local_offset(clock: Timespec) -> (i32, i32) {
+    use js_sys::wasm_bindgen::JsValue;
+    use js_sys::Date;
+
+    // `getTimezoneOffset` returns the minutes west of UTC
+    let date = Date::new(&JsValue::from_f64(clock.sec as f64 * 1000.0));
+    let year = date.get_full_year();
+    let january = Date::new_with_year_month_day(year, 0, 1).get_timezone_offset();
+    let july = Date::new_with_year_month_day(year, 6, 1).get_timezone_offset();
+    let offset = date.get_timezone_offset();
+    (-offset as i32 * 60, (offset < january.max(july)) as i32)
+}
+
+// The local timezone is UTC on the other platforms (e.g. WASI), where the timezone of host is
+// unknown
+#[cfg(not(any(unix, windows, all(feature = "wasm", target_arch = "wasm32", target_os = "unknown"))))]
+pub(crate) fn local_offset(_clock: Timespec) -> (i32, i32) {
+    (0, 0)
+}
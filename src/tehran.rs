@@ -0,0 +1,89 @@
+// The timezone of Tehran (Asia/Tehran), independent of the local timezone of host
+
+use Timespec;
+
+// The standard offset of Iran (+03:30)
+const STANDARD_OFFSET: i32 = 12600;
+
+// The transitions of the offset of Tehran since the first daylight saving time of 1977 until its
+// abolition in 2022, as the instant of transition in seconds since January 1, 1970 UTC, the
+// offset and the daylight saving flag, followed by the wall clock after the transition
+static TRANSITIONS: [(i64, i32, i32); 69] = [
+    (227820600, 16200, 1), // 1977-03-22 00:00 +04:30
+    (246223800, 14400, 0), // 1977-10-20 23:30 +04:00
+    (259617600, 18000, 1), // 1978-03-25 01:00 +05:00
+    (271108800, 14400, 0), // 1978-08-05 00:00 +04:00
+    (279576000, 12600, 0), // 1978-11-10 23:30 +03:30
+    (296598600, 16200, 1), // 1979-05-27 01:00 +04:30
+    (306531000, 12600, 0), // 1979-09-18 23:00 +03:30
+    (322432200, 16200, 1), // 1980-03-21 01:00 +04:30
+    (338499000, 12600, 0), // 1980-09-22 23:00 +03:30
+    (673216200, 16200, 1), // 1991-05-03 01:00 +04:30
+    (685481400, 12600, 0), // 1991-09-21 23:00 +03:30
+    (701209800, 16200, 1), // 1992-03-22 01:00 +04:30
+    (717103800, 12600, 0), // 1992-09-21 23:00 +03:30
+    (732745800, 16200, 1), // 1993-03-22 01:00 +04:30
+    (748639800, 12600, 0), // 1993-09-21 23:00 +03:30
+    (764281800, 16200, 1), // 1994-03-22 01:00 +04:30
+    (780175800, 12600, 0), // 1994-09-21 23:00 +03:30
+    (795817800, 16200, 1), // 1995-03-22 01:00 +04:30
+    (811711800, 12600, 0), // 1995-09-21 23:00 +03:30
+    (827353800, 16200, 1), // 1996-03-21 01:00 +04:30
+    (843247800, 12600, 0), // 1996-09-20 23:00 +03:30
+    (858976200, 16200, 1), // 1997-03-22 01:00 +04:30
+    (874870200, 12600, 0), // 1997-09-21 23:00 +03:30
+    (890512200, 16200, 1), // 1998-03-22 01:00 +04:30
+    (906406200, 12600, 0), // 1998-09-21 23:00 +03:30
+    (922048200, 16200, 1), // 1999-03-22 01:00 +04:30
+    (937942200, 12600, 0), // 1999-09-21 23:00 +03:30
+    (953584200, 16200, 1), // 2000-03-21 01:00 +04:30
+    (969478200, 12600, 0), // 2000-09-20 23:00 +03:30
+    (985206600, 16200, 1), // 2001-03-22 01:00 +04:30
+    (1001100600, 12600, 0), // 2001-09-21 23:00 +03:30
+    (1016742600, 16200, 1), // 2002-03-22 01:00 +04:30
+    (1032636600, 12600, 0), // 2002-09-21 23:00 +03:30
+    (1048278600, 16200, 1), // 2003-03-22 01:00 +04:30
+    (1064172600, 12600, 0), // 2003-09-21 23:00 +03:30
+    (1079814600, 16200, 1), // 2004-03-21 01:00 +04:30
+    (1095708600, 12600, 0), // 2004-09-20 23:00 +03:30
+    (1111437000, 16200, 1), // 2005-03-22 01:00 +04:30
+    (1127331000, 12600, 0), // 2005-09-21 23:00 +03:30
+    (1206045000, 16200, 1), // 2008-03-21 01:00 +04:30
+    (1221939000, 12600, 0), // 2008-09-20 23:00 +03:30
+    (1237667400, 16200, 1), // 2009-03-22 01:00 +04:30
+    (1253561400, 12600, 0), // 2009-09-21 23:00 +03:30
+    (1269203400, 16200, 1), // 2010-03-22 01:00 +04:30
+    (1285097400, 12600, 0), // 2010-09-21 23:00 +03:30
+    (1300739400, 16200, 1), // 2011-03-22 01:00 +04:30
+    (1316633400, 12600, 0), // 2011-09-21 23:00 +03:30
+    (1332275400, 16200, 1), // 2012-03-21 01:00 +04:30
+    (1348169400, 12600, 0), // 2012-09-20 23:00 +03:30
+    (1363897800, 16200, 1), // 2013-03-22 01:00 +04:30
+    (1379791800, 12600, 0), // 2013-09-21 23:00 +03:30
+    (1395433800, 16200, 1), // 2014-03-22 01:00 +04:30
+    (1411327800, 12600, 0), // 2014-09-21 23:00 +03:30
+    (1426969800, 16200, 1), // 2015-03-22 01:00 +04:30
+    (1442863800, 12600, 0), // 2015-09-21 23:00 +03:30
+    (1458505800, 16200, 1), // 2016-03-21 01:00 +04:30
+    (1474399800, 12600, 0), // 2016-09-20 23:00 +03:30
+    (1490128200, 16200, 1), // 2017-03-22 01:00 +04:30
+    (1506022200, 12600, 0), // 2017-09-21 23:00 +03:30
+    (1521664200, 16200, 1), // 2018-03-22 01:00 +04:30
+    (1537558200, 12600, 0), // 2018-09-21 23:00 +03:30
+    (1553200200, 16200, 1), // 2019-03-22 01:00 +04:30
+    (1569094200, 12600, 0), // 2019-09-21 23:00 +03:30
+    (1584736200, 16200, 1), // 2020-03-21 01:00 +04:30
+    (1600630200, 12600, 0), // 2020-09-20 23:00 +03:30
+    (1616358600, 16200, 1), // 2021-03-22 01:00 +04:30
+    (1632252600, 12600, 0), // 2021-09-21 23:00 +03:30
+    (1647894600, 16200, 1), // 2022-03-22 01:00 +04:30
+    (1663788600, 12600, 0), // 2022-09-21 23:00 +03:30
+];
+
+// Returns the offset and the daylight saving flag of Tehran at the instant of `clock`
+pub(crate) fn resolve(clock: Timespec) -> (i32, i32) {
+    match TRANSITIONS.partition_point(|transition| transition.0 <= clock.sec) {
+        0 => (STANDARD_OFFSET, 0),
+        i => (TRANSITIONS[i - 1].1, TRANSITIONS[i - 1].2),
+    }
+}
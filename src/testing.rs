@@ -0,0 +1,56 @@
+//! Strategies of the crate proptest to test the code handling Persian dates, enabled by the
+//! `proptest` feature (e.g. `proptest!(|(date in ptime::testing::any_date())| { ... })`)
+
+use proptest::prelude::*;
+use proptest::strategy::Union;
+use {PersianDate, Timespec, Tm, MAX_YEAR, MIN_YEAR};
+
+/// Generates the dates in [`start`, `end`], uniform over the days
+pub fn dates(start: PersianDate, end: PersianDate) -> impl Strategy<Value = PersianDate> {
+    (start.to_tm().to_jdn()..=end.to_tm().to_jdn()).prop_map(|jdn| PersianDate::from_tm(&::from_jdn(jdn).expect("day in supported range")))
+}
+
+/// Generates the dates of the supported range, uniform over the days
+pub fn any_date() -> impl Strategy<Value = PersianDate> {
+    dates(PersianDate::from_tm(&Tm::MIN), PersianDate::from_tm(&Tm::MAX))
+}
+
+/// Generates the dates of leap years (i.e. where Esfand has 30 days) in the supported range
+pub fn leap_year_dates() -> impl Strategy<Value = PersianDate> {
+    (leap_years(), 0..366).prop_map(|(year, day)| add_days(PersianDate::new_unchecked(year, 0, 1), day))
+}
+
+/// Generates the dates that are often mishandled: Nowruz (1 Farvardin), the last day of each
+/// month, Esfand 29 of any year, Esfand 30 of leap years and the bounds of supported range
+pub fn edge_dates() -> impl Strategy<Value = PersianDate> {
+    let years = MIN_YEAR..=MAX_YEAR;
+    Union::new(vec![
+        years.clone().prop_map(|year| PersianDate::new_unchecked(year, 0, 1)).boxed(),
+        (years.clone(), 0..11).prop_map(|(year, month)| PersianDate::new_unchecked(year, month, if month < 6 { 31 } else { 30 })).boxed(),
+        years.prop_map(|year| PersianDate::new_unchecked(year, 11, 29)).boxed(),
+        leap_years().prop_map(|year| PersianDate::new_unchecked(year, 11, 30)).boxed(),
+        Just(PersianDate::from_tm(&Tm::MIN)).boxed(),
+        Just(PersianDate::from_tm(&Tm::MAX)).boxed(),
+    ])
+}
+
+/// Generates the instants in [`start`, `end`] in UTC, uniform over the nanoseconds
+pub fn instants(start: &Tm, end: &Tm) -> impl Strategy<Value = Tm> {
+    let start = start.to_timespec();
+    let end = end.to_timespec();
+    let nanoseconds = (end.sec - start.sec) as i128 * 1_000_000_000 + (end.nsec - start.nsec) as i128;
+    (0..=nanoseconds).prop_map(move |offset| {
+        let nsec = start.nsec as i128 + offset;
+        ::at_utc(Timespec::new(start.sec + (nsec / 1_000_000_000) as i64, (nsec % 1_000_000_000) as i32))
+    })
+}
+
+// Generates the leap years of the supported range
+fn leap_years() -> impl Strategy<Value = i32> {
+    (MIN_YEAR..=MAX_YEAR).prop_filter("leap year", |&year| ::days_in_month(year, 11) == Some(30))
+}
+
+// Returns the date `days` after `date`
+fn add_days(date: PersianDate, days: i32) -> PersianDate {
+    PersianDate::from_tm(&::from_jdn(date.to_tm().to_jdn() + days).expect("day in supported range"))
+}
@@ -0,0 +1,70 @@
+// Conversions from and to the types of the crate time 0.3, enabled by the `time03` feature
+
+use std::convert::TryFrom;
+use time03::error::ComponentRange;
+use time03::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+use {DateError, Tm};
+
+// Creates the Persian date at midnight in UTC
+impl TryFrom<Date> for Tm {
+    type Error = DateError;
+
+    fn try_from(date: Date) -> Result<Tm, DateError> {
+        ::try_from_gregorian_date(date.year(), u8::from(date.month()) as i32 - 1, date.day() as i32)
+    }
+}
+
+// Creates the Persian time in UTC
+impl TryFrom<PrimitiveDateTime> for Tm {
+    type Error = DateError;
+
+    fn try_from(date_time: PrimitiveDateTime) -> Result<Tm, DateError> {
+        ::try_from_gregorian_components(date_time.year(),
+                                        u8::from(date_time.month()) as i32 - 1,
+                                        date_time.day() as i32,
+                                        date_time.hour() as i32,
+                                        date_time.minute() as i32,
+                                        date_time.second() as i32,
+                                        date_time.nanosecond() as i32)
+    }
+}
+
+// Keeps the wall clock and the offset
+impl TryFrom<OffsetDateTime> for Tm {
+    type Error = DateError;
+
+    fn try_from(date_time: OffsetDateTime) -> Result<Tm, DateError> {
+        let tm = Tm::try_from(PrimitiveDateTime::new(date_time.date(), date_time.time()))?;
+        Ok(Tm { tm_utcoff: date_time.offset().whole_seconds(), ..tm })
+    }
+}
+
+// Returns the Gregorian date, ignoring the time of day and the offset, which fails if the year
+// is out of the range supported by time 0.3
+impl TryFrom<Tm> for Date {
+    type Error = ComponentRange;
+
+    fn try_from(tm: Tm) -> Result<Date, ComponentRange> {
+        let (year, month, day) = tm.to_gregorian_date();
+        Date::from_calendar_date(year, time03::Month::try_from(month as u8 + 1)?, day as u8)
+    }
+}
+
+// Returns the Gregorian date and the wall clock, ignoring the offset
+impl TryFrom<Tm> for PrimitiveDateTime {
+    type Error = ComponentRange;
+
+    fn try_from(tm: Tm) -> Result<PrimitiveDateTime, ComponentRange> {
+        let time = Time::from_hms_nano(tm.tm_hour as u8, tm.tm_min as u8, tm.tm_sec as u8, tm.tm_nsec as u32)?;
+        Ok(PrimitiveDateTime::new(Date::try_from(tm)?, time))
+    }
+}
+
+impl TryFrom<Tm> for OffsetDateTime {
+    type Error = ComponentRange;
+
+    fn try_from(tm: Tm) -> Result<OffsetDateTime, ComponentRange> {
+        let offset = UtcOffset::from_whole_seconds(tm.tm_utcoff)?;
+        Ok(PrimitiveDateTime::try_from(tm)?.assume_offset(offset))
+    }
+}
@@ -0,0 +1,67 @@
+// Instants of time, compatible with `time::Timespec` of the crate time 0.1
+
+use core::ops::{Add, Sub};
+use core::time;
+use Duration;
+
+/// Represents an instant as the number of seconds and nanoseconds since January 1, 1970 UTC
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "rkyv", derive(::rkyv::Archive, ::rkyv::Serialize, ::rkyv::Deserialize))]
+#[cfg_attr(feature = "rkyv", rkyv(derive(Debug)))]
+pub struct Timespec {
+    /// The number of seconds since January 1, 1970 UTC
+    pub sec: i64,
+
+    /// The nanosecond of second - [0, 999999999]
+    pub nsec: i32,
+}
+
+impl Timespec {
+    /// Creates a new instant, panics if `nsec` is out of [0, 999999999]
+    pub const fn new(sec: i64, nsec: i32) -> Timespec {
+        assert!(nsec >= 0 && nsec < 1_000_000_000, "nanosecond out of range");
+        Timespec { sec, nsec }
+    }
+}
+
+impl Add<Duration> for Timespec {
+    type Output = Timespec;
+
+    fn add(self, other: Duration) -> Timespec {
+        let sum = Duration::from_parts(self.sec, self.nsec) + other;
+        Timespec { sec: sum.secs, nsec: sum.nanos }
+    }
+}
+
+impl Sub<Duration> for Timespec {
+    type Output = Timespec;
+
+    fn sub(self, other: Duration) -> Timespec {
+        let difference = Duration::from_parts(self.sec, self.nsec) - other;
+        Timespec { sec: difference.secs, nsec: difference.nanos }
+    }
+}
+
+impl Add<time::Duration> for Timespec {
+    type Output = Timespec;
+
+    fn add(self, other: time::Duration) -> Timespec {
+        self + Duration::from_std(other).expect("duration out of bounds")
+    }
+}
+
+impl Sub<time::Duration> for Timespec {
+    type Output = Timespec;
+
+    fn sub(self, other: time::Duration) -> Timespec {
+        self - Duration::from_std(other).expect("duration out of bounds")
+    }
+}
+
+impl Sub for Timespec {
+    type Output = Duration;
+
+    fn sub(self, other: Timespec) -> Duration {
+        Duration::from_parts(self.sec, self.nsec) - Duration::from_parts(other.sec, other.nsec)
+    }
+}
@@ -0,0 +1,385 @@
+// The local timezone of host read from the tz database (TZif files of RFC 8536) and the POSIX TZ
+// strings, instead of `localtime_r` whose reading of `TZ` races with the modifications of the
+// environment by other threads (RUSTSEC-2020-0071)
+
+use std::env;
+use std::ffi::{OsStr, OsString};
+use std::fs;
+use std::os::unix::ffi::OsStrExt;
+use std::sync::Mutex;
+
+// The directories of the tz database, where the zone names of `TZ` are looked up
+static ZONEINFO_DIRS: [&str; 3] = ["/usr/share/zoneinfo", "/usr/lib/zoneinfo", "/usr/share/lib/zoneinfo"];
+
+// The local timezone of the last value of `TZ`, which is read again once `TZ` changes
+static LOCAL: Mutex<Option<(Option<OsString>, Zone)>> = Mutex::new(None);
+
+// Returns the offset and the daylight saving flag of the local timezone of host at the instant
+// of `sec` seconds since January 1, 1970 UTC, where the unknown timezones are UTC
+pub(crate) fn local_offset(sec: i64) -> (i32, i32) {
+    // `env::var_os` holds the lock of `std` on the environment, unlike `getenv` of libc
+    let tz = env::var_os("TZ");
+    let mut local = LOCAL.lock().unwrap_or_else(|e| e.into_inner());
+    match *local {
+        Some((ref key, ref zone)) if *key == tz => zone.resolve(sec),
+        _ => {
+            let zone = load(tz.as_deref());
+            let resolved = zone.resolve(sec);
+            *local = Some((tz, zone));
+            resolved
+        }
+    }
+}
+
+// Represents the local time types of a timezone and the transitions among them
+struct Zone {
+    // The instants of transition in seconds since January 1, 1970 UTC and the index of local
+    // time type after the transition
+    transitions: Vec<(i64, usize)>,
+    // The offsets and the daylight saving flags of local time types, where the first one is used
+    // before the first transition
+    types: Vec<(i32, i32)>,
+    // The rule of the instants after the last transition
+    rule: Option<Rule>,
+}
+
+impl Zone {
+    const UTC: Zone = Zone {
+        transitions: Vec::new(),
+        types: Vec::new(),
+        rule: None,
+    };
+
+    fn resolve(&self, sec: i64) -> (i32, i32) {
+        let i = self.transitions.partition_point(|&(at, _)| at <= sec);
+        match self.rule {
+            Some(ref rule) if i == self.transitions.len() => rule.resolve(sec),
+            _ if i == 0 => self.types.first().copied().unwrap_or((0, 0)),
+            _ => self.types[self.transitions[i - 1].1],
+        }
+    }
+}
+
+// Returns the timezone of `TZ`, i.e. the path or the name of TZif file, or the POSIX TZ string,
+// where `/etc/localtime` is used if `TZ` is not set
+fn load(tz: Option<&OsStr>) -> Zone {
+    let tz = match tz {
+        Some(tz) => tz.as_bytes(),
+        None => return read_tzif("/etc/localtime".as_ref()).unwrap_or(Zone::UTC),
+    };
+    let tz = tz.strip_prefix(b":").unwrap_or(tz);
+    if tz.first() == Some(&b'/') {
+        return read_tzif(OsStr::from_bytes(tz)).unwrap_or(Zone::UTC)
+    }
+    if !tz.is_empty() && !tz.windows(2).any(|w| w == b"..") {
+        for dir in ZONEINFO_DIRS.iter() {
+            let mut path = OsString::from(dir);
+            path.push("/");
+            path.push(OsStr::from_bytes(tz));
+            if let Some(zone) = read_tzif(&path) {
+                return zone
+            }
+        }
+    }
+    parse_rule(tz).map_or(Zone::UTC, |rule| Zone {
+        transitions: Vec::new(),
+        types: Vec::new(),
+        rule: Some(rule),
+    })
+}
+
+fn read_tzif(path: &OsStr) -> Option<Zone> {
+    parse_tzif(&fs::read(path).ok()?)
+}
+
+// Parses the TZif file, using the 64-bit data and the footer of version 2 and later
+fn parse_tzif(data: &[u8]) -> Option<Zone> {
+    let mut reader = Reader { data, pos: 0 };
+    let mut counts = reader.header()?;
+    let mut time_size = 4;
+    if counts.version >= b'2' {
+        reader.skip(counts.data_len(4))?;
+        counts = reader.header()?;
+        time_size = 8;
+    }
+    if counts.typecnt == 0 {
+        return None
+    }
+
+    let mut transitions = Vec::with_capacity(counts.timecnt);
+    for _ in 0..counts.timecnt {
+        transitions.push((reader.int(time_size)?, 0));
+    }
+    for transition in transitions.iter_mut() {
+        transition.1 = reader.bytes(1)?[0] as usize;
+        if transition.1 >= counts.typecnt {
+            return None
+        }
+    }
+    let mut types = Vec::with_capacity(counts.typecnt);
+    for _ in 0..counts.typecnt {
+        let utoff = reader.int(4)? as i32;
+        let isdst = reader.bytes(2)?[0];
+        types.push((utoff, (isdst != 0) as i32));
+    }
+    reader.skip(counts.charcnt + counts.leapcnt * (time_size + 4) + counts.isstdcnt + counts.isutcnt)?;
+
+    let rule = match counts.version >= b'2' && reader.bytes(1) == Some(b"\n") {
+        true => {
+            let rest = &data[reader.pos..];
+            rest.iter().position(|&b| b == b'\n').and_then(|len| parse_rule(&rest[..len]))
+        }
+        false => None,
+    };
+
+    Some(Zone { transitions, types, rule })
+}
+
+// The version and the counts of the header of TZif file
+struct Counts {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl Counts {
+    // Returns the length of the data block following the header
+    fn data_len(&self, time_size: usize) -> usize {
+        self.timecnt * (time_size + 1) + self.typecnt * 6 + self.charcnt + self.leapcnt * (time_size + 4) +
+            self.isstdcnt + self.isutcnt
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        let bytes = self.data.get(self.pos..self.pos.checked_add(len)?)?;
+        self.pos += len;
+        Some(bytes)
+    }
+
+    fn skip(&mut self, len: usize) -> Option<()> {
+        self.bytes(len).map(|_| ())
+    }
+
+    // Reads the big-endian signed integer of 4 or 8 bytes
+    fn int(&mut self, size: usize) -> Option<i64> {
+        let bytes = self.bytes(size)?;
+        let value = bytes.iter().fold(0u64, |acc, &b| acc << 8 | b as u64);
+        Some(match size {
+            4 => value as u32 as i32 as i64,
+            _ => value as i64,
+        })
+    }
+
+    fn header(&mut self) -> Option<Counts> {
+        if self.bytes(4)? != b"TZif" {
+            return None
+        }
+        let version = self.bytes(16)?[0];
+        let mut count = || self.int(4).map(|count| count as u32 as usize);
+        Some(Counts {
+            version,
+            isutcnt: count()?,
+            isstdcnt: count()?,
+            leapcnt: count()?,
+            timecnt: count()?,
+            typecnt: count()?,
+            charcnt: count()?,
+        })
+    }
+}
+
+// Represents the POSIX TZ string, e.g. `<+0330>-3:30` or `EST5EDT,M3.2.0,M11.1.0`, with the
+// offsets in seconds east of UTC
+struct Rule {
+    std_offset: i32,
+    dst: Option<Dst>,
+}
+
+// Represents the daylight saving time of POSIX TZ string, with the dates and the local times of
+// its start (in standard time) and its end (in daylight saving time)
+struct Dst {
+    offset: i32,
+    start: (RuleDate, i32),
+    end: (RuleDate, i32),
+}
+
+// Represents the date of transition of POSIX TZ string
+#[derive(Copy, Clone)]
+enum RuleDate {
+    // `Jn`: the day of year [1, 365], not counting February 29
+    Julian(i32),
+    // `n`: the day of year since January 1 [0, 365]
+    Ordinal(i32),
+    // `Mm.w.d`: the weekday since Sunday of the week [1, 5] of the month [1, 12], where the
+    // week 5 is the last one
+    Weekday(i32, i32, i32),
+}
+
+impl Rule {
+    fn resolve(&self, sec: i64) -> (i32, i32) {
+        let dst = match self.dst {
+            Some(ref dst) => dst,
+            None => return (self.std_offset, 0),
+        };
+        let jdn = ::epoch::UNIX_EPOCH_JDN as i64 + (sec + self.std_offset as i64).div_euclid(86400);
+        let (year, _, _) = ::get_gregorian_date(jdn);
+        let start = dst.start.0.unix_day(year) * 86400 + (dst.start.1 - self.std_offset) as i64;
+        let end = dst.end.0.unix_day(year) * 86400 + (dst.end.1 - dst.offset) as i64;
+        // The daylight saving time of southern hemisphere spans the end of year
+        let is_dst = match start <= end {
+            true => start <= sec && sec < end,
+            false => sec < end || start <= sec,
+        };
+        match is_dst {
+            true => (dst.offset, 1),
+            false => (self.std_offset, 0),
+        }
+    }
+}
+
+impl RuleDate {
+    // Returns the number of days since January 1, 1970 of the date in year
+    fn unix_day(self, year: i32) -> i64 {
+        let jdn = match self {
+            RuleDate::Julian(day) => {
+                ::get_gregorian_jdn(year, 1, 1) + (day - 1) as i64 + (::is_gregorian_leap(year) && day >= 60) as i64
+            }
+            RuleDate::Ordinal(day) => ::get_gregorian_jdn(year, 1, 1) + day as i64,
+            RuleDate::Weekday(month, week, weekday) => {
+                let first = ::get_gregorian_jdn(year, month, 1);
+                // The Julian day number 0 is Monday
+                let mut day = first + (weekday as i64 - (first + 1)).rem_euclid(7) + (week - 1) as i64 * 7;
+                while day >= first + ::get_gregorian_month_days(year, month - 1) as i64 {
+                    day -= 7;
+                }
+                day
+            }
+        };
+        jdn - ::epoch::UNIX_EPOCH_JDN as i64
+    }
+}
+
+// Parses the POSIX TZ string, where the rule of United States is used if daylight saving time
+// has no rule
+fn parse_rule(s: &[u8]) -> Option<Rule> {
+    let mut parser = Parser { s, pos: 0 };
+    parser.name()?;
+    let std_offset = -parser.time()?;
+    if parser.is_end() {
+        return Some(Rule { std_offset, dst: None })
+    }
+
+    parser.name()?;
+    let offset = match parser.peek() {
+        None | Some(b',') => std_offset + 3600,
+        _ => -parser.time()?,
+    };
+    let (start, end) = match parser.eat(b',') {
+        true => {
+            let start = parser.transition()?;
+            if !parser.eat(b',') {
+                return None
+            }
+            (start, parser.transition()?)
+        }
+        false => ((RuleDate::Weekday(3, 2, 0), 7200), (RuleDate::Weekday(11, 1, 0), 7200)),
+    };
+    match parser.is_end() {
+        true => Some(Rule { std_offset, dst: Some(Dst { offset, start, end }) }),
+        false => None,
+    }
+}
+
+struct Parser<'a> {
+    s: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.s.get(self.pos).copied()
+    }
+
+    fn is_end(&self) -> bool {
+        self.pos == self.s.len()
+    }
+
+    fn eat(&mut self, b: u8) -> bool {
+        let eaten = self.peek() == Some(b);
+        self.pos += eaten as usize;
+        eaten
+    }
+
+    // Parses the name of timezone, either alphabetic or quoted in angle brackets
+    fn name(&mut self) -> Option<()> {
+        let start = self.pos;
+        if self.eat(b'<') {
+            while !self.eat(b'>') {
+                self.peek()?;
+                self.pos += 1;
+            }
+            return Some(())
+        }
+        while self.peek().is_some_and(|b| b.is_ascii_alphabetic()) {
+            self.pos += 1;
+        }
+        (self.pos >= start + 3).then_some(())
+    }
+
+    fn number(&mut self, min: i32, max: i32) -> Option<i32> {
+        let start = self.pos;
+        let mut number = 0i32;
+        while let Some(digit @ b'0'..=b'9') = self.peek() {
+            number = number.checked_mul(10)?.checked_add((digit - b'0') as i32)?;
+            self.pos += 1;
+        }
+        (self.pos > start && min <= number && number <= max).then_some(number)
+    }
+
+    // Parses `[+-]hh[:mm[:ss]]` as seconds, where the hours of transition times reach 167
+    fn time(&mut self) -> Option<i32> {
+        let sign = match self.peek() {
+            Some(b'-') => -1,
+            _ => 1,
+        };
+        let _ = self.eat(b'+') || self.eat(b'-');
+        let mut seconds = self.number(0, 167)? * 3600;
+        if self.eat(b':') {
+            seconds += self.number(0, 59)? * 60;
+            if self.eat(b':') {
+                seconds += self.number(0, 59)?;
+            }
+        }
+        Some(sign * seconds)
+    }
+
+    // Parses `date[/time]`, where time is 02:00:00 by default
+    fn transition(&mut self) -> Option<(RuleDate, i32)> {
+        let date = if self.eat(b'J') {
+            RuleDate::Julian(self.number(1, 365)?)
+        } else if self.eat(b'M') {
+            let month = self.number(1, 12)?;
+            self.eat(b'.').then_some(())?;
+            let week = self.number(1, 5)?;
+            self.eat(b'.').then_some(())?;
+            RuleDate::Weekday(month, week, self.number(0, 6)?)
+        } else {
+            RuleDate::Ordinal(self.number(0, 365)?)
+        };
+        let time = match self.eat(b'/') {
+            true => self.time()?,
+            false => 7200,
+        };
+        Some((date, time))
+    }
+}
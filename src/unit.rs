@@ -0,0 +1,15 @@
+// Units of Persian calendar used by truncation and rounding
+
+/// Represents a unit of time in Persian calendar, where weeks start from Shanbeh and quarters
+/// are the seasons starting from Farvardin, Tir, Mehr and Dey
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub enum Unit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
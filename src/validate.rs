@@ -0,0 +1,68 @@
+//! Provides the validation of date and time components without creating a `ptime::Tm`.
+
+use {Component, DateError, MAX_YEAR, MIN_YEAR};
+
+/// Validates the Persian date of the year, the month since Farvardin [0, 11] and the day [1, 31]
+pub const fn persian_date(year: i32, month: i32, day: i32) -> Result<(), DateError> {
+    if year < MIN_YEAR || year > MAX_YEAR {
+        return Err(DateError::YearOutOfRange { year })
+    }
+    if month < 0 || month > 11 {
+        return Err(DateError::MonthOutOfRange { month })
+    }
+
+    let max = ::get_persian_month_days(year, month);
+    if day < 1 || day > max {
+        return Err(DateError::DayOutOfRange { day, max })
+    }
+    Ok(())
+}
+
+/// Validates the Gregorian date of the year, the month since January [0, 11] and the day [1, 31]
+pub const fn gregorian_date(year: i32, month: i32, day: i32) -> Result<(), DateError> {
+    // The Persian years of the supported range start 621 years earlier and end 622 years later
+    if year < MIN_YEAR + 621 || year > MAX_YEAR + 622 {
+        return Err(DateError::YearOutOfRange { year })
+    }
+    if month < 0 || month > 11 {
+        return Err(DateError::MonthOutOfRange { month })
+    }
+
+    let max = ::get_gregorian_month_days(year, month);
+    if day < 1 || day > max {
+        return Err(DateError::DayOutOfRange { day, max })
+    }
+    Ok(())
+}
+
+/// Validates the time of day of the hour [0, 23], the minute [0, 59], the second [0, 59] and the
+/// nanosecond [0, 999999999]
+pub const fn time(hour: i32, minute: i32, second: i32, nanosecond: i32) -> Result<(), DateError> {
+    let component = if hour < 0 || hour > 23 {
+        Component::Hour
+    } else if minute < 0 || minute > 59 {
+        Component::Minute
+    } else if second < 0 || second > 59 {
+        Component::Second
+    } else if nanosecond < 0 || nanosecond > 999999999 {
+        Component::Nanosecond
+    } else {
+        return Ok(())
+    };
+    Err(DateError::InvalidTime { component })
+}
+
+/// Returns true if the Persian date exists (see `persian_date`)
+pub const fn is_persian_date_valid(year: i32, month: i32, day: i32) -> bool {
+    persian_date(year, month, day).is_ok()
+}
+
+/// Returns true if the Gregorian date exists (see `gregorian_date`)
+pub const fn is_gregorian_date_valid(year: i32, month: i32, day: i32) -> bool {
+    gregorian_date(year, month, day).is_ok()
+}
+
+/// Returns true if the time of day is valid (see `time`)
+pub const fn is_time_valid(hour: i32, minute: i32, second: i32, nanosecond: i32) -> bool {
+    time(hour, minute, second, nanosecond).is_ok()
+}
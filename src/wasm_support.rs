@@ -0,0 +1,46 @@
+// Conversions from and to `Date` of the crate js-sys, enabled by the `wasm` feature, which stores
+// the milliseconds since January 1, 1970 UTC
+
+use js_sys::wasm_bindgen::JsValue;
+use js_sys::Date;
+use std::convert::TryFrom;
+use {Component, DateError, Tm};
+
+// Truncates the nanosecond to milliseconds
+impl<'a> From<&'a Tm> for Date {
+    fn from(tm: &'a Tm) -> Date {
+        Date::new(&JsValue::from_f64(tm.timestamp_millis() as f64))
+    }
+}
+
+impl From<Tm> for Date {
+    fn from(tm: Tm) -> Date {
+        Date::from(&tm)
+    }
+}
+
+// Creates the Persian time in UTC, which fails with the missing year if the date is invalid (i.e.
+// `NaN`) or with the year out of the supported range
+impl<'a> TryFrom<&'a Date> for Tm {
+    type Error = DateError;
+
+    fn try_from(date: &'a Date) -> Result<Tm, DateError> {
+        let millis = date.get_time();
+        if millis.is_nan() {
+            return Err(DateError::Missing { component: Component::Year })
+        }
+        let millis = millis as i64;
+        ::from_unix_millis(millis).ok_or_else(|| {
+            let (year, _, _) = ::get_gregorian_date(millis.div_euclid(86_400_000) + ::epoch::UNIX_EPOCH_JDN as i64);
+            DateError::YearOutOfRange { year }
+        })
+    }
+}
+
+impl TryFrom<Date> for Tm {
+    type Error = DateError;
+
+    fn try_from(date: Date) -> Result<Tm, DateError> {
+        Tm::try_from(&date)
+    }
+}
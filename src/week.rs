@@ -0,0 +1,98 @@
+// Weeks of Persian calendar starting from Shanbeh
+
+use Tm;
+
+/// Represents a week of Persian calendar, where weeks start from Shanbeh and the first week
+/// of year is the one containing its first Seshanbeh (i.e. 4 Farvardin)
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct PersianIsoWeek {
+    year: i32,
+    week: i32,
+    weekday: i32,
+}
+
+impl PersianIsoWeek {
+    /// Creates a new instance of week from the week-based year, the week [1, 53] and the weekday
+    /// [1, 7] (1 = Shanbeh, ..., 7 = Jomeh), returns `None` if week does not exist in year or any
+    /// day of week is out of the supported range
+    pub fn new(year: i32, week: i32, weekday: i32) -> Option<PersianIsoWeek> {
+        if !(::MIN_YEAR..=::MAX_YEAR).contains(&year) || !(1..=7).contains(&weekday) ||
+            week < 1 || week > PersianIsoWeek::weeks_in_year(year) {
+            return None
+        }
+        if !::is_jdn_supported(get_week_jdn(year, week, 1)) || !::is_jdn_supported(get_week_jdn(year, week, 7)) {
+            return None
+        }
+        Some(PersianIsoWeek { year, week, weekday })
+    }
+
+    /// Creates the week of `tm`
+    pub fn from_tm(tm: &Tm) -> PersianIsoWeek {
+        let mut year = tm.tm_year;
+        let mut mid = tm.tm_yday - tm.tm_wday + 3;
+        if mid < 0 {
+            year -= 1;
+            mid += ::get_persian_year_days(year);
+        } else if mid >= ::get_persian_year_days(year) {
+            mid -= ::get_persian_year_days(year);
+            year += 1;
+        }
+        PersianIsoWeek {
+            year,
+            week: mid / 7 + 1,
+            weekday: tm.tm_wday + 1,
+        }
+    }
+
+    /// Returns the number of weeks of the week-based year - [52, 53]
+    pub fn weeks_in_year(year: i32) -> i32 {
+        let days = get_first_mid(year + 1) + ::get_persian_year_days(year) - get_first_mid(year);
+        days / 7
+    }
+
+    /// Returns the week-based year
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the week - [1, 53]
+    pub const fn week(&self) -> i32 {
+        self.week
+    }
+
+    /// Returns the weekday since Shanbeh - [1, 7]. 1 = Shanbeh, ..., 7 = Jomeh.
+    pub const fn weekday(&self) -> i32 {
+        self.weekday
+    }
+
+    /// Returns the first day (Shanbeh) of week
+    pub fn first_day(&self) -> Tm {
+        PersianIsoWeek { weekday: 1, ..*self }.to_tm()
+    }
+
+    /// Converts week to the corresponding Persian date, where the days of the weeks of `Tm::MIN`
+    /// and `Tm::MAX` out of the supported range are clamped to them
+    pub fn to_tm(&self) -> Tm {
+        let jdn = get_week_jdn(self.year, self.week, self.weekday);
+        match ::from_jdn(jdn as i32) {
+            Some(tm) => tm,
+            None => ::saturate(jdn - Tm::MIN.to_jdn() as i64),
+        }
+    }
+}
+
+impl From<PersianIsoWeek> for Tm {
+    fn from(week: PersianIsoWeek) -> Tm {
+        week.to_tm()
+    }
+}
+
+// Returns the Julian day number of the weekday of the week of week-based year
+fn get_week_jdn(year: i32, week: i32, weekday: i32) -> i64 {
+    ::get_jdn(year, 1, 1) + (get_first_mid(year) + 7 * (week - 1) + weekday - 4) as i64
+}
+
+// Returns the day of year of the first Seshanbeh of year
+fn get_first_mid(year: i32) -> i32 {
+    (10 - ::get_jdn_weekday(::get_jdn(year, 1, 1))) % 7
+}
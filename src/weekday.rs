@@ -0,0 +1,142 @@
+// Weekdays of Persian calendar starting from Shanbeh
+
+use core::fmt;
+use core::ops::{Add, Sub};
+
+/// Represents a day of week, where weeks start from Shanbeh
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum Weekday {
+    Shanbeh,
+    Yekshanbeh,
+    Doshanbeh,
+    Seshanbeh,
+    Chaharshanbeh,
+    Panjshanbeh,
+    Jomeh,
+}
+
+pub(crate) static WEEKDAYS: [Weekday; 7] = [
+    Weekday::Shanbeh,
+    Weekday::Yekshanbeh,
+    Weekday::Doshanbeh,
+    Weekday::Seshanbeh,
+    Weekday::Chaharshanbeh,
+    Weekday::Panjshanbeh,
+    Weekday::Jomeh,
+];
+
+impl Weekday {
+    /// Returns the weekday of `tm_wday` since Shanbeh - [0, 6]
+    pub const fn from_index(index: i32) -> Option<Weekday> {
+        if index < 0 || index > 6 {
+            return None
+        }
+        Some(WEEKDAYS[index as usize])
+    }
+
+    /// Returns the number of days since Shanbeh as used by `tm_wday` - [0, 6]
+    pub const fn num_days_from_shanbeh(&self) -> i32 {
+        *self as i32
+    }
+
+    /// Returns the Persian name of weekday
+    pub const fn name(&self) -> &'static str {
+        ::locale::PERSIAN_WEEKDAYS[*self as usize]
+    }
+
+    /// Returns the name of weekday transliterated to Latin script
+    pub const fn transliterated_name(&self) -> &'static str {
+        ::locale::TRANSLITERATED_WEEKDAYS[*self as usize]
+    }
+
+    /// Returns the next weekday, where Jomeh is followed by Shanbeh
+    pub const fn succ(&self) -> Weekday {
+        WEEKDAYS[(*self as usize + 1) % 7]
+    }
+
+    /// Returns the previous weekday, where Shanbeh is preceded by Jomeh
+    pub const fn pred(&self) -> Weekday {
+        WEEKDAYS[(*self as usize + 6) % 7]
+    }
+}
+
+impl fmt::Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+// Returns the weekday `days` days later
+impl Add<i32> for Weekday {
+    type Output = Weekday;
+
+    fn add(self, days: i32) -> Weekday {
+        WEEKDAYS[(self as i32 + days.rem_euclid(7)) as usize % 7]
+    }
+}
+
+// Returns the weekday `days` days earlier
+impl Sub<i32> for Weekday {
+    type Output = Weekday;
+
+    fn sub(self, days: i32) -> Weekday {
+        self + (7 - days.rem_euclid(7))
+    }
+}
+
+/// Represents the set of weekdays off work, e.g. `Weekend::new(&[Weekday::Jomeh])`
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Weekend {
+    bits: u8,
+}
+
+impl Weekend {
+    /// The weekend of Jomeh only, i.e. the official weekend of Iran
+    pub const IRAN_FRIDAY_ONLY: Weekend = Weekend::new(&[Weekday::Jomeh]);
+
+    /// The weekend of Panjshanbeh and Jomeh, e.g. of the governmental offices of Iran that are
+    /// closed on Panjshanbeh
+    pub const LEGACY_THU_FRI: Weekend = Weekend::new(&[Weekday::Panjshanbeh, Weekday::Jomeh]);
+
+    /// The official weekend of Afghanistan, i.e. Jomeh
+    pub const AFGHAN_FRIDAY: Weekend = Weekend::new(&[Weekday::Jomeh]);
+
+    /// Creates a new weekend of `weekdays`
+    pub const fn new(weekdays: &[Weekday]) -> Weekend {
+        let mut bits = 0;
+        let mut i = 0;
+        while i < weekdays.len() {
+            bits |= 1 << weekdays[i] as u8;
+            i += 1;
+        }
+        Weekend { bits }
+    }
+
+    /// Creates a new weekend from the bitmask of weekdays, where the bit 0 is Shanbeh, ..., the
+    /// bit 6 is Jomeh and the higher bits are ignored
+    pub const fn from_bits(bits: u8) -> Weekend {
+        Weekend { bits: bits & 0x7f }
+    }
+
+    /// Returns the bitmask of weekdays (see `from_bits`)
+    pub const fn bits(&self) -> u8 {
+        self.bits
+    }
+
+    /// Returns true if `weekday` is off work
+    pub const fn contains(&self, weekday: Weekday) -> bool {
+        self.bits & (1 << weekday as u8) != 0
+    }
+
+    /// Returns the number of business days of each week - [0, 7]
+    pub const fn business_days_per_week(&self) -> i32 {
+        7 - self.bits.count_ones() as i32
+    }
+}
+
+// Jomeh as the official weekend of Iran
+impl Default for Weekend {
+    fn default() -> Weekend {
+        Weekend::IRAN_FRIDAY_ONLY
+    }
+}
@@ -0,0 +1,11 @@
+// IANA timezones of the tz database, enabled by the `tzdb` feature
+
+use Timespec;
+use tzdb;
+
+// Returns the offset and the daylight saving flag of the timezone named `zone` at the instant of
+// `clock`, returns `None` if zone is unknown
+pub(crate) fn resolve(clock: Timespec, zone: &str) -> Option<(i32, i32)> {
+    let local_time_type = tzdb::tz_by_name(zone)?.find_local_time_type(clock.sec).ok()?;
+    Some((local_time_type.ut_offset(), local_time_type.is_dst() as i32))
+}
@@ -0,0 +1,28 @@
+#![cfg(feature = "arbitrary")]
+
+extern crate arbitrary;
+extern crate ptime;
+
+use arbitrary::{Arbitrary, Unstructured};
+
+#[test]
+fn valid_values() {
+    let bytes: Vec<u8> = (0..4096u32).map(|i| (i.wrapping_mul(2654435761) >> 13) as u8).collect();
+    let mut u = Unstructured::new(&bytes);
+    while !u.is_empty() {
+        let date = ptime::PersianDate::arbitrary(&mut u).unwrap();
+        assert!(ptime::PersianDate::new(date.year(), date.month_number() - 1, date.day()).is_some());
+
+        let p_tm = ptime::Tm::arbitrary(&mut u).unwrap();
+        assert!(ptime::from_persian_components(p_tm.tm_year, p_tm.tm_mon, p_tm.tm_mday, p_tm.tm_hour, p_tm.tm_min, p_tm.tm_sec, p_tm.tm_nsec).is_some());
+        assert!(p_tm.tm_utcoff >= -43200 && p_tm.tm_utcoff <= 50400 && p_tm.tm_utcoff % 60 == 0);
+        assert_eq!(p_tm.tm_wday, p_tm.date().weekday() as i32);
+    }
+}
+
+#[test]
+fn exhausted() {
+    let mut u = Unstructured::new(&[]);
+    assert_eq!(ptime::PersianDate::arbitrary(&mut u).unwrap(), ptime::PersianDate::new(ptime::MIN_YEAR, 0, 1).unwrap());
+    assert_eq!(ptime::Tm::arbitrary(&mut u).unwrap(), ptime::localize(&ptime::Tm::MIN, ptime::Offset::Fixed(-43200)));
+}
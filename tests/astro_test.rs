@@ -0,0 +1,42 @@
+#![cfg(feature = "astro")]
+
+extern crate ptime;
+
+use ptime::astro;
+
+#[test]
+fn march_equinox() {
+    // The equinox of 2024 was at 03:06:26 UTC, and of 2025 at 09:01:30 UTC
+    let equinox = astro::march_equinox(2024).unwrap();
+    let expected = ptime::from_gregorian_components(2024, 2, 20, 3, 6, 26, 0).unwrap();
+    assert!((equinox.to_timespec().sec - expected.to_timespec().sec).abs() < 60);
+    assert_eq!(equinox.tm_utcoff, 0);
+    let equinox = astro::march_equinox(2025).unwrap();
+    let expected = ptime::from_gregorian_components(2025, 2, 20, 9, 1, 30, 0).unwrap();
+    assert!((equinox.to_timespec().sec - expected.to_timespec().sec).abs() < 60);
+
+    assert!(astro::march_equinox(-1000).is_some());
+    assert_eq!(astro::march_equinox(3001), None);
+}
+
+#[test]
+fn nowruz_instant() {
+    // The year 1403 started at 06:36:26 of Nowruz, and 1404 at 12:31:30 of the day before Nowruz
+    // in Tehran
+    let instant = astro::nowruz_instant(1403).unwrap();
+    assert_eq!((instant.tm_year, instant.tm_mon, instant.tm_mday, instant.tm_hour, instant.tm_min), (1403, 0, 1, 6, 36));
+    assert_eq!(instant.tm_utcoff, 12600);
+    let instant = astro::nowruz_instant(1404).unwrap();
+    assert_eq!((instant.tm_mon, instant.tm_mday, instant.tm_hour), (11, 30, 12));
+    assert_eq!(astro::nowruz_instant(i32::MAX), None);
+}
+
+#[test]
+fn astronomical_nowruz() {
+    // The equinox of 1404 is after the noon, so Nowruz is on the next day
+    assert_eq!(astro::astronomical_nowruz_of(1403), ptime::nowruz_of(1403));
+    assert_eq!(astro::astronomical_nowruz_of(1404), ptime::nowruz_of(1404));
+    assert_eq!(ptime::nowruz_of(1404).unwrap().to_gregorian_date(), (2025, 2, 21));
+    assert!((1300..=1450).all(|year| astro::astronomical_nowruz_of(year) == ptime::nowruz_of(year)));
+    assert_eq!(astro::astronomical_nowruz_of(3000), None);
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "bson")]
+
+extern crate bson;
+extern crate ptime;
+#[macro_use]
+extern crate serde;
+extern crate serde_json;
+
+use bson::DateTime;
+use std::convert::TryFrom;
+
+#[test]
+fn conversions() {
+    let p_tm = ptime::localize(&ptime::from_persian_components(1395, 0, 2, 10, 30, 5, 250_999_999).unwrap(), ptime::Offset::Fixed(12600));
+    let date_time = DateTime::from(p_tm);
+    assert_eq!(date_time.timestamp_millis(), 1458543605250);
+    assert_eq!(ptime::Tm::try_from(date_time).unwrap(), ptime::from_persian_components(1395, 0, 2, 7, 0, 5, 250_000_000).unwrap());
+
+    let date_time = DateTime::from_millis(-1);
+    assert_eq!(ptime::Tm::try_from(date_time).unwrap(), ptime::from_gregorian_components(1969, 11, 31, 23, 59, 59, 999_000_000).unwrap());
+    assert_eq!(ptime::Tm::try_from(DateTime::MAX), Err(ptime::DateError::YearOutOfRange { year: 292278994 }));
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Event {
+    #[serde(with = "ptime::serde::bson_datetime")]
+    at: ptime::Tm,
+}
+
+#[test]
+fn bson_datetime() {
+    let event = Event { at: ptime::from_persian_components(1395, 0, 2, 7, 0, 5, 0).unwrap() };
+    let json = serde_json::to_string(&event).unwrap();
+    assert_eq!(json, r#"{"at":{"$date":{"$numberLong":"1458543605000"}}}"#);
+    assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+
+    let document = bson::serialize_to_document(&event).unwrap();
+    assert_eq!(document.get_datetime("at").unwrap().timestamp_millis(), 1458543605000);
+    assert_eq!(bson::deserialize_from_document::<Event>(document).unwrap(), event);
+}
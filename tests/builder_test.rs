@@ -0,0 +1,28 @@
+extern crate ptime;
+
+use ptime::{Component, DateError, TmBuilder};
+
+#[test]
+fn build() {
+    let p_tm = TmBuilder::new().year(1395).month(1).day(2).hour(10).minute(30).build();
+    assert_eq!(p_tm.ok(), ptime::from_persian_components(1395, 0, 2, 10, 30, 0, 0));
+
+    let p_tm = TmBuilder::new().year(1395).month(12).day(30).utc_offset(12600).build().unwrap();
+    assert_eq!((p_tm.tm_mon, p_tm.tm_mday, p_tm.tm_utcoff), (11, 30, 12600));
+    assert_eq!((p_tm.tm_wday, p_tm.tm_yday), (2, 365));
+
+    assert_eq!(TmBuilder::new().year(1395).build().ok(), ptime::from_persian_date(1395, 0, 1));
+}
+
+#[test]
+fn build_error() {
+    assert_eq!(TmBuilder::new().month(1).build(), Err(DateError::Missing { component: Component::Year }));
+    assert_eq!(TmBuilder::new().year(1395).month(13).build(), Err(DateError::MonthOutOfRange { month: 12 }));
+    assert_eq!(TmBuilder::new().year(1396).month(12).day(30).build(), Err(DateError::DayOutOfRange { day: 30, max: 29 }));
+    assert_eq!(TmBuilder::new().year(1395).second(60).build(), Err(DateError::InvalidTime { component: Component::Second }));
+    assert_eq!(TmBuilder::new().year(1403).month(i32::MIN).build(), Err(DateError::MonthOutOfRange { month: i32::MIN }));
+    assert_eq!(TmBuilder::new().year(1403).utc_offset(86400).build(), Err(DateError::InvalidTime { component: Component::Offset }));
+    assert_eq!(TmBuilder::new().year(1403).utc_offset(-86400).build(), Err(DateError::InvalidTime { component: Component::Offset }));
+    assert_eq!(TmBuilder::new().year(1403).utc_offset(i32::MIN).build(), Err(DateError::InvalidTime { component: Component::Offset }));
+    assert!(TmBuilder::new().year(1403).utc_offset(-86399).build().is_ok());
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "chrono")]
+
+extern crate chrono;
+extern crate ptime;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use ptime::ToPersian;
+use std::convert::TryFrom;
+
+#[test]
+fn from_chrono() {
+    let date = NaiveDate::from_ymd_opt(2016, 3, 21).unwrap();
+    assert_eq!(ptime::Tm::try_from(date).unwrap(), ptime::from_persian_date(1395, 0, 2).unwrap());
+
+    let date_time = date.and_hms_nano_opt(10, 30, 5, 500).unwrap();
+    let p_tm = ptime::from_persian_components(1395, 0, 2, 10, 30, 5, 500).unwrap();
+    assert_eq!(ptime::Tm::try_from(date_time).unwrap(), p_tm);
+    assert_eq!(ptime::Tm::try_from(Utc.from_utc_datetime(&date_time)).unwrap(), p_tm);
+
+    let tehran = FixedOffset::east_opt(12600).unwrap().from_local_datetime(&date_time).unwrap();
+    let p_tm = ptime::Tm::try_from(tehran).unwrap();
+    assert_eq!((p_tm.tm_mday, p_tm.tm_hour, p_tm.tm_min, p_tm.tm_utcoff), (2, 10, 30, 12600));
+    assert_eq!(p_tm, ptime::at_offset(p_tm.to_timespec(), ptime::Offset::Fixed(12600)));
+}
+
+#[test]
+fn to_chrono() {
+    let p_tm = ptime::localize(&ptime::from_persian_components(1395, 0, 2, 10, 30, 5, 500).unwrap(), ptime::Offset::Fixed(12600));
+    let date = NaiveDate::from_ymd_opt(2016, 3, 21).unwrap();
+    let date_time = date.and_hms_nano_opt(10, 30, 5, 500).unwrap();
+    assert_eq!(NaiveDate::from(p_tm), date);
+    assert_eq!(NaiveDateTime::from(p_tm), date_time);
+    assert_eq!(DateTime::<Utc>::from(p_tm), Utc.from_utc_datetime(&date_time) - chrono::Duration::seconds(12600));
+    let fixed = DateTime::<FixedOffset>::from(p_tm);
+    assert_eq!(fixed.naive_local(), date_time);
+    assert_eq!(fixed.offset().local_minus_utc(), 12600);
+}
+
+#[test]
+fn to_persian() {
+    let date_time = NaiveDate::from_ymd_opt(2016, 3, 21).unwrap().and_hms_opt(10, 30, 0).unwrap();
+    assert_eq!(date_time.date().to_persian().unwrap(), ptime::from_persian_date(1395, 0, 2).unwrap());
+    let p_tm = Utc.from_utc_datetime(&date_time).to_persian().unwrap();
+    assert_eq!(DateTime::<Utc>::from(p_tm).to_persian().unwrap(), p_tm);
+
+    let out_of_range = NaiveDate::from_ymd_opt(20000, 1, 1).unwrap();
+    assert_eq!(out_of_range.to_persian(), Err(ptime::DateError::YearOutOfRange { year: 20000 }));
+    let leap_second = NaiveDate::from_ymd_opt(2016, 12, 31).unwrap().and_hms_milli_opt(23, 59, 59, 1500).unwrap();
+    assert_eq!(leap_second.to_persian(), Err(ptime::DateError::InvalidTime { component: ptime::Component::Nanosecond }));
+}
@@ -0,0 +1,143 @@
+#![cfg(feature = "compat")]
+
+extern crate ptime;
+extern crate time;
+
+// year, month, day of month, weekday, day of year
+static PERSIAN_GREGORIAN: [[[i32; 5]; 2]; 9] = [
+    [
+        [1383, 3, 15, 2, 107],
+        [2004, 6, 5, 1, 186],
+    ],
+    [
+        [1394, 11, 9, 1, 344],
+        [2016, 1, 28, 0, 58],
+    ],
+    [
+        [1394, 9, 11, 6, 286],
+        [2016, 0, 1, 5, 0],
+    ],
+    [
+        [1394, 11, 11, 3, 346],
+        [2016, 2, 1, 2, 60],
+    ],
+    [
+        [1394, 11, 29, 0, 364],
+        [2016, 2, 19, 6, 78],
+    ],
+    [
+        [1395, 0, 1, 1, 0],
+        [2016, 2, 20, 0, 79],
+    ],
+    [
+        [1395, 0, 2, 2, 1],
+        [2016, 2, 21, 1, 80],
+    ],
+    [
+        [1395, 0, 3, 3, 2],
+        [2016, 2, 22, 2, 81],
+    ],
+    [
+        [1395, 9, 11, 0, 286],
+        [2016, 11, 31, 6, 365],
+    ],
+];
+
+#[test]
+fn gregorian_to_persian() {
+    for pair in PERSIAN_GREGORIAN.iter() {
+        let p_tm = ptime::from_gregorian(time::Tm{
+            tm_year: pair[1][0] - 1900,
+            tm_mon: pair[1][1],
+            tm_mday: pair[1][2],
+            tm_hour: 10,
+            tm_min: 30,
+            tm_sec: 50,
+            tm_nsec: 121,
+            tm_wday: pair[1][3],
+            tm_yday: pair[1][4],
+            tm_isdst: 0,
+            tm_utcoff: 0,
+        });
+
+        assert_eq!(p_tm.tm_year, pair[0][0]);
+        assert_eq!(p_tm.tm_mon, pair[0][1]);
+        assert_eq!(p_tm.tm_mday, pair[0][2]);
+        assert_eq!(p_tm.tm_wday, pair[0][3]);
+        assert_eq!(p_tm.tm_yday, pair[0][4]);
+        assert_eq!(p_tm.tm_hour, 10);
+        assert_eq!(p_tm.tm_min, 30);
+        assert_eq!(p_tm.tm_sec, 50);
+        assert_eq!(p_tm.tm_nsec, 121);
+    }
+}
+
+#[test]
+fn persian_to_gregorian() {
+    for pair in PERSIAN_GREGORIAN.iter() {
+        let g_tm = ptime::Tm{
+            tm_year: pair[0][0],
+            tm_mon: pair[0][1],
+            tm_mday: pair[0][2],
+            tm_hour: 10,
+            tm_min: 30,
+            tm_sec: 50,
+            tm_nsec: 121,
+            tm_wday: pair[0][3],
+            tm_yday: pair[0][4],
+            tm_isdst: 0,
+            tm_utcoff: 0,
+        }.to_gregorian();
+
+        assert_eq!(g_tm.tm_year, pair[1][0] - 1900);
+        assert_eq!(g_tm.tm_mon, pair[1][1]);
+        assert_eq!(g_tm.tm_mday, pair[1][2]);
+        assert_eq!(g_tm.tm_wday, pair[1][3]);
+        assert_eq!(g_tm.tm_yday, pair[1][4]);
+        assert_eq!(g_tm.tm_hour, 10);
+        assert_eq!(g_tm.tm_min, 30);
+        assert_eq!(g_tm.tm_sec, 50);
+        assert_eq!(g_tm.tm_nsec, 121);
+    }
+}
+
+#[test]
+fn compare_now_utc() {
+    let mut p_tm = ptime::now_utc();
+    let g_tm = time::now_utc();
+    p_tm.tm_hour = g_tm.tm_hour;
+    p_tm.tm_min = g_tm.tm_min;
+    p_tm.tm_sec = g_tm.tm_sec;
+    p_tm.tm_nsec = g_tm.tm_nsec;
+    assert_eq!(p_tm.to_timespec(), g_tm.to_timespec().into());
+}
+
+#[test]
+fn conversions() {
+    let clock = time::Timespec::new(1458540000, 250);
+    assert_eq!(ptime::Timespec::from(clock), ptime::Timespec::new(1458540000, 250));
+    assert_eq!(time::Timespec::from(ptime::Timespec::from(clock)), clock);
+    assert_eq!(ptime::Duration::from(time::Duration::milliseconds(-1500)), ptime::Duration::milliseconds(-1500));
+    assert_eq!(time::Duration::from(ptime::Duration::nanoseconds(-7)), time::Duration::nanoseconds(-7));
+
+    let p_tm = ptime::at_utc(clock.into());
+    assert_eq!(ptime::Tm::from(time::at_utc(clock)), p_tm);
+    assert_eq!(time::Tm::from(p_tm).to_timespec(), clock);
+    assert_eq!(p_tm - time::at_utc(clock), ptime::Duration::zero());
+    for year in 1..10000 {
+        let p_tm = ptime::from_persian_date(year, 0, 1).unwrap();
+        assert_eq!(ptime::from_gregorian(p_tm.to_gregorian()), p_tm);
+    }
+
+    let p_tm = ptime::localize(&ptime::from_persian_components(1390, 5, 10, 8, 15, 0, 0).unwrap(), ptime::Offset::Tehran);
+    let g_tm: time::Tm = (&p_tm).into();
+    assert_eq!(g_tm, p_tm.to_time_tm());
+    assert_eq!((g_tm.tm_year, g_tm.tm_mon, g_tm.tm_mday, g_tm.tm_hour), (111, 8, 1, 8));
+    assert_eq!((g_tm.tm_utcoff, g_tm.tm_isdst), (16200, 1));
+    assert_eq!(ptime::Tm::from(&g_tm), p_tm);
+    let p_tm: ptime::Tm = g_tm.into();
+    assert_eq!((p_tm.tm_utcoff, p_tm.tm_isdst), (16200, 1));
+
+    // The local timezone of host is the same as `time::at`
+    assert_eq!(ptime::at(clock.into()).tm_utcoff, time::at(clock).tm_utcoff);
+}
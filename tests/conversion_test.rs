@@ -1,5 +1,4 @@
 extern crate ptime;
-extern crate time;
 
 // year, month, day of month, weekday, day of year
 static PERSIAN_GREGORIAN: [[[i32; 5]; 2]; 9] = [
@@ -41,64 +40,6 @@ static PERSIAN_GREGORIAN: [[[i32; 5]; 2]; 9] = [
     ],
 ];
 
-#[test]
-fn gregorian_to_persian() {
-    for pair in PERSIAN_GREGORIAN.iter() {
-        let p_tm = ptime::from_gregorian(time::Tm{
-            tm_year: pair[1][0] - 1900,
-            tm_mon: pair[1][1],
-            tm_mday: pair[1][2],
-            tm_hour: 10,
-            tm_min: 30,
-            tm_sec: 50,
-            tm_nsec: 121,
-            tm_wday: pair[1][3],
-            tm_yday: pair[1][4],
-            tm_isdst: 0,
-            tm_utcoff: 0,
-        });
-
-        assert_eq!(p_tm.tm_year, pair[0][0]);
-        assert_eq!(p_tm.tm_mon, pair[0][1]);
-        assert_eq!(p_tm.tm_mday, pair[0][2]);
-        assert_eq!(p_tm.tm_wday, pair[0][3]);
-        assert_eq!(p_tm.tm_yday, pair[0][4]);
-        assert_eq!(p_tm.tm_hour, 10);
-        assert_eq!(p_tm.tm_min, 30);
-        assert_eq!(p_tm.tm_sec, 50);
-        assert_eq!(p_tm.tm_nsec, 121);
-    }
-}
-
-#[test]
-fn persian_to_gregorian() {
-    for pair in PERSIAN_GREGORIAN.iter() {
-        let g_tm = ptime::Tm{
-            tm_year: pair[0][0],
-            tm_mon: pair[0][1],
-            tm_mday: pair[0][2],
-            tm_hour: 10,
-            tm_min: 30,
-            tm_sec: 50,
-            tm_nsec: 121,
-            tm_wday: pair[0][3],
-            tm_yday: pair[0][4],
-            tm_isdst: 0,
-            tm_utcoff: 0,
-        }.to_gregorian();
-
-        assert_eq!(g_tm.tm_year, pair[1][0] - 1900);
-        assert_eq!(g_tm.tm_mon, pair[1][1]);
-        assert_eq!(g_tm.tm_mday, pair[1][2]);
-        assert_eq!(g_tm.tm_wday, pair[1][3]);
-        assert_eq!(g_tm.tm_yday, pair[1][4]);
-        assert_eq!(g_tm.tm_hour, 10);
-        assert_eq!(g_tm.tm_min, 30);
-        assert_eq!(g_tm.tm_sec, 50);
-        assert_eq!(g_tm.tm_nsec, 121);
-    }
-}
-
 #[test]
 fn gregorian_components_to_persian() {
     for pair in PERSIAN_GREGORIAN.iter() {
@@ -140,17 +81,8 @@ fn persian_components_to_gregorian() {
             121,
         ) {
             Some(p_tm) => {
-                let g_tm = p_tm.to_gregorian();
-
-                assert_eq!(g_tm.tm_year, pair[1][0] - 1900);
-                assert_eq!(g_tm.tm_mon, pair[1][1]);
-                assert_eq!(g_tm.tm_mday, pair[1][2]);
-                assert_eq!(g_tm.tm_wday, pair[1][3]);
-                assert_eq!(g_tm.tm_yday, pair[1][4]);
-                assert_eq!(g_tm.tm_hour, 10);
-                assert_eq!(g_tm.tm_min, 30);
-                assert_eq!(g_tm.tm_sec, 50);
-                assert_eq!(g_tm.tm_nsec, 121);
+                assert_eq!(p_tm.to_gregorian_date(), (pair[1][0], pair[1][1], pair[1][2]));
+                assert_eq!(p_tm.tm_wday, pair[0][3]);
             },
             None => panic!("invalid input validation of {:?}", pair[1])
         }
@@ -158,12 +90,249 @@ fn persian_components_to_gregorian() {
 }
 
 #[test]
-fn compare_now_utc() {
-    let mut p_tm = ptime::now_utc();
-    let g_tm = time::now_utc();
-    p_tm.tm_hour = g_tm.tm_hour;
-    p_tm.tm_min = g_tm.tm_min;
-    p_tm.tm_sec = g_tm.tm_sec;
-    p_tm.tm_nsec = g_tm.tm_nsec;
-    assert_eq!(p_tm.to_timespec(), g_tm.to_timespec());
+fn date_error() {
+    use ptime::{Component, DateError};
+
+    assert_eq!(ptime::try_from_persian_date(1395, 12, 1), Err(DateError::MonthOutOfRange { month: 12 }));
+    assert_eq!(ptime::try_from_persian_date(1396, 11, 30), Err(DateError::DayOutOfRange { day: 30, max: 29 }));
+    assert_eq!(ptime::try_from_persian_date(1395, 0, 0), Err(DateError::DayOutOfRange { day: 0, max: 31 }));
+    assert_eq!(ptime::try_from_gregorian_date(2015, 1, 29), Err(DateError::DayOutOfRange { day: 29, max: 28 }));
+    assert_eq!(ptime::try_from_persian_components(1395, 0, 2, 10, 60, 0, 0), Err(DateError::InvalidTime { component: Component::Minute }));
+    assert_eq!(ptime::try_from_gregorian_components(2016, 2, 21, 24, 0, 0, 0), Err(DateError::InvalidTime { component: Component::Hour }));
+    assert_eq!(ptime::try_from_persian_date(1395, 0, 2).ok(), ptime::from_persian_date(1395, 0, 2));
+    assert_eq!(DateError::DayOutOfRange { day: 30, max: 29 }.to_string(), "day 30 out of range [1, 29]");
+    assert!(ptime::from_persian_date(1395, 0, 0).is_none());
+}
+
+#[test]
+fn supported_range() {
+    use ptime::DateError;
+
+    assert_eq!((ptime::Tm::MIN.tm_year, ptime::Tm::MIN.tm_mon, ptime::Tm::MIN.tm_mday), (ptime::MIN_YEAR, 0, 1));
+    assert_eq!((ptime::Tm::MAX.tm_year, ptime::Tm::MAX.tm_mon, ptime::Tm::MAX.tm_mday), (ptime::MAX_YEAR, 11, 29));
+    assert!(ptime::Tm::MIN < ptime::Tm::MAX);
+    assert_eq!(ptime::try_from_persian_date(-5001, 0, 1), Err(DateError::YearOutOfRange { year: -5001 }));
+    assert_eq!(ptime::try_from_persian_date(10000, 0, 1), Err(DateError::YearOutOfRange { year: 10000 }));
+    assert_eq!(ptime::try_from_gregorian_date(-4380, 0, 1), Err(DateError::YearOutOfRange { year: -4380 }));
+    assert_eq!(ptime::try_from_gregorian_date(10622, 0, 1), Err(DateError::YearOutOfRange { year: 10622 }));
+    assert!(ptime::PersianDate::new(-5001, 0, 1).is_none());
+}
+
+#[test]
+fn rata_die() {
+    let p_tm = ptime::from_persian_date(1395, 0, 1).unwrap();
+    assert_eq!(p_tm.to_rata_die(), 736043);
+    assert_eq!(ptime::from_rata_die(736043), Some(p_tm));
+    assert_eq!(ptime::Tm::default().to_rata_die(), 719163);
+    assert_eq!(ptime::from_rata_die(736043 + 365).unwrap().to_gregorian_date().2, 20);
+    for rata_die in 736000..737000 {
+        assert_eq!(ptime::from_rata_die(rata_die).unwrap().to_rata_die(), rata_die);
+    }
+    assert_eq!(ptime::from_rata_die(ptime::Tm::MIN.to_rata_die() - 1), None);
+    assert_eq!(ptime::from_rata_die(i32::MAX), None);
+}
+
+#[test]
+fn julian_day() {
+    let p_tm = ptime::from_persian_date(1395, 0, 1).unwrap();
+    assert_eq!(p_tm.to_jdn(), 2457468);
+    assert_eq!(ptime::from_jdn(2457468), Some(p_tm));
+    assert_eq!(ptime::from_jdn(0), None);
+
+    assert_eq!(ptime::Tm::default().to_mjd(), 40587.0);
+    assert_eq!(ptime::from_mjd(40587.0), Some(ptime::Tm::default()));
+    assert_eq!(ptime::from_mjd(57467.0), Some(p_tm));
+
+    let p_tm = ptime::from_persian_components(1395, 0, 1, 18, 0, 0, 0).unwrap();
+    assert_eq!(p_tm.to_mjd(), 57467.75);
+    assert_eq!(ptime::from_mjd(57467.75), Some(p_tm));
+    let mut p_tm = ptime::from_persian_components(1395, 0, 1, 21, 30, 0, 0).unwrap();
+    p_tm.tm_utcoff = 12600;
+    assert_eq!(p_tm.to_mjd(), 57467.75);
+    assert_eq!(ptime::from_mjd(f64::NAN), None);
+    assert_eq!(ptime::from_mjd(1e12), None);
+}
+
+#[test]
+fn unix_timestamps() {
+    let p_tm = ptime::from_persian_components(1395, 0, 2, 10, 30, 5, 250_000_000).unwrap();
+    assert_eq!(p_tm.timestamp(), 1458556205);
+    assert_eq!(p_tm.timestamp_millis(), 1458556205250);
+    assert_eq!(p_tm.timestamp_nanos(), 1458556205250000000);
+    assert_eq!(ptime::from_unix_millis(1458556205250), Some(p_tm));
+    assert_eq!(ptime::from_unix_nanos(1458556205250000000), Some(p_tm));
+    assert_eq!(p_tm.with_offset(12600).timestamp_millis(), p_tm.timestamp_millis());
+
+    let before_epoch = ptime::from_unix_millis(-1).unwrap();
+    assert_eq!((before_epoch.tm_mday, before_epoch.tm_hour, before_epoch.tm_sec, before_epoch.tm_nsec), (10, 23, 59, 999_000_000));
+    assert_eq!(before_epoch.timestamp_millis(), -1);
+    assert_eq!(ptime::from_unix_nanos(-1).unwrap().timestamp_nanos(), -1);
+
+    assert_eq!(ptime::from_unix_millis(ptime::Tm::MAX.timestamp_millis() + 1), None);
+    assert_eq!(ptime::from_unix_millis(i64::MIN), None);
+    assert_eq!(ptime::from_unix_nanos(ptime::Tm::MAX.timestamp_nanos()), Some(ptime::Tm::MAX));
+    assert_eq!(ptime::from_unix_nanos(i128::MAX), None);
+}
+
+#[test]
+fn epoch() {
+    use ptime::epoch;
+
+    assert_eq!(epoch::PERSIAN_EPOCH.to_jdn(), epoch::PERSIAN_EPOCH_JDN);
+    assert_eq!(epoch::PERSIAN_EPOCH_JDN, 1948320);
+    assert_eq!(epoch::PERSIAN_EPOCH.to_gregorian_date(), (622, 2, 18));
+    assert_eq!((epoch::UNIX_EPOCH.tm_year, epoch::UNIX_EPOCH.tm_mon, epoch::UNIX_EPOCH.tm_mday), (1348, 9, 11));
+    assert_eq!(epoch::UNIX_EPOCH.to_timespec(), ptime::Timespec::new(0, 0));
+    assert_eq!(epoch::UNIX_EPOCH.to_mjd(), (epoch::UNIX_EPOCH_JDN - epoch::MJD_EPOCH_JDN) as f64);
+    assert_eq!(epoch::UNIX_EPOCH.to_rata_die(), epoch::UNIX_EPOCH_JDN - epoch::RATA_DIE_EPOCH_JDN);
+
+    assert_eq!(ptime::from_jdn(epoch::GREGORIAN_REFORM_JDN).unwrap().to_gregorian_date(), (1582, 9, 15));
+    assert_eq!(ptime::from_jdn(epoch::GREGORIAN_REFORM_JDN - 1).unwrap().to_gregorian_date(), (1582, 9, 4));
+}
+
+#[test]
+fn proleptic_years() {
+    use ptime::Era;
+
+    let p_tm = ptime::from_persian_date(1, 0, 1).unwrap();
+    let prev = ptime::from_jdn(p_tm.to_jdn() - 1).unwrap();
+    assert_eq!((prev.tm_year, prev.tm_mon, prev.tm_mday), (0, 11, 29));
+    assert_eq!((prev.era(), prev.year_of_era()), (Era::BP, 1));
+    assert_eq!((p_tm.era(), p_tm.year_of_era()), (Era::AP, 1));
+    assert_eq!(Era::BP.to_year(2), -1);
+
+    let mut jdn = ptime::from_persian_date(-40, 0, 1).unwrap().to_jdn();
+    for year in -40..40 {
+        assert_eq!(ptime::from_persian_date(year, 0, 1).unwrap().to_jdn(), jdn);
+        assert_eq!(ptime::from_jdn(jdn).unwrap().tm_year, year);
+        assert_eq!(ptime::days_in_year(year), if ptime::from_persian_date(year, 11, 30).is_some() { 366 } else { 365 });
+        jdn += ptime::days_in_year(year);
+    }
+    assert_eq!(ptime::from_jdn(ptime::Tm::MIN.to_jdn()), Some(ptime::Tm::MIN));
+
+    let p_tm = ptime::from_persian_date(-4, 0, 1).unwrap();
+    assert_eq!(p_tm.to_string("Y/MM/dd g"), "5/01/01 پ.ه.");
+    assert_eq!(p_tm.to_locale_string("Y G", &ptime::locale::Transliterated), "5 Before Persian");
+    assert_eq!(ptime::strptime("5/01/01 پ.ه.", "Y/MM/dd g"), Ok(p_tm));
+    assert_eq!(ptime::strptime("5/01/01 ه.ش.", "Y/MM/dd g").unwrap().tm_year, 5);
+    assert_eq!(p_tm.to_string("y/MM/dd g"), "-4/01/01 پ.ه.");
+    assert_eq!(ptime::strptime("-4/01/01 پ.ه.", "y/MM/dd g"), Ok(p_tm));
+    assert!(ptime::strptime("-4/01/01 ه.ش.", "y/MM/dd g").is_err());
+
+    // The year of Display is the astronomical year, so 1 BP and 1 AP are distinct
+    let bp = ptime::from_persian_date(0, 0, 1).unwrap();
+    let ap = ptime::from_persian_date(1, 0, 1).unwrap();
+    assert_eq!((bp.to_string("Y G"), ap.to_string("Y G")), ("1 پیش از هجرت".to_string(), "1 هجری شمسی".to_string()));
+    assert_ne!(format!("{}", bp), format!("{}", ap));
+    assert_eq!(ptime::from_persian_date(1405, 0, 1).unwrap().to_string("yy"), "05");
+}
+
+#[test]
+fn wide_range() {
+    for year in 1..10000 {
+        let p_tm = ptime::from_persian_date(year, 0, 1).unwrap();
+        let seconds = (p_tm.to_jdn() as i64 - ptime::epoch::UNIX_EPOCH_JDN as i64) * 86400;
+        assert_eq!(p_tm.to_timespec(), ptime::Timespec::new(seconds, 0));
+        assert_eq!(ptime::at_utc(p_tm.to_timespec()), p_tm);
+        let (g_year, g_month, g_day) = p_tm.to_gregorian_date();
+        assert_eq!(ptime::from_gregorian_date(g_year, g_month, g_day), Some(p_tm));
+    }
+
+    assert_eq!(ptime::epoch::PERSIAN_EPOCH.to_timespec(), ptime::Timespec::new(-42531955200, 0));
+    assert_eq!(ptime::Tm::MAX.to_timespec().sec % 86400, 86399);
+    assert_eq!((ptime::Tm::MAX - ptime::Tm::MIN).num_days(), (ptime::Tm::MAX.to_jdn() - ptime::Tm::MIN.to_jdn()) as i64);
+    assert_eq!(ptime::Tm::MAX - ptime::Duration::days(365) + ptime::Duration::days(365), ptime::Tm::MAX);
+
+    let p_tm = ptime::from_gregorian_date(1000, 0, 1).unwrap();
+    assert_eq!(p_tm.to_gregorian_date(), (1000, 0, 1));
+}
+
+#[test]
+fn offset() {
+    use ptime::Offset;
+
+    let clock = ptime::Timespec::new(1458543600, 0);
+    let p_tm = ptime::at_offset(clock, Offset::Fixed(12600));
+    assert_eq!((p_tm.tm_year, p_tm.tm_mon, p_tm.tm_mday, p_tm.tm_hour, p_tm.tm_min), (1395, 0, 2, 10, 30));
+    assert_eq!(p_tm.offset(), Offset::Fixed(12600));
+    assert_eq!(p_tm.to_utc(), ptime::at_utc(clock));
+    assert_eq!(p_tm.to_utc().offset(), Offset::Utc);
+    assert_eq!(p_tm.to_offset(Offset::Fixed(-18000)).tm_hour, 2);
+    assert_eq!(p_tm.to_local(), ptime::at(clock));
+    assert_eq!(p_tm.to_local().cmp(&p_tm), std::cmp::Ordering::Equal);
+    assert_eq!(p_tm - ptime::at_utc(clock), ptime::Duration::zero());
+
+    let mut p_tm = ptime::from_persian_components(1395, 0, 2, 10, 30, 0, 0).unwrap();
+    p_tm.tm_utcoff = 12600;
+    assert_eq!(p_tm.to_timespec(), clock);
+    assert_eq!(p_tm.to_timespec_utc_naive(), clock + ptime::Duration::seconds(12600));
+
+    // 29 Esfand 23:30 UTC is 1 Farvardin 03:00 +03:30 of the next year
+    let p_tm = ptime::from_persian_components(1394, 11, 29, 23, 30, 0, 0).unwrap();
+    let p_tm = p_tm.with_timezone(&Offset::Fixed(-18000)).with_offset(12600);
+    assert_eq!((p_tm.tm_year, p_tm.tm_mon, p_tm.tm_mday, p_tm.tm_hour, p_tm.tm_min), (1395, 0, 1, 3, 0));
+    assert_eq!(p_tm.tm_wday, ptime::from_persian_date(1395, 0, 1).unwrap().tm_wday);
+    assert_eq!(p_tm.with_offset(-18000).tm_hour, 18);
+    assert_eq!(Offset::Fixed(-12600).to_string(), "-03:30");
+}
+
+#[test]
+fn tehran() {
+    // 1 Farvardin 1395 00:00 +03:30 and the start of daylight saving time a day later
+    let nowruz = ptime::Timespec::new(1458419400, 0);
+    let p_tm = ptime::at_tehran(nowruz);
+    assert_eq!((p_tm.tm_mon, p_tm.tm_mday, p_tm.tm_hour, p_tm.tm_utcoff, p_tm.tm_isdst), (0, 1, 0, 12600, 0));
+    let p_tm = ptime::at_tehran(nowruz + ptime::Duration::days(1) - ptime::Duration::seconds(1));
+    assert_eq!((p_tm.tm_mday, p_tm.tm_hour, p_tm.tm_utcoff), (1, 23, 12600));
+    let p_tm = ptime::at_tehran(nowruz + ptime::Duration::days(1));
+    assert_eq!((p_tm.tm_mday, p_tm.tm_hour, p_tm.tm_utcoff, p_tm.tm_isdst), (2, 1, 16200, 1));
+
+    // 30 Shahrivar 1395 24:00 +04:30 falls back to 23:00 +03:30
+    let fall_back = ptime::Timespec::new(1474399800, 0);
+    let p_tm = ptime::at_tehran(fall_back - ptime::Duration::seconds(1));
+    assert_eq!((p_tm.tm_mon, p_tm.tm_mday, p_tm.tm_hour, p_tm.tm_min, p_tm.tm_utcoff), (5, 30, 23, 59, 16200));
+    let p_tm = ptime::at_tehran(fall_back);
+    assert_eq!((p_tm.tm_mon, p_tm.tm_mday, p_tm.tm_hour, p_tm.tm_min, p_tm.tm_utcoff), (5, 30, 23, 0, 12600));
+
+    // The offset of +04:00 from Aban 1356 to Aban 1357, and no daylight saving time in 1385
+    let p_tm = ptime::at_tehran(ptime::Timespec::new(265000000, 0));
+    assert_eq!((p_tm.tm_year, p_tm.tm_mon, p_tm.tm_utcoff, p_tm.tm_isdst), (1357, 2, 18000, 1));
+    let p_tm = ptime::at_tehran(ptime::Timespec::new(250000000, 0));
+    assert_eq!((p_tm.tm_year, p_tm.tm_utcoff, p_tm.tm_isdst), (1356, 14400, 0));
+    assert_eq!(ptime::at_tehran(ptime::Timespec::new(1150000000, 0)).tm_utcoff, 12600);
+    assert_eq!(ptime::at_tehran(ptime::Timespec::new(1120000000, 0)).tm_utcoff, 16200);
+
+    // No daylight saving time after its abolition in 1401
+    let p_tm = ptime::from_persian_components(1403, 3, 1, 12, 0, 0, 0).unwrap().to_offset(ptime::Offset::Tehran);
+    assert_eq!((p_tm.tm_hour, p_tm.tm_min, p_tm.tm_utcoff), (15, 30, 12600));
+    assert_eq!(ptime::now_tehran().tm_utcoff, 12600);
+    assert_eq!(ptime::Offset::Tehran.to_string(), "Asia/Tehran");
+}
+
+#[test]
+fn daylight_saving_arithmetic() {
+    use ptime::Offset;
+
+    // 1 Farvardin 1395 12:00 +03:30 and 2 Farvardin 12:00 +04:30 are 23 hours apart
+    let p_tm = ptime::at_tehran(ptime::Timespec::new(1458462600, 0));
+    assert_eq!((p_tm.tm_mday, p_tm.tm_hour, p_tm.tm_utcoff), (1, 12, 12600));
+    let next = p_tm.add_days_in(1, Offset::Tehran);
+    assert_eq!((next.tm_mday, next.tm_hour, next.tm_utcoff, next.tm_isdst), (2, 12, 16200, 1));
+    assert_eq!(next - p_tm, ptime::Duration::hours(23));
+    assert_eq!(p_tm + ptime::Duration::days(1), next + ptime::Duration::hours(1));
+    assert_eq!(p_tm.add_days(1).tm_utcoff, 12600);
+    assert_eq!(next.add_days_in(-1, Offset::Tehran), p_tm);
+    assert_eq!(next.add_months_in(6, ptime::EndOfMonth::Clamp, Offset::Tehran).tm_utcoff, 12600);
+
+    // 30 Shahrivar 1395 12:00 +04:30 and 31 Shahrivar 12:00 +03:30 are 25 hours apart
+    let p_tm = ptime::at_tehran(ptime::Timespec::new(1474358400, 0));
+    assert_eq!((p_tm.tm_mon, p_tm.tm_mday, p_tm.tm_hour, p_tm.tm_utcoff), (5, 30, 12, 16200));
+    assert_eq!(p_tm.add_days_in(1, Offset::Tehran) - p_tm, ptime::Duration::hours(25));
+
+    // 2 Farvardin 00:30 is skipped and 30 Shahrivar 23:30 is repeated
+    let skipped = ptime::localize(&ptime::from_persian_components(1395, 0, 2, 0, 30, 0, 0).unwrap(), Offset::Tehran);
+    assert_eq!((skipped.tm_hour, skipped.tm_min, skipped.tm_utcoff), (1, 30, 16200));
+    let repeated = ptime::localize(&ptime::from_persian_components(1395, 5, 30, 23, 30, 0, 0).unwrap(), Offset::Tehran);
+    assert_eq!((repeated.tm_hour, repeated.tm_min, repeated.tm_utcoff), (23, 30, 16200));
+    assert_eq!(ptime::localize(&repeated, Offset::Fixed(-18000)).tm_utcoff, -18000);
 }
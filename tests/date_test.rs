@@ -0,0 +1,87 @@
+#[macro_use]
+extern crate ptime;
+
+use ptime::{PersianDate, PersianDateTime, PersianTime};
+
+#[test]
+fn date() {
+    let date = PersianDate::new(1395, 0, 2).unwrap();
+    assert_eq!((date.year(), date.month(), date.day()), (1395, ptime::Month::Farvardin, 2));
+    assert_eq!((date.month_number(), date.ordinal()), (1, 2));
+    assert_eq!((date.weekday(), date.yday()), (ptime::Weekday::Doshanbeh, 1));
+    assert_eq!(date.to_string(), "1395-01-02");
+    assert_eq!(date.to_tm(), ptime::from_persian_date(1395, 0, 2).unwrap());
+    assert_eq!(PersianDate::new(1395, 11, 30).unwrap().weekday(), ptime::from_persian_date(1395, 11, 30).unwrap().weekday());
+    assert!(PersianDate::new(1396, 11, 30).is_none());
+    assert!(PersianDate::new(1395, 12, 1).is_none());
+    assert!(PersianDate::new(1395, 0, 0).is_none());
+    assert!(PersianDate::new(1395, 0, 2) < PersianDate::new(1395, 1, 1));
+}
+
+#[test]
+fn time() {
+    let time = PersianTime::new(10, 30, 5, 0).unwrap();
+    assert_eq!((time.hour(), time.minute(), time.second(), time.nanosecond()), (10, 30, 5, 0));
+    assert_eq!(time.to_string(), "10:30:05");
+    assert_eq!(PersianTime::new(10, 30, 5, 250_000_000).unwrap().to_string(), "10:30:05.250000000");
+    assert_eq!(PersianTime::MIDNIGHT, PersianTime::new(0, 0, 0, 0).unwrap());
+    assert!(PersianTime::new(24, 0, 0, 0).is_none());
+}
+
+#[test]
+fn date_time() {
+    let p_tm = ptime::from_persian_components(1395, 0, 2, 10, 30, 5, 0).unwrap();
+    let date_time = p_tm.date_time();
+    assert_eq!(date_time, PersianDateTime::new(p_tm.date(), p_tm.time()));
+    assert_eq!(date_time.date(), PersianDate::new(1395, 0, 2).unwrap());
+    assert_eq!(date_time.to_string(), "1395-01-02T10:30:05");
+    assert_eq!(date_time.to_tm(), p_tm);
+    assert_eq!(ptime::Tm::from(PersianDate::from(p_tm)), ptime::from_persian_date(1395, 0, 2).unwrap());
+}
+
+#[test]
+fn date_literal() {
+    const NOWRUZ: PersianDate = pdate!(1395-01-01);
+    assert_eq!(NOWRUZ, PersianDate::new(1395, 0, 1).unwrap());
+    assert_eq!(pdate!(1395-12-30), PersianDate::new(1395, 11, 30).unwrap());
+}
+
+#[test]
+fn const_construction() {
+    const NOWRUZ: Option<ptime::Tm> = ptime::from_persian_date(1395, 0, 1);
+    static DATES: [Option<PersianDate>; 2] = [PersianDate::new(1395, 0, 1), PersianDate::new(1396, 11, 30)];
+    const WEEKDAY: ptime::Weekday = match PersianDate::new(1395, 0, 2) {
+        Some(date) => date.weekday(),
+        None => ptime::Weekday::Jomeh,
+    };
+    const ESFAND_DAYS: i32 = ptime::Month::Esfand.days(1395);
+
+    assert_eq!(NOWRUZ, ptime::from_gregorian_date(2016, 2, 20));
+    assert_eq!(DATES, [NOWRUZ.map(|tm| tm.date()), None]);
+    assert_eq!((WEEKDAY, ESFAND_DAYS), (ptime::Weekday::Doshanbeh, 30));
+}
+
+#[test]
+fn unchecked_construction() {
+    for &(year, month, day) in &[(1395, 0, 1), (1395, 11, 30), (1403, 6, 15), (-4, 3, 31)] {
+        assert_eq!(Some(ptime::from_persian_components_unchecked(year, month, day, 10, 30, 5, 250)),
+                   ptime::from_persian_components(year, month, day, 10, 30, 5, 250));
+        assert_eq!(Some(PersianDate::new_unchecked(year, month, day)), PersianDate::new(year, month, day));
+    }
+    assert_eq!(Some(PersianTime::new_unchecked(23, 59, 59, 999999999)), PersianTime::new(23, 59, 59, 999999999));
+}
+
+#[test]
+fn from_str() {
+    let date = PersianDate::new(1395, 0, 2).unwrap();
+    let time = PersianTime::new(10, 30, 5, 250000000).unwrap();
+    assert_eq!("1395-01-02".parse(), Ok(date));
+    assert_eq!("10:30:05.250".parse(), Ok(time));
+    assert_eq!("10:30".parse(), Ok(PersianTime::new(10, 30, 0, 0).unwrap()));
+    assert_eq!("1395-01-02T10:30:05.250".parse(), Ok(date.and_time(time)));
+    assert_eq!(date.and_time(time).to_string().parse(), Ok(date.and_time(time)));
+    assert!("1395-12-30".parse::<PersianDate>().is_ok());
+    assert!("1396-12-30".parse::<PersianDate>().is_err());
+    assert!("24:00".parse::<PersianTime>().is_err());
+    assert!("1395-01-02".parse::<PersianDateTime>().is_err());
+}
@@ -0,0 +1,43 @@
+extern crate ptime;
+
+use ptime::{Datelike, Month, PersianDate, PersianDateTime, PersianTime, Timelike, Weekday};
+
+fn date_of<D: Datelike>(date: &D) -> (i32, i32, i32, i32, Weekday) {
+    (date.year(), date.month_number(), date.day(), date.ordinal(), date.weekday())
+}
+
+fn time_of<T: Timelike>(time: &T) -> (i32, i32, i32, i32, i32) {
+    (time.hour(), time.minute(), time.second(), time.nanosecond(), time.num_seconds_from_midnight())
+}
+
+#[test]
+fn datelike() {
+    let p_tm = ptime::from_persian_components(1395, 11, 30, 13, 30, 5, 500).unwrap();
+    let expected = (1395, 12, 30, 366, Weekday::Doshanbeh);
+    assert_eq!(date_of(&p_tm), expected);
+    assert_eq!(date_of(&PersianDate::from_tm(&p_tm)), expected);
+    assert_eq!(date_of(&PersianDateTime::from_tm(&p_tm)), expected);
+    assert!(Datelike::is_leap(&PersianDate::from_tm(&p_tm)));
+    assert_eq!(Datelike::year_of_era(&p_tm), 1395);
+
+    let date = PersianDate::new(1395, 0, 31).unwrap();
+    assert_eq!(date.with_month(Month::Ordibehesht), Ok(PersianDate::new(1395, 1, 31).unwrap()));
+    assert_eq!(date.with_month(Month::Mehr), Err(ptime::DateError::DayOutOfRange { day: 31, max: 30 }));
+    assert_eq!(date.with_year(1396).map(|date| date.ordinal0()), Ok(30));
+    assert_eq!(Datelike::with_day(&p_tm, 1).unwrap(), ptime::from_persian_components(1395, 11, 1, 13, 30, 5, 500).unwrap());
+}
+
+#[test]
+fn timelike() {
+    let p_tm = ptime::from_persian_components(1395, 0, 2, 13, 30, 5, 500).unwrap();
+    let expected = (13, 30, 5, 500, 48605);
+    assert_eq!(time_of(&p_tm), expected);
+    assert_eq!(time_of(&PersianTime::from_tm(&p_tm)), expected);
+    assert_eq!(time_of(&PersianDateTime::from_tm(&p_tm)), expected);
+    assert_eq!(Timelike::hour12(&p_tm), (true, 1));
+    assert_eq!(PersianTime::MIDNIGHT.hour12(), (false, 12));
+
+    let date_time = PersianDateTime::from_tm(&p_tm);
+    assert_eq!(date_time.with_hour(0).map(|date_time| date_time.time()), Ok(PersianTime::new(0, 30, 5, 500).unwrap()));
+    assert_eq!(date_time.with_minute(60), Err(ptime::DateError::InvalidTime { component: ptime::Component::Minute }));
+}
@@ -0,0 +1,24 @@
+extern crate ptime;
+
+use ptime::Offset;
+
+// The default offset is process-wide, so it is tested by a single test in its own binary
+#[test]
+fn default_offset() {
+    let clock = ptime::Timespec::new(1458540000, 0);
+    let host = ptime::at(clock);
+    assert_eq!(ptime::default_offset(), Offset::Local);
+    assert_eq!(host.tm_utcoff, Offset::Local.utc_offset_at(clock));
+
+    ptime::set_default_offset(Offset::Tehran);
+    assert_eq!(ptime::default_offset(), Offset::Tehran);
+    assert_eq!(ptime::at(clock), ptime::at_tehran(clock));
+    assert_eq!(ptime::at_utc(clock).to_local(), ptime::at_tehran(clock));
+    assert_eq!(ptime::now().tm_utcoff, 12600);
+
+    ptime::set_default_offset(Offset::Fixed(-18000));
+    assert_eq!(ptime::at(clock).tm_hour, 1);
+
+    ptime::set_default_offset(Offset::Local);
+    assert_eq!(ptime::at(clock), host);
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "diesel_sqlite")]
+
+extern crate diesel;
+extern crate ptime;
+
+use diesel::dsl::sql;
+use diesel::prelude::*;
+use diesel::sql_types::{Date, Text, Timestamp};
+use diesel::sqlite::SqliteConnection;
+
+table! {
+    events (id) {
+        id -> Integer,
+        day -> Date,
+        starts_at -> Timestamp,
+    }
+}
+
+#[derive(Queryable, Insertable, PartialEq, Debug)]
+#[diesel(table_name = events)]
+struct Event {
+    id: i32,
+    day: ptime::PersianDate,
+    starts_at: ptime::Tm,
+}
+
+fn connect() -> SqliteConnection {
+    let mut conn = SqliteConnection::establish(":memory:").unwrap();
+    diesel::sql_query("CREATE TABLE events (id INTEGER PRIMARY KEY, day TEXT NOT NULL, starts_at TEXT NOT NULL)")
+        .execute(&mut conn)
+        .unwrap();
+    conn
+}
+
+#[test]
+fn roundtrip() {
+    let mut conn = connect();
+    let starts_at = ptime::localize(&ptime::from_persian_components(1403, 0, 1, 12, 30, 5, 250_000_000).unwrap(), ptime::Offset::Fixed(12600));
+    let event = Event {
+        id: 1,
+        day: ptime::PersianDate::new(1403, 0, 1).unwrap(),
+        starts_at,
+    };
+    diesel::insert_into(events::table).values(&event).execute(&mut conn).unwrap();
+
+    let loaded = events::table.first::<Event>(&mut conn).unwrap();
+    assert_eq!(loaded.day, event.day);
+    assert_eq!(loaded.starts_at, starts_at.to_utc());
+
+    let found = events::table.filter(events::day.eq(ptime::PersianDate::new(1403, 0, 1).unwrap())).count().get_result::<i64>(&mut conn).unwrap();
+    assert_eq!(found, 1);
+}
+
+#[test]
+fn stored_as_gregorian() {
+    let mut conn = connect();
+    let event = Event {
+        id: 1,
+        day: ptime::PersianDate::new(1395, 0, 2).unwrap(),
+        starts_at: ptime::localize(&ptime::from_persian_components(1395, 0, 2, 10, 30, 5, 0).unwrap(), ptime::Offset::Fixed(12600)),
+    };
+    diesel::insert_into(events::table).values(&event).execute(&mut conn).unwrap();
+
+    let (day, starts_at) = events::table
+        .select((sql::<Text>("day"), sql::<Text>("starts_at")))
+        .first::<(String, String)>(&mut conn)
+        .unwrap();
+    assert_eq!(day, "2016-03-21");
+    assert_eq!(starts_at, "2016-03-21 07:00:05");
+}
+
+#[test]
+fn from_sqlite_functions() {
+    let mut conn = connect();
+    let day = diesel::select(sql::<Date>("date('2016-03-21', '+1 day')")).get_result::<ptime::PersianDate>(&mut conn).unwrap();
+    assert_eq!(day, ptime::PersianDate::new(1395, 0, 3).unwrap());
+    let starts_at = diesel::select(sql::<Timestamp>("datetime('2016-03-21 07:00:05')")).get_result::<ptime::Tm>(&mut conn).unwrap();
+    assert_eq!(starts_at, ptime::from_persian_components(1395, 0, 2, 7, 0, 5, 0).unwrap());
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "ffi")]
+
+extern crate ptime;
+
+use ptime::ffi::{self, Status};
+use std::ffi::{CStr, CString};
+use std::mem::MaybeUninit;
+use std::ptr;
+
+#[test]
+fn conversions() {
+    let mut tm = MaybeUninit::uninit();
+    assert_eq!(unsafe { ffi::ptime_from_gregorian(2016, 2, 21, 10, 30, 5, 0, tm.as_mut_ptr()) }, Status::Ok);
+    let tm = unsafe { tm.assume_init() };
+    assert_eq!(tm, ptime::from_persian_components(1395, 0, 2, 10, 30, 5, 0).unwrap());
+
+    let (mut year, mut month, mut day) = (0, 0, 0);
+    assert_eq!(unsafe { ffi::ptime_to_gregorian(&tm, &mut year, &mut month, &mut day) }, Status::Ok);
+    assert_eq!((year, month, day), (2016, 2, 21));
+
+    let mut out = ptime::Tm::MIN;
+    assert_eq!(unsafe { ffi::ptime_at_offset(1458543605, 0, 12600, &mut out) }, Status::Ok);
+    assert_eq!(out, ptime::localize(&tm, ptime::Offset::Fixed(12600)));
+    let (mut sec, mut nsec) = (0, 0);
+    assert_eq!(unsafe { ffi::ptime_to_timestamp(&out, &mut sec, &mut nsec) }, Status::Ok);
+    assert_eq!((sec, nsec), (1458543605, 0));
+
+    assert_eq!(unsafe { ffi::ptime_from_persian(1396, 11, 30, 0, 0, 0, 0, &mut out) }, Status::InvalidTime);
+    assert_eq!(unsafe { ffi::ptime_from_persian(1395, 0, 1, 0, 0, 0, 0, ptr::null_mut()) }, Status::NullPointer);
+    assert_eq!(unsafe { ffi::ptime_at_offset(i64::MAX, 0, 0, &mut out) }, Status::InvalidTime);
+    let max = ptime::Tm::MAX.to_timespec().sec;
+    assert_eq!(unsafe { ffi::ptime_at_offset(max, 0, 86399, &mut out) }, Status::InvalidTime);
+    assert_eq!(unsafe { ffi::ptime_at_offset(max, 0, 0, &mut out) }, Status::Ok);
+    assert_eq!(unsafe { ffi::ptime_to_gregorian(&out, &mut year, &mut month, &mut day) }, Status::Ok);
+    let min = ptime::Tm::MIN.to_timespec().sec;
+    assert_eq!(unsafe { ffi::ptime_at_offset(min, 0, -1, &mut out) }, Status::InvalidTime);
+}
+
+#[test]
+fn validates_input() {
+    // The weekday and the day of year are recomputed
+    let mut tm = ptime::from_persian_date(1395, 0, 2).unwrap();
+    tm.tm_wday = 9;
+    let (mut sec, mut nsec) = (0, 0);
+    assert_eq!(unsafe { ffi::ptime_to_timestamp(&tm, &mut sec, &mut nsec) }, Status::Ok);
+    tm.tm_mday = 32;
+    assert_eq!(unsafe { ffi::ptime_to_timestamp(&tm, &mut sec, &mut nsec) }, Status::InvalidTime);
+    assert_eq!(unsafe { ffi::ptime_to_timestamp(ptr::null(), &mut sec, &mut nsec) }, Status::NullPointer);
+}
+
+#[test]
+fn format() {
+    let tm = ptime::from_persian_date(1395, 0, 2).unwrap();
+    let format = CString::new("yyyy/MM/dd E").unwrap();
+    let mut buf = [0x7f as std::os::raw::c_char; 64];
+    let len = unsafe { ffi::ptime_format(&tm, format.as_ptr(), buf.as_mut_ptr(), buf.len()) };
+    let formatted = unsafe { CStr::from_ptr(buf.as_ptr()) };
+    assert_eq!(formatted.to_str().unwrap(), "1395/01/02 دوشنبه");
+    assert_eq!(len as usize, formatted.to_bytes().len());
+
+    let len = unsafe { ffi::ptime_format(&tm, format.as_ptr(), buf.as_mut_ptr(), 5) };
+    assert_eq!(unsafe { CStr::from_ptr(buf.as_ptr()) }.to_bytes(), b"1395");
+    assert_eq!(len, 23);
+    assert_eq!(unsafe { ffi::ptime_format(&tm, format.as_ptr(), ptr::null_mut(), 0) }, 23);
+    assert_eq!(unsafe { ffi::ptime_format(&tm, ptr::null(), buf.as_mut_ptr(), buf.len()) }, -(Status::NullPointer as isize));
+}
+
+#[test]
+fn parse() {
+    let s = CString::new("1395/01/02").unwrap();
+    let format = CString::new("yyyy/MM/dd").unwrap();
+    let mut tm = ptime::Tm::MIN;
+    assert_eq!(unsafe { ffi::ptime_parse(s.as_ptr(), format.as_ptr(), &mut tm) }, Status::Ok);
+    assert_eq!(tm, ptime::from_persian_date(1395, 0, 2).unwrap());
+
+    let s = CString::new("1395-01-02").unwrap();
+    assert_eq!(unsafe { ffi::ptime_parse(s.as_ptr(), format.as_ptr(), &mut tm) }, Status::ParseFailed);
+    let s = CString::new(vec![0xff, 0xfe]).unwrap();
+    assert_eq!(unsafe { ffi::ptime_parse(s.as_ptr(), format.as_ptr(), &mut tm) }, Status::InvalidUtf8);
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "filetime")]
+
+extern crate ptime;
+
+#[test]
+fn conversions() {
+    // 2016-03-21T07:00:05.1234567Z
+    let p_tm = ptime::from_persian_components(1395, 0, 2, 7, 0, 5, 123_456_700).unwrap();
+    assert_eq!(p_tm.to_filetime(), Some(131030172051234567));
+    assert_eq!(ptime::from_filetime(131030172051234567), Some(p_tm));
+    assert_eq!(p_tm.with_offset(12600).to_filetime(), p_tm.to_filetime());
+
+    let mut p_tm = p_tm;
+    p_tm.tm_nsec = 123_456_789;
+    assert_eq!(p_tm.to_filetime(), Some(131030172051234567));
+}
+
+#[test]
+fn epochs() {
+    let epoch = ptime::from_filetime(0).unwrap();
+    assert_eq!(epoch.to_gregorian_date(), (1601, 0, 1));
+    assert_eq!(epoch.to_jdn(), ptime::epoch::WINDOWS_EPOCH_JDN);
+    assert_eq!(ptime::epoch::UNIX_EPOCH.to_filetime(), Some(116444736000000000));
+    assert_eq!(ptime::from_filetime(116444736000000000), Some(ptime::epoch::UNIX_EPOCH));
+
+    let before = ptime::from_jdn(ptime::epoch::WINDOWS_EPOCH_JDN - 1).unwrap();
+    assert_eq!(before.to_filetime(), None);
+    assert_eq!(ptime::from_filetime(u64::MAX), None);
+    assert!(ptime::Tm::MAX.to_filetime().is_some());
+}
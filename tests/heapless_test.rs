@@ -0,0 +1,12 @@
+#![cfg(feature = "heapless")]
+
+extern crate ptime;
+
+#[test]
+fn to_heapless_string() {
+    const FORMAT: &str = "E d MMM yyyy";
+    let p_tm = ptime::from_persian_date(1395, 0, 2).unwrap();
+    let formatted = p_tm.to_heapless_string::<{ ptime::max_formatted_len(FORMAT) }>(FORMAT).unwrap();
+    assert_eq!(formatted.as_str(), "دوشنبه 2 فروردین 1395");
+    assert_eq!(p_tm.to_heapless_string::<8>(FORMAT), None);
+}
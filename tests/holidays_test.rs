@@ -0,0 +1,149 @@
+extern crate ptime;
+
+use ptime::holidays;
+
+#[test]
+fn public_holidays() {
+    assert!(holidays::is_public_holiday(&ptime::from_persian_date(1403, 0, 1).unwrap()));
+    assert!(holidays::is_public_holiday(&ptime::from_persian_date(1403, 0, 13).unwrap()));
+    assert!(holidays::is_public_holiday(&ptime::from_persian_components(1403, 10, 22, 18, 0, 0, 0).unwrap()));
+    assert!(holidays::is_public_holiday(&ptime::from_persian_date(1402, 11, 29).unwrap()));
+    assert!(!holidays::is_public_holiday(&ptime::from_persian_date(1403, 0, 5).unwrap()));
+    assert!(!holidays::is_public_holiday(&ptime::from_persian_date(1403, 11, 30).unwrap()));
+}
+
+#[test]
+fn holidays_in_year() {
+    let holidays = holidays::holidays_in_year(1403);
+    let fixed: Vec<_> = holidays.iter().filter(|holiday| !holiday.is_lunar()).collect();
+    assert_eq!(fixed.len(), 10);
+    assert_eq!(fixed[0].date(), ptime::PersianDate::new(1403, 0, 1).unwrap());
+    assert_eq!(fixed[0].name(), "عید نوروز");
+    assert_eq!(fixed[6].date(), ptime::PersianDate::new(1403, 2, 14).unwrap());
+    assert_eq!(fixed[9].date(), ptime::PersianDate::new(1403, 11, 29).unwrap());
+    assert!(holidays.windows(2).all(|pair| pair[0].date() <= pair[1].date()));
+    assert!(holidays.iter().all(|holiday| holidays::is_public_holiday(&holiday.date().to_tm())));
+    assert!(holidays::holidays_in_year(ptime::MAX_YEAR + 1).is_empty());
+}
+
+#[test]
+fn working_days() {
+    // Nowruz 1403 falls on Chaharshanbeh to Shanbeh, where Jomeh is the weekend
+    let from = ptime::from_persian_date(1403, 0, 1).unwrap();
+    let to = ptime::from_persian_date(1403, 0, 8).unwrap();
+    let weekend = ptime::Weekend::default();
+    assert_eq!(ptime::working_days_between(&from, &to, &weekend, None), 6);
+    assert_eq!(ptime::working_days_between(&from, &to, &weekend, Some(&holidays::Iran::new())), 3);
+}
+
+#[test]
+fn lunar_holidays() {
+    // The year 1403 spans from 10 Ramadan 1445 AH to 20 Ramadan 1446 AH
+    let holidays = holidays::holidays_in_year(1403);
+    let lunar: Vec<_> = holidays.iter().filter(|holiday| holiday.is_lunar()).collect();
+    assert_eq!(lunar.len(), 17);
+    assert_eq!(lunar[0].name(), "شهادت امام علی");
+    assert_eq!(lunar[0].date(), ptime::PersianDate::new(1403, 0, 12).unwrap());
+    assert_eq!(lunar[1].name(), "عید سعید فطر");
+    assert_eq!(lunar[1].date(), ptime::PersianDate::new(1403, 0, 22).unwrap());
+    assert_eq!(lunar[2].date(), ptime::PersianDate::new(1403, 0, 23).unwrap());
+    assert!(holidays::is_public_holiday(&ptime::from_persian_date(1403, 0, 22).unwrap()));
+
+    // The tabular Ashura of 1446 AH is 27 Tir, a day after the observed one
+    let ashura = lunar.iter().find(|holiday| holiday.name() == "عاشورای حسینی").unwrap();
+    assert_eq!(ashura.date(), ptime::PersianDate::new(1403, 3, 27).unwrap());
+    // The last day of Safar has 29 days in the tabular calendar
+    let reza = lunar.iter().find(|holiday| holiday.name() == "شهادت امام رضا").unwrap();
+    assert_eq!(reza.date(), ptime::PersianDate::new(1403, 5, 14).unwrap());
+
+    // A lunar holiday falls twice in some Persian years
+    let fitr = |year| holidays::holidays_in_year(year).iter().filter(|holiday| holiday.name() == "عید سعید فطر").count();
+    assert_eq!(fitr(1403), 1);
+    assert!((1300..1450).any(|year| fitr(year) == 2));
+}
+
+#[test]
+fn observed_month_starts() {
+    let starts = holidays::HijriMonthStarts::new()
+        .with_month_start(1446, 1, ptime::PersianDate::new(1403, 3, 17).unwrap())
+        .with_month_start(1446, 3, ptime::PersianDate::new(1403, 5, 14).unwrap());
+    let holidays = holidays::holidays_in_year_observed(1403, &starts);
+    let ashura = holidays.iter().find(|holiday| holiday.name() == "عاشورای حسینی").unwrap();
+    assert_eq!(ashura.date(), ptime::PersianDate::new(1403, 3, 26).unwrap());
+    let reza = holidays.iter().find(|holiday| holiday.name() == "شهادت امام رضا").unwrap();
+    assert_eq!(reza.date(), ptime::PersianDate::new(1403, 5, 13).unwrap());
+
+    // Tasua is a day earlier too
+    let observed = ptime::from_persian_date(1403, 3, 25).unwrap();
+    assert!(holidays::is_public_holiday_observed(&observed, &starts));
+    assert!(!holidays::is_public_holiday(&observed));
+    assert!(!holidays::is_public_holiday_observed(&ptime::from_persian_date(1403, 3, 27).unwrap(), &starts));
+}
+
+#[test]
+fn holiday_calendars() {
+    use ptime::holidays::{Custom, Holiday, HolidayCalendar, Iran};
+
+    let iran = Iran::new();
+    assert_eq!(iran.holidays(1403), holidays::holidays_in_year(1403));
+    assert!(iran.is_holiday(&ptime::from_persian_date(1403, 10, 22).unwrap()));
+
+    let founding = Holiday::new(ptime::PersianDate::new(1403, 6, 5).unwrap(), "روز شرکت");
+    let company = Custom::new().with_yearly(11, 30, "پایان سال").with_holiday(founding);
+    assert_eq!(company.holidays(1403).len(), 2);
+    assert_eq!(company.holidays(1404), vec![]);
+    assert_eq!(company.holidays(1399)[0].date(), ptime::PersianDate::new(1399, 11, 30).unwrap());
+    assert!(company.is_holiday(&ptime::from_persian_date(1403, 6, 5).unwrap()));
+    assert!(!company.is_holiday(&ptime::from_persian_date(1403, 0, 1).unwrap()));
+
+    let layered = (&iran, &company);
+    let holidays = layered.holidays(1403);
+    assert_eq!(holidays.len(), iran.holidays(1403).len() + 2);
+    assert!(holidays.windows(2).all(|pair| pair[0].date() <= pair[1].date()));
+    assert!(layered.is_holiday(&ptime::from_persian_date(1403, 0, 1).unwrap()));
+    assert!(layered.is_holiday(&ptime::from_persian_date(1403, 6, 5).unwrap()));
+
+    let observed = Iran::observed(holidays::HijriMonthStarts::new().with_month_start(1446, 1, ptime::PersianDate::new(1403, 3, 17).unwrap()));
+    assert!(observed.is_holiday(&ptime::from_persian_date(1403, 3, 25).unwrap()));
+}
+
+#[test]
+fn working_days_of_calendar() {
+    let calendar = holidays::Iran::new();
+    let weekend = ptime::Weekend::default();
+    // 29 Esfand 1402 is Seshanbeh and Nowruz 1403 spans from Chaharshanbeh to Shanbeh
+    let p_tm = ptime::from_persian_components(1402, 11, 28, 9, 0, 0, 0).unwrap();
+    assert!(p_tm.is_working_day(&weekend, &calendar));
+    assert!(!p_tm.add_days(1).is_working_day(&weekend, &calendar));
+    let next = p_tm.add_working_days(1, &weekend, &calendar).unwrap();
+    assert_eq!((next.tm_year, next.tm_mon, next.tm_mday, next.tm_hour), (1403, 0, 5, 9));
+    assert_eq!(next.add_working_days(-1, &weekend, &calendar), Some(p_tm));
+    assert_eq!(p_tm.add_working_days(0, &weekend, &calendar), Some(p_tm));
+}
+
+#[test]
+fn working_days_out_of_range() {
+    use ptime::holidays::{Holiday, HolidayCalendar};
+
+    // Every day is a holiday
+    struct Closed;
+    impl HolidayCalendar for Closed {
+        fn holidays(&self, _year: i32) -> Vec<Holiday> {
+            Vec::new()
+        }
+
+        fn is_holiday(&self, _tm: &ptime::Tm) -> bool {
+            true
+        }
+    }
+
+    let weekend = ptime::Weekend::default();
+    let p_tm = ptime::from_persian_date(ptime::MAX_YEAR, 11, 1).unwrap();
+    assert_eq!(p_tm.add_working_days(1, &weekend, &Closed), None);
+    let p_tm = ptime::from_persian_date(ptime::MIN_YEAR, 0, 20).unwrap();
+    assert_eq!(p_tm.add_working_days(-1, &weekend, &Closed), None);
+
+    let p_tm = ptime::from_persian_date(ptime::MAX_YEAR, 11, 28).unwrap();
+    assert_eq!(p_tm.add_working_days(5, &weekend, &holidays::Iran::new()), None);
+    assert!(p_tm.add_working_days(-5, &weekend, &holidays::Iran::new()).is_some());
+}
@@ -0,0 +1,23 @@
+extern crate ptime;
+
+use ptime::Duration;
+
+#[test]
+fn humanize() {
+    let p_tm = ptime::from_persian_components(1403, 0, 10, 12, 0, 0, 0).unwrap();
+    assert_eq!((p_tm - Duration::seconds(10)).humanize(&p_tm), "چند لحظه پیش");
+    assert_eq!((p_tm + Duration::seconds(10)).humanize(&p_tm), "چند لحظه دیگر");
+    assert_eq!((p_tm - Duration::minutes(5)).humanize(&p_tm), "۵ دقیقه پیش");
+    assert_eq!((p_tm - Duration::days(3)).humanize(&p_tm), "۳ روز پیش");
+    assert_eq!((p_tm + Duration::days(14)).humanize(&p_tm), "۲ هفته دیگر");
+    assert_eq!((p_tm + Duration::days(60)).humanize(&p_tm), "۲ ماه دیگر");
+    assert_eq!((p_tm - Duration::days(800)).humanize(&p_tm), "۲ سال پیش");
+}
+
+#[test]
+fn duration() {
+    assert_eq!(ptime::humanize::duration(Duration::minutes(135)), "۲ ساعت و ۱۵ دقیقه");
+    assert_eq!(ptime::humanize::duration(Duration::seconds(90061)), "۱ روز و ۱ ساعت و ۱ دقیقه و ۱ ثانیه");
+    assert_eq!(ptime::humanize::duration(Duration::milliseconds(500)), "۰ ثانیه");
+    assert_eq!(ptime::humanize::duration(Duration::seconds(-45)), "منفی ۴۵ ثانیه");
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "icu_calendar")]
+
+extern crate icu_calendar;
+extern crate ptime;
+
+use icu_calendar::cal::Persian;
+use icu_calendar::{Date, Iso};
+use std::convert::TryFrom;
+
+#[test]
+fn from_icu() {
+    let date = Date::try_new_iso(2016, 3, 21).unwrap();
+    assert_eq!(ptime::Tm::try_from(date).unwrap(), ptime::from_persian_date(1395, 0, 2).unwrap());
+    let date = Date::try_new_persian(1395, 12, 30).unwrap();
+    assert_eq!(ptime::Tm::try_from(date).unwrap(), ptime::from_persian_date(1395, 11, 30).unwrap());
+    let date = Date::try_new_iso(-9000, 1, 1).unwrap();
+    assert_eq!(ptime::Tm::try_from(date), Err(ptime::DateError::YearOutOfRange { year: -9000 }));
+}
+
+#[test]
+fn to_icu() {
+    let p_tm = ptime::from_persian_components(1403, 11, 30, 10, 30, 0, 0).unwrap();
+    let date = Date::<Persian>::try_from(p_tm).unwrap();
+    assert_eq!((date.year().extended_year(), date.month().ordinal, date.day_of_month().0), (1403, 12, 30));
+}
+
+#[test]
+fn cross_validate() {
+    // The last day of each year agrees with ICU4X, which follows the 33-year rule until 1501
+    for year in 1178..1502 {
+        let p_tm = ptime::from_persian_date(year, 11, ptime::days_in_month(year, 11).unwrap()).unwrap();
+        let iso = Date::<Persian>::try_from(p_tm).unwrap().to_calendar(Iso);
+        let (g_year, g_month, g_day) = p_tm.to_gregorian_date();
+        assert_eq!((iso.year().extended_year(), iso.month().ordinal as i32 - 1, iso.day_of_month().0 as i32), (g_year, g_month, g_day));
+    }
+    // ICU4X corrects the rule to match the astronomical calendar from 1502, which is not leap
+    assert!(ptime::from_persian_date(1502, 11, 30).unwrap().is_leap());
+    assert!(Date::try_new_persian(1502, 12, 30).is_err());
+}
@@ -0,0 +1,44 @@
+extern crate ptime;
+
+use ptime::iter;
+
+#[test]
+fn months_between() {
+    let from = ptime::from_persian_components(1403, 6, 15, 10, 0, 0, 0).unwrap();
+    let to = ptime::from_persian_date(1403, 8, 10).unwrap();
+    let months: Vec<_> = iter::months_between(&from, &to).map(|tm| (tm.tm_year, tm.tm_mon, tm.tm_mday, tm.tm_hour)).collect();
+    assert_eq!(months, vec![(1403, 6, 1, 0), (1403, 7, 1, 0), (1403, 8, 1, 0)]);
+
+    let to = ptime::from_persian_date(1404, 0, 1).unwrap();
+    let months: Vec<_> = iter::months_between(&from, &to).map(|tm| (tm.tm_year, tm.tm_mon)).collect();
+    assert_eq!(months.len(), 7);
+    assert_eq!(months.last(), Some(&(1404, 0)));
+    assert_eq!(iter::months_between(&to, &from).next(), None);
+    assert_eq!(iter::months_between(&ptime::Tm::MAX, &ptime::Tm::MAX).count(), 1);
+}
+
+#[test]
+fn years_between() {
+    let from = ptime::from_persian_date(1399, 11, 30).unwrap();
+    let to = ptime::from_persian_date(1403, 0, 1).unwrap();
+    let years: Vec<_> = iter::years_between(&from, &to).collect();
+    assert_eq!(years.iter().map(|tm| tm.tm_year).collect::<Vec<_>>(), vec![1399, 1400, 1401, 1402, 1403]);
+    assert!(years.iter().all(|tm| tm.tm_yday == 0));
+    assert_eq!(iter::years_between(&ptime::Tm::MAX, &ptime::Tm::MAX).count(), 1);
+}
+
+#[test]
+fn weeks_between() {
+    use ptime::Weekday;
+
+    // 1403/07/17 is Seshanbeh and 1403/07/28 is Shanbeh
+    let from = ptime::from_persian_components(1403, 6, 17, 10, 0, 0, 0).unwrap();
+    let to = ptime::from_persian_date(1403, 6, 28).unwrap();
+    let weeks: Vec<_> = iter::weeks_between(&from, &to).map(|tm| (tm.tm_mday, tm.weekday(), tm.tm_hour)).collect();
+    assert_eq!(weeks, vec![(14, Weekday::Shanbeh, 0), (21, Weekday::Shanbeh, 0), (28, Weekday::Shanbeh, 0)]);
+
+    let weeks: Vec<_> = iter::weeks_starting_on(&from, &to, Weekday::Doshanbeh).map(|tm| tm.tm_mday).collect();
+    assert_eq!(weeks, vec![16, 23]);
+    let weeks: Vec<_> = iter::weeks_starting_on(&from, &to, Weekday::Seshanbeh).map(|tm| tm.tm_mday).collect();
+    assert_eq!(weeks, vec![17, 24]);
+}
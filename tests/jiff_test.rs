@@ -0,0 +1,40 @@
+#![cfg(feature = "jiff")]
+
+extern crate jiff;
+extern crate ptime;
+
+use jiff::civil::{date, Date, DateTime};
+use jiff::tz::{self, TimeZone};
+use jiff::Zoned;
+use std::convert::TryFrom;
+
+#[test]
+fn from_jiff() {
+    let date = date(2016, 3, 21);
+    assert_eq!(ptime::Tm::try_from(date).unwrap(), ptime::from_persian_date(1395, 0, 2).unwrap());
+
+    let date_time = date.at(10, 30, 5, 500);
+    assert_eq!(ptime::Tm::try_from(date_time).unwrap(), ptime::from_persian_components(1395, 0, 2, 10, 30, 5, 500).unwrap());
+
+    let zoned = date_time.to_zoned(TimeZone::fixed(tz::offset(4))).unwrap();
+    let p_tm = ptime::Tm::try_from(zoned).unwrap();
+    assert_eq!((p_tm.tm_mday, p_tm.tm_hour, p_tm.tm_min, p_tm.tm_utcoff), (2, 10, 30, 14400));
+    assert_eq!(p_tm, ptime::at_offset(p_tm.to_timespec(), ptime::Offset::Fixed(14400)));
+
+    assert_eq!(ptime::Tm::try_from(date.with().year(-9999).build().unwrap()), Err(ptime::DateError::YearOutOfRange { year: -9999 }));
+}
+
+#[test]
+fn to_jiff() {
+    let p_tm = ptime::localize(&ptime::from_persian_components(1395, 0, 2, 10, 30, 5, 500).unwrap(), ptime::Offset::Fixed(12600));
+    let date_time = date(2016, 3, 21).at(10, 30, 5, 500);
+    assert_eq!(Date::try_from(p_tm).unwrap(), date_time.date());
+    assert_eq!(DateTime::try_from(p_tm).unwrap(), date_time);
+    let zoned = Zoned::try_from(p_tm).unwrap();
+    assert_eq!(zoned.datetime(), date_time);
+    assert_eq!(zoned.offset().seconds(), 12600);
+    assert_eq!(zoned.timestamp().as_second(), p_tm.to_timespec().sec);
+
+    // 1 Farvardin 9999 is in the Gregorian year 10620, beyond the range of jiff
+    assert!(Date::try_from(ptime::from_persian_date(9999, 0, 1).unwrap()).is_err());
+}
@@ -0,0 +1,39 @@
+#![cfg(all(unix, feature = "std"))]
+
+extern crate ptime;
+
+use ptime::{Offset, Timespec};
+use std::env;
+
+fn local_offset(tz: &str, sec: i64) -> (i32, bool) {
+    env::set_var("TZ", tz);
+    let p_tm = ptime::at(Timespec::new(sec, 0));
+    (p_tm.tm_utcoff, p_tm.tm_isdst > 0)
+}
+
+// `TZ` is process-wide, so it is tested by a single test in its own binary
+#[test]
+fn local_offset_of_tz() {
+    // The TZif files of the tz database, including the POSIX TZ string of their footer
+    assert_eq!(local_offset("Asia/Tehran", 1458540000), (16200, true));
+    assert_eq!(local_offset("Asia/Tehran", 1700000000), (12600, false));
+    assert_eq!(local_offset("America/New_York", 4102444800), (-18000, false));
+    assert_eq!(local_offset("America/New_York", 4118083200), (-14400, true));
+    for &sec in [0, 227820600, 1458540000, 1663963200, 4118083200].iter() {
+        assert_eq!(local_offset(":/usr/share/zoneinfo/Asia/Tehran", sec).0, Offset::Tehran.utc_offset_at(Timespec::new(sec, 0)));
+    }
+
+    // The POSIX TZ strings
+    assert_eq!(local_offset("IRST-3:30", 1458540000), (12600, false));
+    assert_eq!(local_offset("<-03>3", 1458540000), (-10800, false));
+    assert_eq!(local_offset("EST5EDT,M3.2.0,M11.1.0", 1710054000 - 1), (-18000, false));
+    assert_eq!(local_offset("EST5EDT,M3.2.0,M11.1.0", 1710054000), (-14400, true));
+    assert_eq!(local_offset("EST5EDT,M3.2.0,M11.1.0", 1730613600 - 1), (-14400, true));
+    assert_eq!(local_offset("EST5EDT,M3.2.0,M11.1.0", 1730613600), (-18000, false));
+    assert_eq!(local_offset("AEST-10AEDT,M10.1.0,M4.1.0/3", 1577923200), (39600, true));
+    assert_eq!(local_offset("AEST-10AEDT,M10.1.0,M4.1.0/3", 1593561600), (36000, false));
+
+    // The empty and unknown timezones are UTC
+    assert_eq!(local_offset("", 1458540000), (0, false));
+    assert_eq!(local_offset("Unknown/Zone", 1458540000), (0, false));
+}
@@ -0,0 +1,40 @@
+extern crate ptime;
+
+use ptime::locale;
+
+#[test]
+fn dari_month_names() {
+    let months = ["حمل", "ثور", "جوزا", "سرطان", "اسد", "سنبله", "میزان", "عقرب", "قوس", "جدی", "دلو", "حوت"];
+    for (month, name) in months.iter().enumerate() {
+        let p_tm = ptime::from_persian_date(1395, month as i32, 1).unwrap();
+        assert_eq!(p_tm.to_locale_string("d MMM yyyy", &locale::Dari), format!("1 {} 1395", name));
+    }
+}
+
+#[test]
+fn persian_is_default() {
+    let p_tm = ptime::from_persian_date(1395, 0, 2).unwrap();
+    assert_eq!(p_tm.to_string("E d MMM"), p_tm.to_locale_string("E d MMM", &locale::Persian));
+    assert_eq!(p_tm.to_string("E d MMM"), "دوشنبه 2 فروردین");
+}
+
+#[test]
+fn kurdish_names() {
+    let p_tm = ptime::from_persian_components(1395, 0, 2, 14, 0, 0, 0).unwrap();
+    assert_eq!(p_tm.to_locale_string("E d MMM a", &locale::Kurdish), "دووشەممە 2 خاکەلێوە د.ن");
+    assert_eq!(p_tm.to_locale_string("e A", &locale::Kurdish), "د دوای نیوەڕۆ");
+}
+
+#[test]
+fn tajik_names() {
+    let p_tm = ptime::from_persian_components(1395, 6, 1, 9, 0, 0, 0).unwrap();
+    assert_eq!(p_tm.to_locale_string("E d MMM yyyy", &locale::Tajik), "Панҷшанбе 1 Мизон 1395");
+    assert_eq!(p_tm.to_locale_string("e A", &locale::Tajik), "Пшб пеш аз нисфирӯзӣ");
+}
+
+#[test]
+fn transliterated_names() {
+    let p_tm = ptime::from_persian_components(1403, 0, 1, 15, 30, 0, 0).unwrap();
+    assert_eq!(p_tm.to_locale_string("E, d MMM yyyy g, HH:mm a", &locale::Transliterated), "Chaharshanbeh, 1 Farvardin 1403 AP, 15:30 PM");
+    assert_eq!(p_tm.to_locale_string("G", &locale::Transliterated), "Anno Persico");
+}
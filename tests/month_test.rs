@@ -0,0 +1,39 @@
+extern crate ptime;
+
+use ptime::{InvalidMonth, Month};
+use std::convert::TryFrom;
+
+#[test]
+fn month() {
+    assert_eq!(Month::Farvardin.number(), 1);
+    assert_eq!(Month::Esfand.index(), 11);
+    assert_eq!(Month::Mehr.name(), "مهر");
+    assert_eq!(Month::Esfand.to_string(), "اسفند");
+    assert_eq!((Month::Farvardin.days(1395), Month::Mehr.days(1395)), (31, 30));
+    assert_eq!((Month::Esfand.days(1395), Month::Esfand.days(1396)), (30, 29));
+    assert_eq!((Month::Esfand.succ(), Month::Farvardin.pred()), (Month::Farvardin, Month::Esfand));
+    assert_eq!(Month::Tir.succ(), Month::Mordad);
+}
+
+#[test]
+fn month_conversion() {
+    assert_eq!(Month::try_from(1), Ok(Month::Farvardin));
+    assert_eq!(Month::try_from(12), Ok(Month::Esfand));
+    assert_eq!(Month::try_from(0), Err(InvalidMonth(0)));
+    assert_eq!(Month::try_from(13), Err(InvalidMonth(13)));
+    assert_eq!(Month::from_index(0), Some(Month::Farvardin));
+    assert_eq!(Month::from_index(12), None);
+
+    let p_tm = ptime::from_persian_date(1395, 6, 1).unwrap();
+    assert_eq!(p_tm.month(), Month::Mehr);
+    assert_eq!(p_tm.checked_month(), Some(Month::Mehr));
+    let invalid = ptime::Tm { tm_mon: 12, ..p_tm };
+    assert_eq!((invalid.checked_month(), invalid.month_number()), (None, 13));
+    assert_eq!(ptime::Datelike::month_number(&invalid), 13);
+    assert_eq!(ptime::PersianDate::from_month(1395, Month::Mehr, 1), Some(p_tm.date()));
+    assert!(ptime::PersianDate::from_month(1396, Month::Esfand, 30).is_none());
+    assert_eq!(ptime::from_persian_month(1395, Month::Mehr, 1), Some(p_tm));
+    assert!(ptime::from_persian_month(1396, Month::Esfand, 30).is_none());
+    assert_eq!(ptime::try_from_persian_month(1395, Month::Mehr, 31), Err(ptime::DateError::DayOutOfRange { day: 31, max: 30 }));
+    assert_eq!(ptime::TmBuilder::new().year(1395).persian_month(Month::Mehr).build(), Ok(p_tm));
+}
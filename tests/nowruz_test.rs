@@ -0,0 +1,42 @@
+extern crate ptime;
+
+// Persian year, Gregorian year, month since January and day of month of Nowruz
+static NOWRUZ: [[i32; 4]; 11] = [
+    [1395, 2016, 2, 20],
+    [1396, 2017, 2, 21],
+    [1397, 2018, 2, 21],
+    [1398, 2019, 2, 21],
+    [1399, 2020, 2, 20],
+    [1400, 2021, 2, 21],
+    [1401, 2022, 2, 21],
+    [1402, 2023, 2, 21],
+    [1403, 2024, 2, 20],
+    [1404, 2025, 2, 21],
+    [1405, 2026, 2, 21],
+];
+
+#[test]
+fn nowruz_to_gregorian() {
+    for n in NOWRUZ.iter() {
+        let p_tm = ptime::from_persian_date(n[0], 0, 1).unwrap();
+        assert_eq!(p_tm.to_gregorian_date(), (n[1], n[2], n[3]));
+    }
+}
+
+#[test]
+fn nowruz_from_gregorian() {
+    for n in NOWRUZ.iter() {
+        let p_tm = ptime::from_gregorian_date(n[1], n[2], n[3]).unwrap();
+        assert_eq!((p_tm.tm_year, p_tm.tm_mon, p_tm.tm_mday), (n[0], 0, 1));
+        let p_tm = ptime::from_gregorian_date(n[1], n[2], n[3] - 1).unwrap();
+        assert_eq!((p_tm.tm_year + 1, p_tm.tm_mon), (n[0], 11));
+    }
+}
+
+#[test]
+fn leap_years_match_calendar() {
+    for year in 1..3000 {
+        let days = ptime::from_persian_date(year + 1, 0, 1).unwrap().to_jdn() - ptime::from_persian_date(year, 0, 1).unwrap().to_jdn();
+        assert_eq!(days, if ptime::from_persian_date(year, 11, 30).is_some() { 366 } else { 365 });
+    }
+}
@@ -0,0 +1,78 @@
+extern crate ptime;
+
+use ptime::occasions;
+
+#[test]
+fn cultural_occasions_in_year() {
+    let occasions = occasions::cultural_occasions_in_year(1403);
+    assert_eq!(occasions.len(), 13);
+    assert_eq!(occasions[0].date(), ptime::PersianDate::new(1403, 0, 13).unwrap());
+    assert_eq!(occasions[0].name(), "سیزده‌به‌در");
+    assert!(occasions.windows(2).all(|pair| pair[0].date() < pair[1].date()));
+    assert!(occasions.iter().any(|occasion| occasion.name() == "شب یلدا" && occasion.date() == ptime::PersianDate::new(1403, 8, 30).unwrap()));
+    assert!(occasions::cultural_occasions_in_year(ptime::MAX_YEAR + 1).is_empty());
+}
+
+#[test]
+fn charshanbe_suri() {
+    let suri = |year| occasions::cultural_occasions_in_year(year).pop().unwrap();
+
+    // 1403 ends on Panjshanbeh 30 Esfand
+    let last = suri(1403);
+    assert_eq!((last.date(), last.name()), (ptime::PersianDate::new(1403, 11, 28).unwrap(), "چهارشنبه‌سوری"));
+    assert_eq!(last.date().weekday(), ptime::Weekday::Seshanbeh);
+
+    // 1402 and 1396 end on Seshanbeh 29 Esfand, i.e. the eve of Nowruz on Chaharshanbeh
+    let last = suri(1402);
+    assert_eq!(last.date(), ptime::PersianDate::new(1402, 11, 22).unwrap());
+    assert_eq!(last.date().to_tm().to_gregorian_date(), (2024, 2, 12));
+    let last = suri(1396);
+    assert_eq!(last.date(), ptime::PersianDate::new(1396, 11, 22).unwrap());
+    assert_eq!(last.date().to_tm().to_gregorian_date(), (2018, 2, 13));
+    assert!(occasions::cultural_occasions_on(&ptime::from_persian_date(1396, 11, 29).unwrap()).is_empty());
+}
+
+#[test]
+fn cultural_occasions_on() {
+    let tirgan = occasions::cultural_occasions_on(&ptime::from_persian_components(1403, 3, 13, 20, 0, 0, 0).unwrap());
+    assert_eq!(tirgan.iter().map(|occasion| occasion.name()).collect::<Vec<_>>(), ["جشن تیرگان"]);
+    assert_eq!(occasions::cultural_occasions_on(&ptime::from_persian_date(1403, 11, 28).unwrap()).len(), 1);
+    assert!(occasions::cultural_occasions_on(&ptime::from_persian_date(1403, 11, 29).unwrap()).is_empty());
+    assert!(occasions::cultural_occasions_on(&ptime::from_persian_date(1403, 0, 1).unwrap()).is_empty());
+}
+
+#[test]
+fn occasions_on() {
+    use ptime::occasions::Category;
+
+    let sizdah = occasions::occasions_on(&ptime::from_persian_date(1403, 0, 13).unwrap());
+    let summary: Vec<_> = sizdah.iter().map(|occasion| (occasion.name(), occasion.category(), occasion.is_holiday())).collect();
+    assert_eq!(summary, [("روز طبیعت", Category::National, true), ("سیزده‌به‌در", Category::Ancient, false)]);
+
+    let fitr = occasions::occasions_on(&ptime::from_persian_date(1403, 0, 22).unwrap());
+    assert_eq!(fitr[0].category(), Category::Religious);
+
+    // January 1, 2025 is 12 Dey 1403
+    let new_year = occasions::occasions_on(&ptime::from_persian_date(1403, 9, 12).unwrap());
+    assert_eq!(new_year.len(), 1);
+    assert_eq!((new_year[0].name(), new_year[0].category()), ("آغاز سال نو میلادی", Category::World));
+    assert!(occasions::occasions_on(&ptime::from_persian_date(1403, 0, 5).unwrap()).is_empty());
+}
+
+#[test]
+fn registered_occasions() {
+    use ptime::occasions::{Category, Occasion, Occasions};
+
+    let opening = Occasion::new(ptime::PersianDate::new(1403, 6, 5).unwrap(), "افتتاح دفتر", Category::National);
+    let registered = Occasions::new().with_yearly(8, 30, "جشن شرکت", Category::Ancient).with_occasion(opening);
+    let yalda = registered.occasions_on(&ptime::from_persian_date(1402, 8, 30).unwrap());
+    assert_eq!(yalda.iter().map(|occasion| occasion.name()).collect::<Vec<_>>(), ["شب یلدا", "جشن شرکت"]);
+    assert_eq!(registered.occasions_on(&ptime::from_persian_date(1403, 6, 5).unwrap()), [opening]);
+    assert!(registered.occasions_on(&ptime::from_persian_date(1404, 6, 5).unwrap()).is_empty());
+
+    // Tasua is a day earlier by the observed month
+    let starts = ptime::holidays::HijriMonthStarts::new().with_month_start(1446, 1, ptime::PersianDate::new(1403, 3, 17).unwrap());
+    let tasua = Occasions::observed(starts).occasions_on(&ptime::from_persian_date(1403, 3, 25).unwrap());
+    assert!(tasua[0].is_holiday());
+    assert!(occasions::occasions_on(&ptime::from_persian_date(1403, 3, 25).unwrap()).is_empty());
+}
@@ -1,5 +1,6 @@
+#![allow(clippy::bool_assert_comparison)]
+
 extern crate ptime;
-extern crate time;
 
 #[test]
 fn leap_years() {
@@ -25,14 +26,332 @@ fn non_leap_years() {
 fn operators() {
     let p_tm1 = ptime::from_persian_date(1395, 0, 1).unwrap();
     let p_tm2 = ptime::from_gregorian_date(2016, 2, 21).unwrap();
-    assert_eq!(p_tm2 - p_tm1, time::Duration::seconds(24 * 3600));
+    assert_eq!(p_tm2 - p_tm1, ptime::Duration::seconds(24 * 3600));
     assert_eq!(p_tm2 > p_tm1, true);
     assert_eq!(p_tm2 < p_tm1, false);
     assert_eq!(p_tm2 == p_tm1, false);
 }
 
+#[test]
+fn assign_operators() {
+    let mut p_tm = ptime::from_persian_components(1395, 11, 30, 23, 0, 0, 0).unwrap();
+    p_tm.tm_utcoff = 12600;
+    p_tm += ptime::Duration::hours(2);
+    assert_eq!((p_tm.tm_year, p_tm.tm_mon, p_tm.tm_mday, p_tm.tm_hour), (1396, 0, 1, 1));
+    assert_eq!((p_tm.tm_wday, p_tm.tm_yday, p_tm.tm_utcoff), (ptime::from_persian_date(1396, 0, 1).unwrap().tm_wday, 0, 12600));
+    p_tm -= ptime::Duration::minutes(90);
+    assert_eq!((p_tm.tm_mday, p_tm.tm_hour, p_tm.tm_min, p_tm.tm_utcoff), (30, 23, 30, 12600));
+
+    let mut p_tm = ptime::from_persian_date(1395, 0, 1).unwrap();
+    p_tm += ptime::Duration::days(1);
+    assert_eq!(p_tm, ptime::from_persian_date(1395, 0, 2).unwrap());
+}
+
+#[test]
+fn std_duration() {
+    let p_tm = ptime::from_persian_date(1395, 0, 1).unwrap();
+    let timeout = std::time::Duration::from_millis(90_500);
+    assert_eq!(ptime::Duration::from_std(timeout), Some(ptime::Duration::milliseconds(90_500)));
+    assert_eq!(ptime::Duration::from_std(std::time::Duration::MAX), None);
+    assert_eq!(ptime::Duration::milliseconds(90_500).to_std(), Some(timeout));
+    assert_eq!(ptime::Duration::nanoseconds(-1).to_std(), None);
+    assert_eq!(p_tm + timeout, p_tm + ptime::Duration::milliseconds(90_500));
+    assert_eq!(p_tm - timeout, p_tm - ptime::Duration::milliseconds(90_500));
+    assert_eq!(p_tm.to_timespec() + timeout, (p_tm + timeout).to_timespec());
+
+    let mut p_tm = p_tm.with_offset(12600);
+    p_tm += std::time::Duration::from_secs(86400);
+    assert_eq!((p_tm.tm_mday, p_tm.tm_hour, p_tm.tm_min, p_tm.tm_utcoff), (2, 3, 30, 12600));
+    p_tm -= std::time::Duration::from_secs(3600);
+    assert_eq!((p_tm.tm_mday, p_tm.tm_hour, p_tm.tm_utcoff), (2, 2, 12600));
+}
+
 #[test]
 fn format() {
     let p_tm = ptime::from_gregorian_date(2016, 2, 21).unwrap();
     assert_eq!(format!("{}", p_tm), "1395-01-02T00:00:00.0");
 }
+
+#[test]
+fn format_era() {
+    let p_tm = ptime::from_persian_date(1403, 0, 1).unwrap();
+    assert_eq!(p_tm.to_string("d MMM yyyy G"), "1 فروردین 1403 هجری شمسی");
+    assert_eq!(p_tm.to_string("yyyy/MM/dd g"), "1403/01/01 ه.ش.");
+}
+
+#[test]
+fn format_to_slice() {
+    const FORMAT: &str = "yyyy/MM/dd E";
+    let p_tm = ptime::from_persian_date(1395, 0, 2).unwrap();
+    let mut buf = [0; ptime::max_formatted_len(FORMAT)];
+    let len = p_tm.format_to_slice(FORMAT, &mut buf).unwrap();
+    assert_eq!(&buf[..len], "1395/01/02 دوشنبه".as_bytes());
+    assert_eq!(p_tm.format_to_slice(FORMAT, &mut buf[..len - 1]), None);
+
+    let mut formatted = String::new();
+    p_tm.write_to(&mut formatted, "d MMM").unwrap();
+    assert_eq!(formatted, "2 فروردین");
+}
+
+#[test]
+fn max_formatted_len() {
+    const FORMAT: &str = "yyyy Y yy MMM MM M DD D dd d E e G g A a HH H kk k hh h KK K mm m ss s ns Z (تاریخ)";
+    let times = [
+        ptime::Tm::MIN,
+        ptime::Tm::MAX,
+        ptime::from_persian_components(-1, 5, 31, 23, 59, 59, 999_999_999).unwrap().with_offset(-45_000),
+        ptime::from_persian_components(1403, 1, 29, 11, 0, 0, 0).unwrap().with_offset(12600),
+    ];
+    for p_tm in times.iter() {
+        assert!(p_tm.to_string(FORMAT).len() <= ptime::max_formatted_len(FORMAT));
+    }
+    assert_eq!(ptime::max_formatted_len("yyyy/MM/dd"), 11);
+    assert_eq!(ptime::max_formatted_len("HH:mm:ss.ns"), 18);
+    assert_eq!(ptime::max_formatted_len(""), 0);
+}
+
+#[test]
+fn format_rfc3339() {
+    let mut p_tm = ptime::from_persian_components(1395, 0, 2, 10, 30, 5, 0).unwrap();
+    assert_eq!(p_tm.to_rfc3339(), "2016-03-21T10:30:05Z");
+    assert_eq!(p_tm.to_rfc3339_persian(), "1395-01-02T10:30:05Z");
+
+    p_tm.tm_utcoff = 12600;
+    p_tm.tm_nsec = 250_000_000;
+    assert_eq!(p_tm.to_rfc3339(), "2016-03-21T10:30:05.250+03:30");
+    p_tm.tm_utcoff = -18000;
+    assert_eq!(p_tm.to_rfc3339_persian(), "1395-01-02T10:30:05.250-05:00");
+}
+
+#[test]
+fn debug() {
+    let mut p_tm = ptime::from_persian_components(1395, 0, 2, 10, 30, 5, 121).unwrap();
+    p_tm.tm_utcoff = 12600;
+    assert_eq!(format!("{:?}", p_tm), "Tm(1395-01-02 Doshanbeh 2 Farvardin 10:30:05.000000121 +03:30)");
+
+    let pretty = format!("{:#?}", p_tm);
+    assert!(pretty.starts_with("Tm {\n    date: 1395-01-02 Doshanbeh 2 Farvardin"));
+    assert!(pretty.contains("tm_mon: 0,"));
+
+    p_tm.tm_mon = 12;
+    assert_eq!(format!("{:?}", p_tm), "Tm(1395-13-02 Doshanbeh 2 ? 10:30:05.000000121 +03:30)");
+}
+
+#[test]
+fn default() {
+    let p_tm = ptime::Tm::default();
+    assert_eq!((p_tm.tm_year, p_tm.tm_mon, p_tm.tm_mday), (1348, 9, 11));
+    assert_eq!((p_tm.tm_wday, p_tm.tm_yday), (5, 286));
+    assert_eq!(p_tm.to_timespec(), ptime::Timespec::new(0, 0));
+    assert_eq!(p_tm, ptime::at_utc(ptime::Timespec::new(0, 0)));
+}
+
+#[test]
+fn accessors() {
+    let p_tm = ptime::from_persian_components(1395, 11, 30, 10, 30, 5, 121).unwrap();
+    assert_eq!((p_tm.year(), p_tm.month(), p_tm.month_number(), p_tm.day()), (1395, ptime::Month::Esfand, 12, 30));
+    assert_eq!((p_tm.ordinal(), p_tm.weekday()), (366, ptime::Weekday::Doshanbeh));
+    assert_eq!((p_tm.hour(), p_tm.minute(), p_tm.second(), p_tm.nanosecond()), (10, 30, 5, 121));
+    assert_eq!(p_tm.utc_offset(), 0);
+}
+
+#[test]
+fn with_setters() {
+    use ptime::{Component, DateError, Month};
+
+    let mut p_tm = ptime::from_persian_components(1395, 0, 31, 10, 30, 5, 0).unwrap();
+    p_tm.tm_utcoff = 12600;
+
+    let changed = p_tm.with_day(2).unwrap();
+    assert_eq!((changed.tm_mday, changed.tm_wday, changed.tm_yday, changed.tm_utcoff), (2, 2, 1, 12600));
+    let changed = p_tm.with_month(Month::Khordad).unwrap();
+    assert_eq!((changed.tm_mon, changed.tm_yday, changed.tm_hour), (2, 92, 10));
+    let changed = p_tm.with_year(1396).unwrap().with_hour(23).unwrap().with_minute(0).unwrap();
+    assert_eq!((changed.tm_year, changed.tm_hour, changed.tm_min), (1396, 23, 0));
+    assert_eq!(p_tm.with_second(59).unwrap().with_nanosecond(1).unwrap().tm_nsec, 1);
+
+    assert_eq!(p_tm.with_month(Month::Mehr), Err(DateError::DayOutOfRange { day: 31, max: 30 }));
+    assert_eq!(p_tm.with_day(32), Err(DateError::DayOutOfRange { day: 32, max: 31 }));
+    assert_eq!(p_tm.with_hour(24), Err(DateError::InvalidTime { component: Component::Hour }));
+}
+
+#[test]
+fn days_in_month() {
+    assert_eq!(ptime::days_in_month(1395, 0), Some(31));
+    assert_eq!(ptime::days_in_month(1395, 6), Some(30));
+    assert_eq!(ptime::days_in_month(1395, 11), Some(30));
+    assert_eq!(ptime::days_in_month(1396, 11), Some(29));
+    assert_eq!(ptime::days_in_month(1395, 12), None);
+    assert_eq!((ptime::days_in_year(1395), ptime::days_in_year(1396)), (366, 365));
+
+    let p_tm = ptime::from_persian_date(1396, 11, 1).unwrap();
+    assert_eq!((p_tm.days_in_month(), p_tm.days_in_year()), (29, 365));
+    assert_eq!(p_tm.month().days(1396), p_tm.days_in_month());
+}
+
+#[test]
+fn calendar_arithmetic() {
+    use ptime::EndOfMonth;
+
+    let mut p_tm = ptime::from_persian_components(1395, 0, 31, 10, 30, 0, 0).unwrap();
+    p_tm.tm_utcoff = 12600;
+    assert_eq!(p_tm.add_days(1).date(), ptime::PersianDate::new(1395, 1, 1).unwrap());
+    assert_eq!(p_tm.add_days(-31).date(), ptime::PersianDate::new(1394, 11, 29).unwrap());
+    assert_eq!(p_tm.add_days(366).tm_yday, 30);
+
+    let clamped = p_tm.add_months(6, EndOfMonth::Clamp);
+    assert_eq!((clamped.tm_mon, clamped.tm_mday, clamped.tm_hour, clamped.tm_utcoff), (6, 30, 10, 12600));
+    let overflowed = p_tm.add_months(6, EndOfMonth::Overflow);
+    assert_eq!((overflowed.tm_mon, overflowed.tm_mday), (7, 1));
+    assert_eq!(p_tm.add_months(-1, EndOfMonth::Clamp).date(), ptime::PersianDate::new(1394, 11, 29).unwrap());
+    assert_eq!(p_tm.add_months(-1, EndOfMonth::Overflow).date(), ptime::PersianDate::new(1395, 0, 2).unwrap());
+
+    let esfand = ptime::from_persian_date(1395, 11, 30).unwrap();
+    assert_eq!(esfand.add_years(1, EndOfMonth::Clamp).date(), ptime::PersianDate::new(1396, 11, 29).unwrap());
+    assert_eq!(esfand.add_years(1, EndOfMonth::Overflow).date(), ptime::PersianDate::new(1397, 0, 1).unwrap());
+    assert_eq!(esfand.add_years(4, EndOfMonth::Clamp), ptime::from_persian_date(1399, 11, 30).unwrap());
+    assert_eq!(esfand.add_years(-1, EndOfMonth::Clamp).tm_wday, ptime::from_persian_date(1394, 11, 29).unwrap().tm_wday);
+}
+
+#[test]
+fn checked_arithmetic() {
+    use ptime::{EndOfMonth, Tm};
+
+    let p_tm = ptime::from_persian_date(1395, 0, 1).unwrap();
+    assert_eq!(p_tm.checked_add_days(1), Some(p_tm.add_days(1)));
+    assert_eq!(p_tm.checked_add_months(-13, EndOfMonth::Clamp), Some(p_tm.add_months(-13, EndOfMonth::Clamp)));
+    assert_eq!(p_tm.checked_add_years(5, EndOfMonth::Clamp), ptime::from_persian_date(1400, 0, 1));
+    assert_eq!(p_tm.checked_add_days(i32::MAX), None);
+    assert_eq!(p_tm.checked_add_months(i32::MIN, EndOfMonth::Clamp), None);
+    assert_eq!(p_tm.checked_add_years(i32::MAX, EndOfMonth::Clamp), None);
+    assert_eq!(Tm::MAX.checked_add_days(1), None);
+    assert_eq!(Tm::MIN.checked_add_days(-1), None);
+    assert_eq!(Tm::MAX.add_days(-29).checked_add_months(1, EndOfMonth::Overflow), None);
+
+    assert_eq!(p_tm.checked_add_signed(ptime::Duration::hours(25)), Some(p_tm + ptime::Duration::hours(25)));
+    assert_eq!(p_tm.checked_sub_signed(ptime::Duration::nanoseconds(1)), Some(p_tm - ptime::Duration::nanoseconds(1)));
+    assert_eq!(Tm::MAX.checked_add_signed(ptime::Duration::nanoseconds(1)), None);
+    assert_eq!(Tm::MIN.checked_sub_signed(ptime::Duration::nanoseconds(1)), None);
+    assert_eq!(p_tm.checked_add_signed(ptime::Duration::max_value()), None);
+
+    assert_eq!(p_tm.saturating_add_days(i32::MAX), Tm::MAX);
+    assert_eq!(p_tm.saturating_add_months(i32::MIN, EndOfMonth::Clamp), Tm::MIN);
+    assert_eq!(p_tm.saturating_add_years(1, EndOfMonth::Clamp), ptime::from_persian_date(1396, 0, 1).unwrap());
+    assert_eq!(p_tm.saturating_add_signed(ptime::Duration::min_value()), Tm::MIN);
+}
+
+#[test]
+fn years_months_days_since() {
+    use ptime::EndOfMonth;
+
+    let from = ptime::from_persian_date(1395, 0, 1).unwrap();
+    let to = ptime::from_persian_components(1396, 2, 4, 8, 0, 0, 0).unwrap();
+    assert_eq!(to.years_months_days_since(&from), (1, 2, 3));
+    assert_eq!(from.years_months_days_since(&to), (-1, -2, -3));
+    assert_eq!(from.years_months_days_since(&from), (0, 0, 0));
+
+    let from = ptime::from_persian_date(1395, 0, 31).unwrap();
+    assert_eq!(ptime::from_persian_date(1395, 6, 30).unwrap().years_months_days_since(&from), (0, 6, 0));
+    assert_eq!(ptime::from_persian_date(1395, 5, 30).unwrap().years_months_days_since(&from), (0, 4, 30));
+    assert_eq!(ptime::from_persian_date(1396, 0, 30).unwrap().years_months_days_since(&from), (0, 11, 30));
+
+    let from = ptime::from_persian_date(1390, 4, 17).unwrap();
+    for days in 0..2000 {
+        let to = from.add_days(days);
+        let (years, months, days) = to.years_months_days_since(&from);
+        assert!((0..12).contains(&months) && days >= 0);
+        assert_eq!(from.add_months(years * 12 + months, EndOfMonth::Clamp).add_days(days), to);
+    }
+}
+
+#[test]
+fn start_of() {
+    use ptime::Unit;
+
+    let mut p_tm = ptime::from_persian_components(1403, 7, 15, 10, 30, 45, 500).unwrap();
+    p_tm.tm_utcoff = 12600;
+    let start = |unit| {
+        let tm = p_tm.start_of(unit);
+        assert_eq!(tm.tm_utcoff, 12600);
+        (tm.tm_year, tm.tm_mon, tm.tm_mday, tm.tm_hour, tm.tm_min, tm.tm_sec, tm.tm_nsec)
+    };
+    assert_eq!(start(Unit::Second), (1403, 7, 15, 10, 30, 45, 0));
+    assert_eq!(start(Unit::Minute), (1403, 7, 15, 10, 30, 0, 0));
+    assert_eq!(start(Unit::Hour), (1403, 7, 15, 10, 0, 0, 0));
+    assert_eq!(start(Unit::Day), (1403, 7, 15, 0, 0, 0, 0));
+    assert_eq!(start(Unit::Month), (1403, 7, 1, 0, 0, 0, 0));
+    assert_eq!(start(Unit::Quarter), (1403, 6, 1, 0, 0, 0, 0));
+    assert_eq!(start(Unit::Year), (1403, 0, 1, 0, 0, 0, 0));
+
+    let week = p_tm.start_of(Unit::Week);
+    assert_eq!((week.tm_wday, week.tm_hour), (0, 0));
+    assert!(week <= p_tm && p_tm.to_jdn() - week.to_jdn() < 7);
+    assert_eq!(p_tm.start_of(Unit::Year).tm_yday, 0);
+    assert_eq!(p_tm.quarter(), 3);
+}
+
+#[test]
+fn end_of() {
+    use ptime::Unit;
+
+    let mut p_tm = ptime::from_persian_components(1403, 7, 15, 10, 30, 45, 500).unwrap();
+    p_tm.tm_utcoff = 12600;
+    let end = |tm: ptime::Tm| (tm.tm_year, tm.tm_mon, tm.tm_mday, tm.tm_hour, tm.tm_min, tm.tm_sec, tm.tm_nsec, tm.tm_utcoff);
+    assert_eq!(end(p_tm.end_of(Unit::Second)), (1403, 7, 15, 10, 30, 45, 999_999_999, 12600));
+    assert_eq!(end(p_tm.end_of(Unit::Hour)), (1403, 7, 15, 10, 59, 59, 999_999_999, 12600));
+    assert_eq!(end(p_tm.end_of(Unit::Day)), (1403, 7, 15, 23, 59, 59, 999_999_999, 12600));
+    assert_eq!(end(p_tm.end_of_month()), (1403, 7, 30, 23, 59, 59, 999_999_999, 12600));
+    assert_eq!(end(p_tm.end_of(Unit::Quarter)), (1403, 8, 30, 23, 59, 59, 999_999_999, 12600));
+    assert_eq!(end(p_tm.end_of_year()), (1403, 11, 30, 23, 59, 59, 999_999_999, 12600));
+    assert_eq!(p_tm.end_of(Unit::Week).tm_wday, 6);
+    assert_eq!(p_tm.end_of(Unit::Week).to_jdn() - p_tm.start_of(Unit::Week).to_jdn(), 6);
+
+    let esfand = ptime::from_persian_components(1404, 11, 5, 8, 0, 0, 0).unwrap();
+    assert_eq!(esfand.end_of_year().tm_mday, 29);
+    assert_eq!(esfand.end_of_year().tm_yday, 364);
+    assert_eq!(end(esfand.last_day_of_month()), (1404, 11, 29, 8, 0, 0, 0, 0));
+    assert_eq!(ptime::from_persian_date(1403, 0, 10).unwrap().last_day_of_month().tm_mday, 31);
+}
+
+#[test]
+fn round() {
+    use ptime::Unit;
+
+    let mut p_tm = ptime::from_persian_components(1403, 6, 16, 12, 0, 0, 0).unwrap();
+    p_tm.tm_utcoff = 12600;
+    let date = |tm: ptime::Tm| (tm.tm_year, tm.tm_mon, tm.tm_mday, tm.tm_hour, tm.tm_min, tm.tm_utcoff);
+    assert_eq!(date(p_tm.round(Unit::Month)), (1403, 7, 1, 0, 0, 12600));
+    assert_eq!(date(p_tm.with_day(15).unwrap().round(Unit::Month)), (1403, 6, 1, 0, 0, 12600));
+    assert_eq!(date(p_tm.round(Unit::Day)), (1403, 6, 17, 0, 0, 12600));
+    assert_eq!(date(p_tm.with_hour(11).unwrap().round(Unit::Day)), (1403, 6, 16, 0, 0, 12600));
+    assert_eq!(date(p_tm.round(Unit::Year)), (1404, 0, 1, 0, 0, 12600));
+    assert_eq!(date(p_tm.with_minute(29).unwrap().round(Unit::Hour)), (1403, 6, 16, 12, 0, 12600));
+    assert_eq!(date(p_tm.with_minute(30).unwrap().round(Unit::Hour)), (1403, 6, 16, 13, 0, 12600));
+
+    let p_tm = p_tm.with_hour(10).unwrap().with_minute(53).unwrap();
+    assert_eq!(date(p_tm.duration_trunc(ptime::Duration::minutes(15)).unwrap()), (1403, 6, 16, 10, 45, 12600));
+    assert_eq!(date(p_tm.duration_round(ptime::Duration::minutes(15)).unwrap()), (1403, 6, 16, 11, 0, 12600));
+    assert_eq!(date(p_tm.duration_round(ptime::Duration::days(1)).unwrap()), (1403, 6, 16, 0, 0, 12600));
+    assert_eq!(p_tm.duration_round(ptime::Duration::zero()), None);
+    assert_eq!(p_tm.duration_trunc(ptime::Duration::minutes(-1)), None);
+    assert_eq!(ptime::Tm::MAX.duration_round(ptime::Duration::seconds(1)), None);
+    assert_eq!(ptime::Tm::MIN.duration_trunc(ptime::Duration::days(7)), None);
+}
+
+#[test]
+fn nowruz() {
+    assert_eq!(ptime::nowruz_of(1404), ptime::from_gregorian_date(2025, 2, 21));
+    assert_eq!(ptime::nowruz_of(10000), None);
+
+    let mut p_tm = ptime::from_persian_components(1403, 11, 30, 18, 0, 0, 0).unwrap();
+    p_tm.tm_utcoff = 12600;
+    let nowruz = p_tm.next_nowruz();
+    assert_eq!((nowruz.tm_year, nowruz.tm_yday, nowruz.tm_hour, nowruz.tm_utcoff), (1404, 0, 0, 12600));
+    assert_eq!(p_tm.days_until_nowruz(), 1);
+    assert_eq!(p_tm.until_nowruz(), ptime::Duration::hours(6));
+
+    let p_tm = ptime::from_persian_date(1404, 0, 1).unwrap();
+    assert_eq!(p_tm.days_until_nowruz(), 365);
+    assert_eq!(p_tm.next_nowruz(), ptime::nowruz_of(1405).unwrap());
+    assert_eq!(p_tm.until_nowruz(), ptime::Duration::days(365));
+}
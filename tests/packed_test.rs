@@ -0,0 +1,49 @@
+extern crate ptime;
+
+use ptime::{PackedPersianDate, PackedPersianDateTime, PersianDate};
+use std::convert::TryFrom;
+
+#[test]
+fn packed_date() {
+    let date = PersianDate::new(1395, 0, 2).unwrap();
+    let packed = PackedPersianDate::new(date);
+    assert_eq!(packed.bits(), (1395 + 5000) << 9 | 1 << 5 | 2);
+    assert_eq!(packed.date(), date);
+    assert_eq!(PackedPersianDate::from_bits(packed.bits()), Some(packed));
+    assert_eq!(PackedPersianDate::from(ptime::from_persian_components(1395, 0, 2, 23, 0, 0, 0).unwrap()), packed);
+    assert_eq!(ptime::Tm::from(packed), ptime::from_persian_date(1395, 0, 2).unwrap());
+
+    // 30 Esfand 1396 does not exist, neither does the month 13
+    assert_eq!(PackedPersianDate::from_bits((1396 + 5000) << 9 | 12 << 5 | 30), None);
+    assert_eq!(PackedPersianDate::from_bits((1396 + 5000) << 9 | 13 << 5 | 1), None);
+    assert_eq!(PackedPersianDate::from_bits(1 << 23), None);
+
+    let dates = [(-5000, 0, 1), (-1, 11, 29), (0, 0, 1), (1395, 11, 30), (1396, 0, 1), (9999, 11, 29)];
+    let packed: Vec<_> = dates.iter().map(|&(y, m, d)| PackedPersianDate::new(PersianDate::new(y, m, d).unwrap())).collect();
+    assert!(packed.windows(2).all(|pair| pair[0].bits() < pair[1].bits()));
+}
+
+#[test]
+fn packed_date_time() {
+    let p_tm = ptime::localize(&ptime::from_persian_components(1395, 0, 2, 2, 30, 5, 123456789).unwrap(), ptime::Offset::Fixed(12600));
+    let packed = PackedPersianDateTime::new(&p_tm).unwrap();
+    assert_eq!(packed.date(), PersianDate::new(1395, 0, 1).unwrap());
+    assert_eq!(packed.time().to_string(), "23:00:05.123456000");
+    assert_eq!(ptime::Tm::from(packed), ptime::from_persian_components(1395, 0, 1, 23, 0, 5, 123456000).unwrap());
+    assert_eq!(PackedPersianDateTime::from_bits(packed.bits()), Some(packed));
+    assert_eq!(PackedPersianDateTime::try_from(p_tm), Ok(packed));
+
+    // The packed instants are ordered as the instants of time regardless of the offset
+    let clocks = [-200_000_000_000i64, -1, 0, 1458513000, 1458513001, 253_402_300_799];
+    let packed: Vec<_> = clocks.iter()
+        .map(|&sec| PackedPersianDateTime::new(&ptime::at_offset(ptime::Timespec::new(sec, 0), ptime::Offset::Fixed(-3600))).unwrap())
+        .collect();
+    assert!(packed.windows(2).all(|pair| pair[0].bits() < pair[1].bits()));
+    let unpacked: Vec<_> = packed.iter().map(|packed| packed.to_tm().to_timespec().sec).collect();
+    assert_eq!(unpacked, clocks);
+
+    assert_eq!(PackedPersianDateTime::from_bits(-1), None);
+    assert_eq!(PackedPersianDateTime::from_bits(packed[0].bits() | 86_400_000_000), None);
+    let before_min = ptime::localize(&ptime::Tm::MIN, ptime::Offset::Fixed(3600));
+    assert_eq!(PackedPersianDateTime::new(&before_min), Err(ptime::DateError::YearOutOfRange { year: -5001 }));
+}
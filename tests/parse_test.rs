@@ -0,0 +1,186 @@
+extern crate ptime;
+
+#[test]
+fn rfc2822() {
+    let p_tm = ptime::from_rfc2822("Mon, 21 Mar 2016 10:30:05 +0330").unwrap();
+    assert_eq!((p_tm.tm_year, p_tm.tm_mon, p_tm.tm_mday), (1395, 0, 2));
+    assert_eq!((p_tm.tm_hour, p_tm.tm_min, p_tm.tm_sec), (10, 30, 5));
+    assert_eq!(p_tm.tm_utcoff, 12600);
+    assert_eq!(p_tm.to_rfc2822(), "Mon, 21 Mar 2016 10:30:05 +0330");
+
+    let p_tm = ptime::from_rfc2822("1 Jan 2017 23:59 GMT").unwrap();
+    assert_eq!(p_tm.to_rfc2822(), "Sun, 01 Jan 2017 23:59:00 +0000");
+    assert_eq!(ptime::from_rfc2822("21 Mar 2016 10:30:05 EST").unwrap().tm_utcoff, -18000);
+}
+
+#[test]
+fn invalid_rfc2822() {
+    assert!(ptime::from_rfc2822("Tue, 21 Mar 2016 10:30:05 +0330").is_err());
+    assert!(ptime::from_rfc2822("Mon, 21 Foo 2016 10:30:05 +0330").is_err());
+    assert!(ptime::from_rfc2822("Mon, 21 Mar 2016 25:30:05 +0330").is_err());
+    assert!(ptime::from_rfc2822("Mon, 21 Mar 2016 10:30:05").is_err());
+    assert!(ptime::from_rfc2822("Mon, 21 Mar 2016 10:30:05 +0330 extra").is_err());
+}
+
+#[test]
+fn ordinal() {
+    let p_tm = ptime::from_persian_date(1395, 3, 30).unwrap();
+    assert_eq!(p_tm.to_ordinal_string(), "1395-123");
+    assert_eq!(ptime::from_ordinal_string("1395-123"), Ok(p_tm));
+    assert_eq!(ptime::from_ordinal_string("1395-366").ok(), ptime::from_persian_date(1395, 11, 30));
+    assert!(ptime::from_ordinal_string("1396-366").is_err());
+    assert!(ptime::from_ordinal_string("1395-000").is_err());
+}
+
+#[test]
+fn week_date() {
+    assert_eq!(ptime::from_persian_date(1395, 0, 1).unwrap().to_week_date_string(), "1395-W01-2");
+    assert_eq!(ptime::from_persian_date(1395, 3, 30).unwrap().to_week_date_string(), "1395-W18-5");
+    // The week of 30 Esfand 1395 contains 1 Farvardin 1396 as its Seshanbeh
+    let p_tm = ptime::from_persian_date(1395, 11, 30).unwrap();
+    assert_eq!(p_tm.to_week_date_string(), "1396-W01-3");
+    assert_eq!(ptime::from_week_date_string("1396-W01-3"), Ok(p_tm));
+    // 1 Farvardin 1397 is Chaharshanbeh, so it belongs to the last week of 1396
+    let p_tm = ptime::from_persian_date(1397, 0, 1).unwrap();
+    assert_eq!(p_tm.to_week_date_string(), "1396-W53-5");
+    assert_eq!(ptime::from_week_date_string("1396-W53-5"), Ok(p_tm));
+    assert!(ptime::from_week_date_string("1395-W53-1").is_err());
+    assert!(ptime::from_week_date_string("1395-W18-8").is_err());
+}
+
+#[test]
+fn lenient() {
+    let p_tm = ptime::from_persian_date(1395, 0, 2);
+    for s in ["1395/1/2", "1395-01-02", "1395.1.02", "۱۳۹۵٫۱٫۲", "١٣٩٥/١/٢", "  1395/01/02 ", "2/1/1395", "02-01-1395"].iter() {
+        assert_eq!(ptime::parse_lenient(s).ok(), p_tm, "{}", s);
+    }
+    assert_eq!(ptime::parse_lenient("1395/1/2 10:30").ok(), ptime::from_persian_components(1395, 0, 2, 10, 30, 0, 0));
+    assert_eq!(ptime::parse_lenient("۱۳۹۵/۱/۲ ۱۰:۳۰:۰۵").ok(), ptime::from_persian_components(1395, 0, 2, 10, 30, 5, 0));
+    assert!(ptime::parse_lenient("1395/13/2").is_err());
+    assert!(ptime::parse_lenient("1395/1").is_err());
+    assert!(ptime::parse_lenient("1395/1/2 extra").is_err());
+}
+
+#[test]
+fn relative() {
+    use ptime::{Component, ParseError};
+
+    let anchor = ptime::from_persian_components(1395, 0, 2, 10, 30, 0, 0).unwrap();
+    let at = |year, month, day| ptime::from_persian_components(year, month, day, 10, 30, 0, 0);
+    assert_eq!(ptime::parse_relative("امروز", &anchor), Ok(anchor));
+    assert_eq!(ptime::parse_relative("دیروز", &anchor).ok(), at(1395, 0, 1));
+    assert_eq!(ptime::parse_relative("فردا", &anchor).ok(), at(1395, 0, 3));
+    assert_eq!(ptime::parse_relative("پس‌فردا", &anchor).ok(), at(1395, 0, 4));
+    assert_eq!(ptime::parse_relative("پس فردا", &anchor).ok(), at(1395, 0, 4));
+    assert_eq!(ptime::parse_relative("سه روز پیش", &anchor).ok(), at(1394, 11, 28));
+    assert_eq!(ptime::parse_relative("۱۰ روز دیگر", &anchor).ok(), at(1395, 0, 12));
+    assert_eq!(ptime::parse_relative("هفته آینده", &anchor).ok(), at(1395, 0, 9));
+    assert_eq!(ptime::parse_relative("هفته‌ی گذشته", &anchor).ok(), at(1394, 11, 24));
+    assert_eq!(ptime::parse_relative("دو ماه بعد", &anchor).ok(), at(1395, 2, 2));
+    assert_eq!(ptime::parse_relative("سال پیش", &anchor).ok(), at(1394, 0, 2));
+    assert!(ptime::parse_relative("سه روز", &anchor).is_err());
+    assert!(ptime::parse_relative("دیروز صبح", &anchor).is_err());
+    assert_eq!(ptime::parse_relative("999999999 سال بعد", &anchor),
+               Err(ParseError::OutOfRange { position: 0, component: Component::Year }));
+    assert_eq!(ptime::parse_relative("300000000 هفته بعد", &anchor),
+               Err(ParseError::OutOfRange { position: 0, component: Component::Week }));
+    assert_eq!(ptime::parse_relative("999999999 روز پیش", &anchor),
+               Err(ParseError::OutOfRange { position: 0, component: Component::Day }));
+    assert_eq!(ptime::parse_relative("فردا", &ptime::Tm::MAX),
+               Err(ParseError::OutOfRange { position: 0, component: Component::Day }));
+}
+
+#[test]
+fn iso8601() {
+    assert_eq!(ptime::from_iso8601("2016-03-21").ok(), ptime::from_persian_date(1395, 0, 2));
+    assert_eq!(ptime::from_iso8601("2016-03-21T10:30:05Z").ok(), ptime::from_persian_components(1395, 0, 2, 10, 30, 5, 0));
+    assert_eq!(ptime::from_iso8601("2016-03-21 10:30").ok(), ptime::from_persian_components(1395, 0, 2, 10, 30, 0, 0));
+
+    let p_tm = ptime::from_iso8601("2016-03-21T10:30:05.25+03:30").unwrap();
+    assert_eq!((p_tm.tm_year, p_tm.tm_mon, p_tm.tm_mday, p_tm.tm_hour), (1395, 0, 2, 10));
+    assert_eq!((p_tm.tm_nsec, p_tm.tm_utcoff), (250_000_000, 12600));
+    assert_eq!(p_tm.to_rfc3339(), "2016-03-21T10:30:05.250+03:30");
+    assert_eq!(ptime::from_iso8601("2016-03-21T10:30:05.123456789123-0500").unwrap().tm_nsec, 123456789);
+
+    assert!(ptime::from_iso8601("2016-02-30").is_err());
+    assert!(ptime::from_iso8601("2016-03-21T10:30:05.").is_err());
+    assert!(ptime::from_iso8601("2016-03-21T10:30:05+3").is_err());
+}
+
+#[test]
+fn offset() {
+    let p_tm = ptime::from_rfc3339_persian("1395-01-02T10:30:05.250+03:30").unwrap();
+    assert_eq!((p_tm.tm_year, p_tm.tm_mon, p_tm.tm_mday, p_tm.tm_hour, p_tm.tm_nsec, p_tm.tm_utcoff), (1395, 0, 2, 10, 250_000_000, 12600));
+    assert_eq!(ptime::from_rfc3339_persian(&p_tm.to_rfc3339_persian()), Ok(p_tm));
+    assert_eq!(p_tm.to_utc().tm_hour, 7);
+    assert_eq!(ptime::from_rfc3339_persian("1395-01-02T10:30:05Z").unwrap().tm_utcoff, 0);
+    assert!(ptime::from_rfc3339_persian("1396-12-30T10:30:05Z").is_err());
+
+    let p_tm = ptime::parse_lenient("1403/1/1 12:30 -0330").unwrap();
+    assert_eq!((p_tm.tm_hour, p_tm.tm_min, p_tm.tm_utcoff), (12, 30, -12600));
+    assert_eq!(ptime::parse_lenient("1403/1/1 12:30Z").unwrap().tm_utcoff, 0);
+
+    let p_tm = ptime::strptime("1403/01/01 12:30 +03:30", "yyyy/MM/dd HH:mm Z").unwrap();
+    assert_eq!((p_tm.tm_hour, p_tm.tm_utcoff), (12, 12600));
+    assert_eq!(p_tm.to_string("HH:mmZ"), "12:30+03:30");
+    assert_eq!(ptime::strptime("12:30+0330 1403", "HH:mmZ yyyy").unwrap().tm_utcoff, 12600);
+    assert!(ptime::strptime("1403/01/01 12:30 +3", "yyyy/MM/dd HH:mm Z").is_err());
+}
+
+#[test]
+fn strptime() {
+    let p_tm = ptime::from_persian_date(1395, 0, 2);
+    assert_eq!(ptime::strptime("2 فروردین 1395", "d MMM yyyy").ok(), p_tm);
+    assert_eq!(ptime::strptime("۲ فروردین ۱۳۹۵", "d MMM yyyy").ok(), p_tm);
+    assert_eq!(ptime::strptime("دوشنبه ۲ فروردین ۱۳۹۵", "E d MMM yyyy").ok(), p_tm);
+    assert!(ptime::strptime("سه‌شنبه ۲ فروردین ۱۳۹۵", "E d MMM yyyy").is_err());
+    assert!(ptime::strptime("2 فروردین", "d MMM").is_err());
+
+    let p_tm = ptime::from_persian_date(1395, 0, 3);
+    assert_eq!(ptime::strptime("سه‌شنبه 3 فروردین 1395", "E d MMM yyyy").ok(), p_tm);
+    assert_eq!(ptime::strptime("سه شنبه 3 فروردین 1395", "E d MMM yyyy").ok(), p_tm);
+    assert_eq!(ptime::strptime("سهشنبه 3 فروردين 1395", "E d MMM yyyy").ok(), p_tm);
+
+    let p_tm = ptime::from_persian_components(1395, 0, 2, 14, 5, 9, 121).unwrap();
+    let format = "yyyy/MM/dd KK:mm:ss.ns a";
+    assert_eq!(ptime::strptime(&p_tm.to_string(format), format), Ok(p_tm));
+    assert_eq!(ptime::strptime("1395-01-02T14:05:09.121", "yyyy-MM-ddTHH:mm:ss.ns"), Ok(p_tm));
+}
+
+#[test]
+fn strptime_locale() {
+    let p_tm = ptime::from_persian_date(1395, 6, 1);
+    assert_eq!(ptime::strptime_locale("1 mehr 1395 AP", "d MMM yyyy g", &ptime::locale::Transliterated).ok(), p_tm);
+    assert_eq!(ptime::strptime_locale("1 میزان 1395", "d MMM yyyy", &ptime::locale::Dari).ok(), p_tm);
+}
+
+#[test]
+fn two_digit_year() {
+    assert_eq!(ptime::strptime("95/01/02", "yy/MM/dd").ok(), ptime::from_persian_date(1395, 0, 2));
+    assert_eq!(ptime::strptime("03/01/02", "yy/MM/dd").ok(), ptime::from_persian_date(1403, 0, 2));
+    assert_eq!(ptime::strptime("30/01/02", "yy/MM/dd").ok(), ptime::from_persian_date(1430, 0, 2));
+    assert_eq!(ptime::strptime("31/01/02", "yy/MM/dd").ok(), ptime::from_persian_date(1331, 0, 2));
+
+    let locale = ptime::locale::Persian;
+    assert_eq!(ptime::strptime_pivot("95/01/02", "yy/MM/dd", &locale, 1250).ok(), ptime::from_persian_date(1295, 0, 2));
+    assert_eq!(ptime::strptime_pivot("03/01/02", "yy/MM/dd", &locale, 1350).ok(), ptime::from_persian_date(1403, 0, 2));
+    assert_eq!(ptime::parse_lenient("95/1/2").ok(), ptime::from_persian_date(1395, 0, 2));
+}
+
+#[test]
+fn parse_error() {
+    use ptime::{Component, ParseError};
+
+    assert_eq!(ptime::parse_lenient("1395/13/2"), Err(ParseError::OutOfRange { position: 5, component: Component::Month }));
+    assert_eq!(ptime::parse_lenient("1396/12/30"), Err(ParseError::OutOfRange { position: 8, component: Component::Day }));
+    assert_eq!(ptime::parse_lenient("1395/1"), Err(ParseError::TooShort { position: 6, expected: "separator".to_string() }));
+    assert_eq!(ptime::parse_lenient("1395/1/2 extra"), Err(ParseError::Invalid { position: 9, expected: "digit".to_string() }));
+    assert_eq!(ptime::from_ordinal_string("1395-123 x"), Err(ParseError::TrailingCharacters { position: 9 }));
+    assert_eq!(ptime::from_rfc2822("Tue, 21 Mar 2016 10:30:05 +0330"), Err(ParseError::Inconsistent { position: 0, component: Component::Weekday }));
+    assert_eq!(ptime::strptime("2 فروردین", "d MMM"), Err(ParseError::Missing { component: Component::Year }));
+    assert_eq!(ptime::strptime("1395-01-02", "yyyy/MM/dd"), Err(ParseError::Invalid { position: 4, expected: "\"/\"".to_string() }));
+    assert_eq!(ptime::parse_relative("سه روز", &ptime::now()).unwrap_err().position(), Some(5));
+
+    let err = ptime::from_iso8601("2016-03-21T25:30").unwrap_err();
+    assert_eq!(err.to_string(), "hour out of range at 11");
+}
@@ -0,0 +1,84 @@
+extern crate ptime;
+
+use ptime::{ParseError, PersianPeriod};
+
+#[test]
+fn period_arithmetic() {
+    let p_tm = ptime::from_persian_date(1395, 0, 31).unwrap();
+    assert_eq!(p_tm + PersianPeriod::new(0, 6, 0), ptime::from_persian_date(1395, 6, 30).unwrap());
+    assert_eq!(p_tm + PersianPeriod::new(1, 2, 3), ptime::from_persian_date(1396, 3, 3).unwrap());
+    assert_eq!(p_tm - PersianPeriod::new(0, 0, 31), ptime::from_persian_date(1394, 11, 29).unwrap());
+
+    let mut tm = p_tm;
+    tm += PersianPeriod::new(0, 1, 0);
+    tm -= PersianPeriod::new(0, 0, 1);
+    assert_eq!(tm, ptime::from_persian_date(1395, 1, 30).unwrap());
+
+    let to = ptime::from_persian_date(1396, 2, 4).unwrap();
+    let from = ptime::from_persian_date(1395, 0, 1).unwrap();
+    assert_eq!(PersianPeriod::between(&from, &to), PersianPeriod::new(1, 2, 3));
+    assert_eq!(from + PersianPeriod::between(&from, &to), to);
+    assert_eq!(-PersianPeriod::new(1, -2, 3), PersianPeriod::new(-1, 2, -3));
+    assert!(PersianPeriod::ZERO.is_zero() && PersianPeriod::default().is_zero());
+}
+
+#[test]
+fn period_format() {
+    assert_eq!(PersianPeriod::new(1, 2, 3).to_string(), "۱ سال و ۲ ماه و ۳ روز");
+    assert_eq!(PersianPeriod::new(0, -2, 0).to_string(), "منفی ۲ ماه");
+    assert_eq!(PersianPeriod::ZERO.to_string(), "۰ روز");
+}
+
+#[test]
+fn period_parse() {
+    assert_eq!("1y 2m 3d".parse(), Ok(PersianPeriod::new(1, 2, 3)));
+    assert_eq!("-2w 1d".parse(), Ok(PersianPeriod::new(0, 0, -13)));
+    assert_eq!("3d1y".parse(), Ok(PersianPeriod::new(1, 0, 3)));
+    assert_eq!("۱ سال و منفی ۲ ماه".parse(), Ok(PersianPeriod::new(1, -2, 0)));
+    for period in &[PersianPeriod::new(1, 2, 3), PersianPeriod::new(0, -5, 10), PersianPeriod::ZERO] {
+        assert_eq!(period.to_string().parse(), Ok(*period));
+    }
+
+    assert_eq!("".parse::<PersianPeriod>(), Err(ParseError::Missing { component: ptime::Component::Day }));
+    assert_eq!("1y 2y".parse::<PersianPeriod>(), Err(ParseError::Inconsistent { position: 4, component: ptime::Component::Year }));
+    assert_eq!("1x".parse::<PersianPeriod>().unwrap_err().position(), Some(1));
+    assert_eq!("1y و".parse::<PersianPeriod>().unwrap_err().position(), Some(5));
+}
+
+#[test]
+fn age() {
+    let born = ptime::from_persian_components(1370, 5, 15, 20, 0, 0, 0).unwrap();
+    assert_eq!(born.age_on(&ptime::from_persian_date(1403, 5, 14).unwrap()), 32);
+    assert_eq!(born.age_on(&ptime::from_persian_date(1403, 5, 15).unwrap()), 33);
+    assert_eq!(born.age_period_on(&ptime::from_persian_date(1403, 7, 20).unwrap()), PersianPeriod::new(33, 2, 5));
+    assert_eq!(born.age_on(&born), 0);
+
+    let born = ptime::from_persian_date(1399, 11, 30).unwrap();
+    assert_eq!(born.age_on(&ptime::from_persian_date(1400, 11, 28).unwrap()), 0);
+    assert_eq!(born.age_on(&ptime::from_persian_date(1400, 11, 29).unwrap()), 1);
+    assert_eq!(born.age_on(&ptime::from_persian_date(1403, 11, 29).unwrap()), 3);
+    assert_eq!(born.age_on(&ptime::from_persian_date(1403, 11, 30).unwrap()), 4);
+    assert_eq!(born.age_period_on(&ptime::from_persian_date(1404, 0, 1).unwrap()), PersianPeriod::new(4, 0, 1));
+}
+
+#[test]
+fn next_anniversary() {
+    use ptime::EndOfMonth;
+
+    let date = |tm: ptime::Tm| (tm.tm_year, tm.tm_mon, tm.tm_mday, tm.tm_hour);
+    let wedding = ptime::from_persian_components(1390, 5, 15, 18, 0, 0, 0).unwrap();
+    let after = ptime::from_persian_date(1403, 5, 14).unwrap();
+    assert_eq!(date(wedding.next_anniversary(&after, EndOfMonth::Clamp)), (1403, 5, 15, 18));
+    let after = ptime::from_persian_date(1403, 5, 15).unwrap();
+    assert_eq!(date(wedding.next_anniversary(&after, EndOfMonth::Clamp)), (1404, 5, 15, 18));
+
+    let born = ptime::from_persian_date(1399, 11, 30).unwrap();
+    let after = ptime::from_persian_date(1404, 0, 10).unwrap();
+    assert_eq!(date(born.next_anniversary(&after, EndOfMonth::Clamp)), (1404, 11, 29, 0));
+    assert_eq!(date(born.next_anniversary(&after, EndOfMonth::Overflow)), (1405, 0, 1, 0));
+    let after = ptime::from_persian_date(1404, 11, 29).unwrap();
+    assert_eq!(date(born.next_anniversary(&after, EndOfMonth::Overflow)), (1405, 0, 1, 0));
+    assert_eq!(date(born.next_anniversary(&after, EndOfMonth::Clamp)), (1405, 11, 29, 0));
+    let after = ptime::from_persian_date(1403, 0, 1).unwrap();
+    assert_eq!(date(born.next_anniversary(&after, EndOfMonth::Overflow)), (1403, 11, 30, 0));
+}
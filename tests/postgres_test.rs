@@ -0,0 +1,35 @@
+#![cfg(feature = "postgres-types")]
+
+extern crate bytes;
+extern crate postgres_types;
+extern crate ptime;
+
+use bytes::BytesMut;
+use postgres_types::{FromSql, ToSql, Type};
+
+fn to_sql<T: ToSql>(value: &T, ty: &Type) -> BytesMut {
+    let mut out = BytesMut::new();
+    value.to_sql_checked(ty, &mut out).unwrap();
+    out
+}
+
+#[test]
+fn date() {
+    // PostgreSQL stores the number of days since January 1, 2000
+    let date = ptime::PersianDate::new(1378, 9, 11).unwrap();
+    assert_eq!(&to_sql(&date, &Type::DATE)[..], &0i32.to_be_bytes());
+    let date = ptime::PersianDate::new(1395, 0, 2).unwrap();
+    assert_eq!(ptime::PersianDate::from_sql(&Type::DATE, &to_sql(&date, &Type::DATE)).unwrap(), date);
+    assert!(!<ptime::PersianDate as ToSql>::accepts(&Type::TIMESTAMP));
+}
+
+#[test]
+fn timestamp() {
+    let date_time = ptime::PersianDate::new(1378, 9, 11).unwrap().and_time(ptime::PersianTime::new(0, 0, 1, 500_000).unwrap());
+    assert_eq!(&to_sql(&date_time, &Type::TIMESTAMP)[..], &1_000_500i64.to_be_bytes());
+    assert_eq!(ptime::PersianDateTime::from_sql(&Type::TIMESTAMP, &to_sql(&date_time, &Type::TIMESTAMP)).unwrap(), date_time);
+
+    let tm = ptime::localize(&ptime::from_persian_components(1395, 0, 2, 10, 30, 5, 250_000_000).unwrap(), ptime::Offset::Fixed(12600));
+    assert_eq!(ptime::Tm::from_sql(&Type::TIMESTAMPTZ, &to_sql(&tm, &Type::TIMESTAMPTZ)).unwrap(), tm.to_utc());
+    assert!(tm.to_sql_checked(&Type::DATE, &mut BytesMut::new()).is_err());
+}
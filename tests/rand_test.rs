@@ -0,0 +1,42 @@
+#![cfg(feature = "rand")]
+
+extern crate ptime;
+extern crate rand;
+
+use ptime::{PersianDate, UniformDate};
+use rand::distr::Distribution;
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+
+#[test]
+fn uniform_date() {
+    let mut rng = SmallRng::seed_from_u64(1404);
+    let start = PersianDate::new(1403, 11, 28).unwrap();
+    let end = PersianDate::new(1404, 0, 2).unwrap();
+    let distribution = UniformDate::from_dates(start, end).unwrap();
+    let mut days = [0; 5];
+    for _ in 0..1000 {
+        let date: PersianDate = distribution.sample(&mut rng);
+        assert!(date >= start && date <= end);
+        days[(date.to_tm().to_jdn() - start.to_tm().to_jdn()) as usize] += 1;
+    }
+    assert!(days.iter().all(|&count| count > 150), "{:?}", days);
+
+    let p_tm: ptime::Tm = distribution.sample(&mut rng);
+    assert_eq!((p_tm.tm_hour, p_tm.tm_min, p_tm.tm_sec, p_tm.tm_nsec, p_tm.tm_utcoff), (0, 0, 0, 0, 0));
+}
+
+#[test]
+fn ranges() {
+    let p_tm = ptime::from_persian_components(1403, 0, 1, 23, 0, 0, 0).unwrap();
+    let mut rng = SmallRng::seed_from_u64(0);
+    let date: PersianDate = UniformDate::new(&p_tm, &p_tm).unwrap().sample(&mut rng);
+    assert_eq!(date, PersianDate::new(1403, 0, 1).unwrap());
+    assert!(UniformDate::new(&p_tm, &ptime::Tm::MIN).is_none());
+
+    let full = UniformDate::full();
+    for p_tm in full.sample_iter(&mut rng).take(1000) {
+        let p_tm: ptime::Tm = p_tm;
+        assert!(p_tm >= ptime::Tm::MIN && p_tm <= ptime::Tm::MAX);
+    }
+}
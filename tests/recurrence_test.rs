@@ -0,0 +1,52 @@
+extern crate ptime;
+
+use ptime::recurrence::{Frequency, Recurrence};
+use ptime::{Month, Weekday};
+
+fn dates(rule: &Recurrence) -> Vec<(i32, i32, i32)> {
+    rule.iter().map(|tm| (tm.tm_year, tm.tm_mon + 1, tm.tm_mday)).collect()
+}
+
+#[test]
+fn yearly() {
+    let start = ptime::from_persian_components(1400, 0, 13, 9, 30, 0, 0).unwrap();
+    let rule = Recurrence::new(start, Frequency::Yearly).count(3);
+    assert_eq!(dates(&rule), vec![(1400, 1, 13), (1401, 1, 13), (1402, 1, 13)]);
+    assert!(rule.iter().all(|tm| (tm.tm_hour, tm.tm_min) == (9, 30)));
+
+    let rule = Recurrence::new(start, Frequency::Yearly).by_month(&[Month::Esfand]).by_month_day(&[-1]).count(4);
+    assert_eq!(dates(&rule), vec![(1400, 12, 29), (1401, 12, 29), (1402, 12, 29), (1403, 12, 30)]);
+
+    let rule = Recurrence::new(start, Frequency::Yearly).interval(2).until(ptime::from_persian_date(1404, 0, 13).unwrap());
+    assert_eq!(dates(&rule), vec![(1400, 1, 13), (1402, 1, 13)]);
+}
+
+#[test]
+fn monthly() {
+    let start = ptime::from_persian_date(1403, 4, 31).unwrap();
+    let rule = Recurrence::new(start, Frequency::Monthly).count(3);
+    assert_eq!(dates(&rule), vec![(1403, 5, 31), (1403, 6, 31), (1404, 1, 31)]);
+
+    let rule = Recurrence::new(start, Frequency::Monthly).by_month_day(&[1, -1]).count(4);
+    assert_eq!(dates(&rule), vec![(1403, 5, 31), (1403, 6, 1), (1403, 6, 31), (1403, 7, 1)]);
+
+    let rule = Recurrence::new(start, Frequency::Monthly).by_day(&[Weekday::Jomeh]).by_month_day(&[13]).count(1);
+    assert_eq!(rule.iter().next().map(|tm| tm.weekday()), Some(Weekday::Jomeh));
+}
+
+#[test]
+fn weekly_and_daily() {
+    // 1403/07/17 is Seshanbeh
+    let start = ptime::from_persian_date(1403, 6, 17).unwrap();
+    let rule = Recurrence::new(start, Frequency::Weekly).by_day(&[Weekday::Shanbeh, Weekday::Seshanbeh]).interval(2).count(4);
+    assert_eq!(dates(&rule), vec![(1403, 7, 17), (1403, 7, 28), (1403, 8, 1), (1403, 8, 12)]);
+
+    let rule = Recurrence::new(start, Frequency::Weekly).count(2);
+    assert_eq!(dates(&rule), vec![(1403, 7, 17), (1403, 7, 24)]);
+
+    let rule = Recurrence::new(start, Frequency::Daily).until(ptime::from_persian_date(1403, 6, 20).unwrap());
+    assert_eq!(dates(&rule), vec![(1403, 7, 17), (1403, 7, 18), (1403, 7, 19), (1403, 7, 20)]);
+
+    let rule = Recurrence::new(start, Frequency::Daily).by_month_day(&[31]).by_month(&[Month::Mehr]);
+    assert_eq!(rule.iter().next(), None);
+}
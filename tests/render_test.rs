@@ -0,0 +1,40 @@
+extern crate ptime;
+
+#[test]
+fn month_grid() {
+    // 1 Farvardin 1403 is Chaharshanbeh
+    let grid = ptime::render::month(1403, 0).unwrap();
+    assert_eq!(grid.weeks.len(), 5);
+    assert_eq!(grid.weeks[0], [None, None, None, None, Some(1), Some(2), Some(3)]);
+    assert_eq!(grid.weeks[4], [Some(25), Some(26), Some(27), Some(28), Some(29), Some(30), Some(31)]);
+    assert!(ptime::render::month(1403, 12).is_none());
+}
+
+#[test]
+fn month_text() {
+    let text = ptime::render::month(1403, 0).unwrap().to_text();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines[0], "    فروردین 1403");
+    assert_eq!(lines[1], " ش  ی  د  س  چ  پ  ج");
+    assert_eq!(lines[2], "             1  2  3");
+    assert_eq!(lines[6], "25 26 27 28 29 30 31");
+}
+
+#[test]
+fn year_text() {
+    let grid = ptime::render::year(1403);
+    assert_eq!(grid.months.len(), 12);
+
+    let text = grid.to_highlighted_text(3, &ptime::locale::Persian, |month, day, cell| {
+        if month == 0 && day == 1 {
+            format!("\x1b[7m{}\x1b[0m", cell)
+        } else {
+            cell.to_string()
+        }
+    });
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines[0].trim(), "1403");
+    assert_eq!(lines[2], "    فروردین 1403          اردیبهشت 1403            خرداد 1403");
+    assert_eq!(lines[4], "            \x1b[7m 1\x1b[0m  2  3    1  2  3  4  5  6  7             1  2  3  4");
+    assert_eq!(lines[1], "");
+}
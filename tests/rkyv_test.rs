@@ -0,0 +1,29 @@
+#![cfg(feature = "rkyv")]
+
+extern crate ptime;
+extern crate rkyv;
+
+use ptime::{PersianDate, PersianDateTime, PersianTime, Timespec, Tm};
+use rkyv::rancor::Error;
+
+#[test]
+fn tm() {
+    let p_tm = ptime::localize(&ptime::from_persian_components(1395, 0, 2, 10, 30, 5, 250).unwrap(), ptime::Offset::Tehran);
+    let bytes = rkyv::to_bytes::<Error>(&p_tm).unwrap();
+    let archived = rkyv::access::<ptime::ArchivedTm, Error>(&bytes).unwrap();
+    assert_eq!((archived.tm_year.to_native(), archived.tm_mday.to_native(), archived.tm_utcoff.to_native()), (1395, 2, 16200));
+    assert_eq!(rkyv::deserialize::<Tm, Error>(archived).unwrap(), p_tm);
+}
+
+#[test]
+fn date_types() {
+    let date_time = PersianDate::new(1403, 11, 30).unwrap().and_time(PersianTime::new(23, 59, 59, 999999999).unwrap());
+    let bytes = rkyv::to_bytes::<Error>(&date_time).unwrap();
+    let archived = rkyv::access::<ptime::ArchivedPersianDateTime, Error>(&bytes).unwrap();
+    assert_eq!(rkyv::deserialize::<PersianDateTime, Error>(archived).unwrap(), date_time);
+    assert_eq!(rkyv::from_bytes::<PersianDateTime, Error>(&bytes).unwrap(), date_time);
+
+    let clock = Timespec::new(-1, 500);
+    let bytes = rkyv::to_bytes::<Error>(&clock).unwrap();
+    assert_eq!(rkyv::from_bytes::<Timespec, Error>(&bytes).unwrap(), clock);
+}
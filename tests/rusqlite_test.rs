@@ -0,0 +1,59 @@
+#![cfg(feature = "rusqlite")]
+
+extern crate ptime;
+extern crate rusqlite;
+
+use ptime::rusqlite::{Storage, Stored};
+use rusqlite::types::Value;
+use rusqlite::Connection;
+
+fn connect() -> Connection {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute("CREATE TABLE events (day, local, starts_at)", ()).unwrap();
+    conn
+}
+
+#[test]
+fn text() {
+    let conn = connect();
+    let day = ptime::PersianDate::new(1395, 0, 2).unwrap();
+    let local = day.and_time(ptime::PersianTime::new(7, 0, 5, 250_000_000).unwrap());
+    let starts_at = ptime::localize(&ptime::from_persian_components(1395, 0, 2, 10, 30, 5, 0).unwrap(), ptime::Offset::Fixed(12600));
+    conn.execute("INSERT INTO events VALUES (?1, ?2, ?3)", (day, local, starts_at)).unwrap();
+
+    let row: (ptime::PersianDate, ptime::PersianDateTime, ptime::Tm) = conn
+        .query_row("SELECT * FROM events", (), |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+        .unwrap();
+    assert_eq!(row, (day, local, starts_at.to_utc()));
+
+    let stored: (String, String, String) = conn
+        .query_row("SELECT * FROM events", (), |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+        .unwrap();
+    assert_eq!(stored, ("2016-03-21".to_string(), "2016-03-21 07:00:05.250".to_string(), "2016-03-21 07:00:05".to_string()));
+}
+
+#[test]
+fn integer() {
+    let conn = connect();
+    let day = ptime::PersianDate::new(1395, 0, 2).unwrap();
+    let starts_at = ptime::from_persian_components(1395, 0, 2, 7, 0, 5, 0).unwrap();
+    let values = (Stored(day, Storage::Integer), Stored(day.and_time(starts_at.time()), Storage::Integer), Stored(starts_at, Storage::Integer));
+    conn.execute("INSERT INTO events VALUES (?1, ?2, ?3)", values).unwrap();
+
+    let stored: (i64, i64) = conn.query_row("SELECT day, starts_at FROM events", (), |row| Ok((row.get(0)?, row.get(1)?))).unwrap();
+    assert_eq!(stored, (1458518400, 1458543605));
+
+    let row: Stored<ptime::Tm> = conn.query_row("SELECT starts_at FROM events", (), |row| row.get(0)).unwrap();
+    assert_eq!(row, Stored(starts_at, Storage::Integer));
+    let row: Stored<ptime::PersianDate> = conn.query_row("SELECT day FROM events", (), |row| row.get(0)).unwrap();
+    assert_eq!(row, Stored(day, Storage::Integer));
+}
+
+#[test]
+fn invalid() {
+    let conn = connect();
+    conn.execute("INSERT INTO events VALUES (?1, ?2, ?3)", (Value::Real(1.5), "not a date", i64::MAX)).unwrap();
+    assert!(conn.query_row("SELECT day FROM events", (), |row| row.get::<_, ptime::PersianDate>(0)).is_err());
+    assert!(conn.query_row("SELECT local FROM events", (), |row| row.get::<_, ptime::PersianDateTime>(0)).is_err());
+    assert!(conn.query_row("SELECT starts_at FROM events", (), |row| row.get::<_, ptime::Tm>(0)).is_err());
+}
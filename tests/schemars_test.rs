@@ -0,0 +1,43 @@
+#![cfg(feature = "schemars")]
+
+extern crate ptime;
+#[macro_use]
+extern crate schemars;
+extern crate serde_json;
+
+use schemars::SchemaGenerator;
+
+#[test]
+fn schemas() {
+    let schema = schema_for!(ptime::Tm);
+    assert_eq!(schema.get("type").unwrap(), "string");
+    assert_eq!(schema.get("title").unwrap(), "Tm");
+    assert!(schema.get("format").is_none());
+
+    let schema = schema_for!(ptime::PersianDate);
+    assert_eq!(schema.get("pattern").unwrap(), r"^-?\d{3,4}-\d{2}-\d{2}$");
+}
+
+#[test]
+fn examples_match_serde() {
+    let p_tm = ptime::localize(&ptime::from_persian_components(1395, 0, 2, 10, 30, 5, 0).unwrap(), ptime::Offset::Fixed(12600));
+    let date_time = p_tm.date().and_time(p_tm.time());
+    let values = [
+        (schema_for!(ptime::Tm), serde_json::to_value(p_tm).unwrap()),
+        (schema_for!(ptime::PersianDate), serde_json::to_value(p_tm.date()).unwrap()),
+        (schema_for!(ptime::PersianTime), serde_json::to_value(p_tm.time()).unwrap()),
+        (schema_for!(ptime::PersianDateTime), serde_json::to_value(date_time).unwrap()),
+        (schema_for!(ptime::PersianPeriod), serde_json::to_value(ptime::PersianPeriod::new(1, 2, 3)).unwrap()),
+    ];
+    for (schema, value) in values.iter() {
+        assert_eq!(&schema.get("examples").unwrap()[0], value);
+    }
+}
+
+#[test]
+fn inlined() {
+    let mut generator = SchemaGenerator::default();
+    let schema = generator.subschema_for::<ptime::PersianDate>();
+    assert_eq!(schema.get("type").unwrap(), "string");
+    assert!(generator.definitions().is_empty());
+}
@@ -0,0 +1,112 @@
+#![cfg(feature = "serde")]
+
+#[macro_use]
+extern crate ptime;
+#[macro_use]
+extern crate serde;
+extern crate serde_json;
+
+use ptime::{PersianDate, PersianDateTime, PersianPeriod, PersianTime};
+
+#[test]
+fn tm() {
+    let p_tm = ptime::localize(&ptime::from_persian_components(1395, 0, 2, 10, 30, 5, 250_000_000).unwrap(), ptime::Offset::Fixed(12600));
+    let json = serde_json::to_string(&p_tm).unwrap();
+    assert_eq!(json, "\"1395-01-02T10:30:05.250+03:30\"");
+    assert_eq!(serde_json::from_str::<ptime::Tm>(&json).unwrap(), p_tm);
+    assert_eq!(serde_json::to_string(&ptime::from_persian_date(1403, 11, 30).unwrap()).unwrap(), "\"1403-12-30T00:00:00Z\"");
+    assert!(serde_json::from_str::<ptime::Tm>("\"1404-12-30T00:00:00Z\"").is_err());
+    assert!(serde_json::from_str::<ptime::Tm>("13950102").is_err());
+}
+
+#[test]
+fn date_types() {
+    let date = PersianDate::new(1395, 0, 2).unwrap();
+    let time = PersianTime::new(10, 30, 5, 500).unwrap();
+    let date_time = date.and_time(time);
+    assert_eq!(serde_json::to_string(&date).unwrap(), "\"1395-01-02\"");
+    assert_eq!(serde_json::to_string(&time).unwrap(), "\"10:30:05.000000500\"");
+    assert_eq!(serde_json::to_string(&date_time).unwrap(), "\"1395-01-02T10:30:05.000000500\"");
+    assert_eq!(serde_json::from_str::<PersianDate>("\"1395-01-02\"").unwrap(), date);
+    assert_eq!(serde_json::from_str::<PersianTime>("\"10:30:05.000000500\"").unwrap(), time);
+    assert_eq!(serde_json::from_str::<PersianDateTime>("\"1395-01-02T10:30:05.000000500\"").unwrap(), date_time);
+    assert!(serde_json::from_str::<PersianDate>("\"1395-01-02T10:30\"").is_err());
+    assert!(serde_json::from_str::<PersianDateTime>("\"1395-01-02T10:30+03:30\"").is_err());
+
+    let period = PersianPeriod::new(1, -2, 3);
+    let json = serde_json::to_string(&period).unwrap();
+    assert_eq!(serde_json::from_str::<PersianPeriod>(&json).unwrap(), period);
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Event {
+    #[serde(with = "ptime::serde::ts_seconds")]
+    created: ptime::Tm,
+    #[serde(with = "ptime::serde::rfc3339")]
+    starts: ptime::Tm,
+    #[serde(with = "ptime::serde::persian_string")]
+    ends: ptime::Tm,
+}
+
+#[test]
+fn with_modules() {
+    let created = ptime::at_utc(ptime::Timespec::new(1458543600, 0));
+    let starts = ptime::localize(&ptime::from_persian_components(1395, 0, 2, 10, 30, 5, 0).unwrap(), ptime::Offset::Fixed(12600));
+    let ends = ptime::from_persian_components(1403, 0, 1, 12, 30, 0, 0).unwrap();
+    let event = Event { created, starts, ends };
+    let json = serde_json::to_string(&event).unwrap();
+    assert_eq!(json, r#"{"created":1458543600,"starts":"2016-03-21T10:30:05+03:30","ends":"1403/01/01 12:30"}"#);
+    assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+
+    let json = r#"{"created":9000000000000,"starts":"2016-03-21T10:30:05+03:30","ends":"1403/01/01 12:30"}"#;
+    assert!(serde_json::from_str::<Event>(json).unwrap_err().to_string().contains("out of supported range"));
+    let json = r#"{"created":0,"starts":"2016-03-21T10:30:05+03:30","ends":"1403-01-01 12:30"}"#;
+    assert!(serde_json::from_str::<Event>(json).is_err());
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Deadline {
+    #[serde(with = "ptime::serde::persian_string")]
+    at: ptime::Tm,
+}
+
+#[test]
+fn persian_string_range() {
+    let years = [
+        (ptime::Tm::MIN, "-5000/01/01 00:00"),
+        (ptime::from_persian_date(0, 0, 1).unwrap(), "0000/01/01 00:00"),
+        (ptime::from_persian_date(5, 0, 1).unwrap(), "0005/01/01 00:00"),
+        (ptime::from_persian_components(ptime::MAX_YEAR, 11, 29, 23, 59, 0, 0).unwrap(), "9999/12/29 23:59"),
+    ];
+    for &(at, written) in years.iter() {
+        let deadline = Deadline { at };
+        let json = serde_json::to_string(&deadline).unwrap();
+        assert_eq!(json, format!(r#"{{"at":"{}"}}"#, written));
+        assert_eq!(serde_json::from_str::<Deadline>(&json).unwrap(), deadline);
+    }
+}
+
+serde_format!(mod long_date, "E d MMM yyyy ساعت HH:mm");
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Meeting {
+    #[serde(with = "long_date")]
+    at: ptime::Tm,
+}
+
+#[test]
+fn format_adapter() {
+    let meeting = Meeting { at: ptime::from_persian_components(1395, 0, 2, 10, 30, 0, 0).unwrap() };
+    let json = serde_json::to_string(&meeting).unwrap();
+    assert_eq!(json, r#"{"at":"دوشنبه 2 فروردین 1395 ساعت 10:30"}"#);
+    assert_eq!(serde_json::from_str::<Meeting>(&json).unwrap(), meeting);
+    assert_eq!(long_date::FORMAT, "E d MMM yyyy ساعت HH:mm");
+    let error = serde_json::from_str::<Meeting>(r#"{"at":1}"#).unwrap_err().to_string();
+    assert!(error.contains("a Persian time formatted as E d MMM yyyy ساعت HH:mm"), "{}", error);
+
+    for &year in [ptime::MIN_YEAR, 0, 5, 999].iter() {
+        let meeting = Meeting { at: ptime::from_persian_components(year, 0, 1, 10, 30, 0, 0).unwrap() };
+        let json = serde_json::to_string(&meeting).unwrap();
+        assert_eq!(serde_json::from_str::<Meeting>(&json).unwrap(), meeting, "{}", json);
+    }
+}
@@ -0,0 +1,48 @@
+#![cfg(all(feature = "sqlx", not(target_arch = "wasm32")))]
+
+extern crate ptime;
+extern crate sqlx;
+extern crate tokio;
+
+use sqlx::{Connection, SqliteConnection};
+use tokio::runtime::{Builder, Runtime};
+
+// The edition of crate does not support `async`, so the futures run one by one
+fn connect() -> (Runtime, SqliteConnection) {
+    let rt = Builder::new_current_thread().build().unwrap();
+    let conn = rt.block_on(SqliteConnection::connect("sqlite::memory:")).unwrap();
+    (rt, conn)
+}
+
+#[test]
+fn roundtrip() {
+    let (rt, mut conn) = connect();
+    let create = sqlx::query("CREATE TABLE events (day DATE NOT NULL, local DATETIME NOT NULL, starts_at DATETIME NOT NULL)");
+    rt.block_on(create.execute(&mut conn)).unwrap();
+
+    let day = ptime::PersianDate::new(1403, 0, 1).unwrap();
+    let local = day.and_time(ptime::PersianTime::new(12, 30, 5, 0).unwrap());
+    let starts_at = ptime::localize(&local.to_tm(), ptime::Offset::Fixed(12600));
+    let insert = sqlx::query("INSERT INTO events VALUES (?, ?, ?)").bind(day).bind(local).bind(starts_at);
+    rt.block_on(insert.execute(&mut conn)).unwrap();
+
+    let select = sqlx::query_as("SELECT day, local, starts_at FROM events");
+    let row: (ptime::PersianDate, ptime::PersianDateTime, ptime::Tm) = rt.block_on(select.fetch_one(&mut conn)).unwrap();
+    assert_eq!(row, (day, local, starts_at.to_utc()));
+
+    let select = sqlx::query_as("SELECT day FROM events");
+    let stored: (String,) = rt.block_on(select.fetch_one(&mut conn)).unwrap();
+    assert_eq!(stored.0, "2024-03-20");
+}
+
+#[test]
+fn decode_gregorian() {
+    let (rt, mut conn) = connect();
+    let select = sqlx::query_as("SELECT date('2016-03-21', '+1 day')");
+    let (day,): (ptime::PersianDate,) = rt.block_on(select.fetch_one(&mut conn)).unwrap();
+    assert_eq!(day, ptime::PersianDate::new(1395, 0, 3).unwrap());
+
+    let select = sqlx::query_as("SELECT '2016-03-21T07:00:05+00:00'");
+    let (tm,): (ptime::Tm,) = rt.block_on(select.fetch_one(&mut conn)).unwrap();
+    assert_eq!(tm, ptime::from_persian_components(1395, 0, 2, 7, 0, 5, 0).unwrap());
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "proptest")]
+
+#[macro_use]
+extern crate proptest;
+extern crate ptime;
+
+use ptime::testing;
+use ptime::PersianDate;
+
+proptest! {
+    #[test]
+    fn dates_in_range(date in testing::dates(PersianDate::new(1400, 0, 1).unwrap(), PersianDate::new(1402, 11, 29).unwrap())) {
+        prop_assert!(date >= PersianDate::new(1400, 0, 1).unwrap());
+        prop_assert!(date <= PersianDate::new(1402, 11, 29).unwrap());
+    }
+
+    #[test]
+    fn any_date_valid(date in testing::any_date()) {
+        prop_assert!(PersianDate::new(date.year(), date.month_number() - 1, date.day()).is_some());
+    }
+
+    #[test]
+    fn leap_year_dates(date in testing::leap_year_dates()) {
+        prop_assert!(date.to_tm().is_leap());
+    }
+
+    #[test]
+    fn edge_dates(date in testing::edge_dates()) {
+        prop_assert!(PersianDate::new(date.year(), date.month_number() - 1, date.day()).is_some());
+        let tm = date.to_tm();
+        let is_edge = date.day() == 1 || date.day() == tm.days_in_month() || (date.month_number() == 12 && date.day() == 29) || date == PersianDate::from_tm(&ptime::Tm::MIN);
+        prop_assert!(is_edge, "{} is not an edge date", date);
+    }
+
+    #[test]
+    fn instants(tm in testing::instants(&ptime::from_persian_components(1403, 0, 1, 0, 0, 0, 0).unwrap(), &ptime::from_persian_components(1403, 0, 1, 0, 0, 1, 500).unwrap())) {
+        prop_assert!(tm.tm_year == 1403 && tm.tm_mon == 0 && tm.tm_mday == 1 && tm.tm_hour == 0 && tm.tm_min == 0);
+        prop_assert!(tm.tm_sec == 0 || tm.tm_nsec <= 500);
+    }
+}
+
+#[test]
+fn edge_dates_cover_esfand() {
+    use proptest::strategy::{Strategy, ValueTree};
+    use proptest::test_runner::TestRunner;
+
+    let strategy = testing::edge_dates();
+    let mut runner = TestRunner::deterministic();
+    let dates: Vec<PersianDate> = (0..1000).map(|_| strategy.new_tree(&mut runner).unwrap().current()).collect();
+    assert!(dates.iter().any(|date| date.month_number() == 12 && date.day() == 30));
+    assert!(dates.iter().any(|date| date.month_number() == 12 && date.day() == 29 && !date.to_tm().is_leap()));
+    assert!(dates.iter().any(|date| date.month_number() == 1 && date.day() == 1));
+}
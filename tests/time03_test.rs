@@ -0,0 +1,40 @@
+#![cfg(feature = "time03")]
+
+extern crate ptime;
+extern crate time03;
+
+use std::convert::TryFrom;
+use time03::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+#[test]
+fn from_time() {
+    let date = Date::from_calendar_date(2016, Month::March, 21).unwrap();
+    assert_eq!(ptime::Tm::try_from(date).unwrap(), ptime::from_persian_date(1395, 0, 2).unwrap());
+
+    let date_time = PrimitiveDateTime::new(date, Time::from_hms_nano(10, 30, 5, 500).unwrap());
+    let p_tm = ptime::from_persian_components(1395, 0, 2, 10, 30, 5, 500).unwrap();
+    assert_eq!(ptime::Tm::try_from(date_time).unwrap(), p_tm);
+    assert_eq!(ptime::Tm::try_from(date_time.assume_utc()).unwrap(), p_tm);
+
+    let p_tm = ptime::Tm::try_from(date_time.assume_offset(UtcOffset::from_hms(3, 30, 0).unwrap())).unwrap();
+    assert_eq!((p_tm.tm_mday, p_tm.tm_hour, p_tm.tm_min, p_tm.tm_utcoff), (2, 10, 30, 12600));
+    assert_eq!(p_tm, ptime::at_offset(p_tm.to_timespec(), ptime::Offset::Fixed(12600)));
+
+    let out_of_range = Date::from_calendar_date(-9999, Month::January, 1).unwrap();
+    assert_eq!(ptime::Tm::try_from(out_of_range), Err(ptime::DateError::YearOutOfRange { year: -9999 }));
+}
+
+#[test]
+fn to_time() {
+    let p_tm = ptime::localize(&ptime::from_persian_components(1395, 0, 2, 10, 30, 5, 500).unwrap(), ptime::Offset::Fixed(12600));
+    let date = Date::from_calendar_date(2016, Month::March, 21).unwrap();
+    let date_time = PrimitiveDateTime::new(date, Time::from_hms_nano(10, 30, 5, 500).unwrap());
+    assert_eq!(Date::try_from(p_tm), Ok(date));
+    assert_eq!(PrimitiveDateTime::try_from(p_tm), Ok(date_time));
+    let offset_date_time = OffsetDateTime::try_from(p_tm).unwrap();
+    assert_eq!(offset_date_time, date_time.assume_offset(UtcOffset::from_whole_seconds(12600).unwrap()));
+    assert_eq!(offset_date_time.unix_timestamp(), p_tm.to_timespec().sec);
+
+    // 1 Farvardin 9999 is in the Gregorian year 10620, beyond the range of time 0.3
+    assert!(Date::try_from(ptime::from_persian_date(9999, 0, 1).unwrap()).is_err());
+}
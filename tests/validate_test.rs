@@ -0,0 +1,27 @@
+extern crate ptime;
+
+use ptime::validate;
+use ptime::{Component, DateError};
+
+#[test]
+fn validate_date() {
+    assert_eq!(validate::persian_date(1395, 11, 30), Ok(()));
+    assert_eq!(validate::persian_date(1396, 11, 30), Err(DateError::DayOutOfRange { day: 30, max: 29 }));
+    assert_eq!(validate::persian_date(1396, -1, 1), Err(DateError::MonthOutOfRange { month: -1 }));
+    assert_eq!(validate::gregorian_date(2016, 1, 29), Ok(()));
+    assert_eq!(validate::gregorian_date(2015, 1, 29), Err(DateError::DayOutOfRange { day: 29, max: 28 }));
+    assert_eq!(validate::gregorian_date(-5000, 0, 1), Err(DateError::YearOutOfRange { year: -5000 }));
+    assert!(validate::is_persian_date_valid(1395, 0, 31));
+    assert!(!validate::is_persian_date_valid(1395, 6, 31));
+    assert!(validate::is_gregorian_date_valid(2000, 1, 29));
+    assert!(!validate::is_gregorian_date_valid(2000, 1, 30));
+}
+
+#[test]
+fn validate_time() {
+    assert_eq!(validate::time(23, 59, 59, 999999999), Ok(()));
+    assert_eq!(validate::time(10, 30, 60, 0), Err(DateError::InvalidTime { component: Component::Second }));
+    assert_eq!(validate::time(10, 30, 0, -1), Err(DateError::InvalidTime { component: Component::Nanosecond }));
+    assert!(validate::is_time_valid(0, 0, 0, 0));
+    assert!(!validate::is_time_valid(-1, 0, 0, 0));
+}
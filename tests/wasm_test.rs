@@ -0,0 +1,31 @@
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+extern crate js_sys;
+extern crate ptime;
+extern crate wasm_bindgen_test;
+
+use js_sys::wasm_bindgen::JsValue;
+use js_sys::Date;
+use std::convert::TryFrom;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn conversions() {
+    let p_tm = ptime::localize(&ptime::from_persian_components(1395, 0, 2, 10, 30, 5, 250_999_999).unwrap(), ptime::Offset::Fixed(12600));
+    let date = Date::from(p_tm);
+    assert_eq!(date.get_time(), 1458543605250.0);
+    assert_eq!(ptime::Tm::try_from(&date).unwrap(), ptime::from_persian_components(1395, 0, 2, 7, 0, 5, 250_000_000).unwrap());
+
+    let date = Date::new(&JsValue::from_str("not a date"));
+    assert_eq!(ptime::Tm::try_from(date), Err(ptime::DateError::Missing { component: ptime::Component::Year }));
+    let date = Date::new(&JsValue::from_f64(8.64e15));
+    assert_eq!(ptime::Tm::try_from(date), Err(ptime::DateError::YearOutOfRange { year: 275760 }));
+}
+
+#[wasm_bindgen_test]
+fn now_utc() {
+    let before = Date::now();
+    let now = ptime::now_utc().to_timespec();
+    let millis = (now.sec * 1000 + now.nsec as i64 / 1_000_000) as f64;
+    assert!(millis >= before && millis <= Date::now());
+}
@@ -0,0 +1,43 @@
+extern crate ptime;
+
+use ptime::PersianIsoWeek;
+
+#[test]
+fn week_of_date() {
+    let week = ptime::from_persian_date(1395, 3, 30).unwrap().week();
+    assert_eq!((week.year(), week.week(), week.weekday()), (1395, 18, 5));
+    assert_eq!(week.first_day(), ptime::from_persian_date(1395, 3, 26).unwrap());
+
+    let week = ptime::from_persian_date(1397, 0, 1).unwrap().week();
+    assert_eq!((week.year(), week.week(), week.weekday()), (1396, 53, 5));
+}
+
+#[test]
+fn week_to_date() {
+    assert_eq!(PersianIsoWeek::weeks_in_year(1395), 52);
+    assert_eq!(PersianIsoWeek::weeks_in_year(1396), 53);
+    assert!(PersianIsoWeek::new(1395, 53, 1).is_none());
+    assert!(PersianIsoWeek::new(1395, 1, 0).is_none());
+    assert!(PersianIsoWeek::new(ptime::MIN_YEAR - 1, 1, 1).is_none());
+    assert!(PersianIsoWeek::new(i32::MAX, 1, 1).is_none());
+    assert!(PersianIsoWeek::new(ptime::MIN_YEAR, 1, 1).is_none());
+    assert!(PersianIsoWeek::new(ptime::MAX_YEAR, 52, 7).is_some());
+    assert!(PersianIsoWeek::new(ptime::MAX_YEAR + 1, 1, 1).is_none());
+    assert_eq!(PersianIsoWeek::new(ptime::MIN_YEAR, 2, 1).unwrap().to_tm().week().week(), 2);
+
+    let week = PersianIsoWeek::new(1396, 1, 3).unwrap();
+    assert_eq!(week.to_tm(), ptime::from_persian_date(1395, 11, 30).unwrap());
+    assert_eq!(ptime::Tm::from(week).week(), week);
+}
+
+#[test]
+fn week_of_bounds() {
+    let week = ptime::Tm::MIN.week();
+    assert_eq!(week.to_tm(), ptime::Tm::MIN);
+    assert_eq!(week.first_day(), ptime::Tm::MIN);
+    assert_eq!(ptime::Tm::from(week), ptime::Tm::MIN);
+
+    let week = ptime::Tm::MAX.week();
+    assert_eq!(week.year(), ptime::MAX_YEAR + 1);
+    assert_eq!(week.to_tm(), ptime::from_persian_date(ptime::MAX_YEAR, 11, 29).unwrap());
+}
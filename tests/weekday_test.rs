@@ -0,0 +1,141 @@
+extern crate ptime;
+
+use ptime::Weekday;
+
+#[test]
+fn weekday() {
+    assert_eq!(Weekday::Shanbeh.num_days_from_shanbeh(), 0);
+    assert_eq!(Weekday::Jomeh.num_days_from_shanbeh(), 6);
+    assert_eq!(Weekday::Seshanbeh.name(), "سه‌شنبه");
+    assert_eq!(Weekday::Seshanbeh.transliterated_name(), "Seshanbeh");
+    assert_eq!(Weekday::Jomeh.to_string(), "جمعه");
+    assert_eq!(Weekday::from_index(1), Some(Weekday::Yekshanbeh));
+    assert_eq!(Weekday::from_index(7), None);
+}
+
+#[test]
+fn weekday_arithmetic() {
+    assert_eq!(Weekday::Jomeh.succ(), Weekday::Shanbeh);
+    assert_eq!(Weekday::Shanbeh.pred(), Weekday::Jomeh);
+    assert_eq!(Weekday::Doshanbeh + 3, Weekday::Panjshanbeh);
+    assert_eq!(Weekday::Doshanbeh + 15, Weekday::Seshanbeh);
+    assert_eq!(Weekday::Doshanbeh - 3, Weekday::Jomeh);
+    assert_eq!(Weekday::Doshanbeh + -3, Weekday::Jomeh);
+    assert_eq!(Weekday::Shanbeh + i32::MAX, Weekday::Shanbeh + i32::MAX % 7);
+    assert_eq!(Weekday::Shanbeh - i32::MIN, Weekday::Shanbeh + 2);
+
+    let p_tm = ptime::from_persian_date(1395, 0, 2).unwrap();
+    assert_eq!(p_tm.weekday(), Weekday::Doshanbeh);
+    assert_eq!(p_tm.checked_weekday(), Some(Weekday::Doshanbeh));
+    assert_eq!(ptime::Tm { tm_wday: -1, ..p_tm }.checked_weekday(), None);
+    assert_eq!(ptime::from_persian_date(1395, 0, 9).unwrap().weekday(), p_tm.weekday() + 7);
+}
+
+#[test]
+fn occurrences() {
+    // 1403/07/17 is Seshanbeh
+    let p_tm = ptime::from_persian_components(1403, 6, 17, 9, 0, 0, 0).unwrap();
+    assert_eq!(p_tm.weekday(), Weekday::Seshanbeh);
+
+    let next = p_tm.next_occurrence_of(Weekday::Jomeh);
+    assert_eq!((next.tm_mday, next.weekday(), next.tm_hour), (20, Weekday::Jomeh, 9));
+    assert_eq!(p_tm.next_occurrence_of(Weekday::Seshanbeh).tm_mday, 24);
+    assert_eq!(p_tm.next_occurrence_of(Weekday::Chaharshanbeh).tm_mday, 18);
+
+    let previous = p_tm.previous_occurrence_of(Weekday::Shanbeh);
+    assert_eq!((previous.tm_mday, previous.weekday()), (14, Weekday::Shanbeh));
+    assert_eq!(p_tm.previous_occurrence_of(Weekday::Seshanbeh).tm_mday, 10);
+    assert_eq!(p_tm.previous_occurrence_of(Weekday::Doshanbeh).tm_mday, 16);
+}
+
+#[test]
+fn nth_weekday_of_month() {
+    // Mehr 1403 starts on Yekshanbeh and has 30 days
+    let third = ptime::nth_weekday_of_month(1403, 6, Weekday::Doshanbeh, 3).unwrap();
+    assert_eq!((third.tm_mday, third.weekday()), (16, Weekday::Doshanbeh));
+    assert_eq!(ptime::nth_weekday_of_month(1403, 6, Weekday::Yekshanbeh, 1).unwrap().tm_mday, 1);
+    assert_eq!(ptime::nth_weekday_of_month(1403, 6, Weekday::Yekshanbeh, 5).unwrap().tm_mday, 29);
+    assert_eq!(ptime::nth_weekday_of_month(1403, 6, Weekday::Doshanbeh, 5).unwrap().tm_mday, 30);
+    assert_eq!(ptime::nth_weekday_of_month(1403, 6, Weekday::Seshanbeh, 5), None);
+    assert_eq!(ptime::nth_weekday_of_month(1403, 6, Weekday::Jomeh, -2).unwrap().tm_mday, 20);
+    assert_eq!(ptime::nth_weekday_of_month(1403, 6, Weekday::Jomeh, 0), None);
+    assert_eq!(ptime::nth_weekday_of_month(1403, 12, Weekday::Jomeh, 1), None);
+
+    let last = ptime::last_weekday_of_month(1403, 6, Weekday::Jomeh).unwrap();
+    assert_eq!((last.tm_mday, last.weekday()), (27, Weekday::Jomeh));
+    assert_eq!(ptime::last_weekday_of_month(1403, 6, Weekday::Doshanbeh).unwrap().tm_mday, 30);
+}
+
+#[test]
+fn business_days() {
+    use ptime::Weekend;
+
+    let weekend = Weekend::default();
+    assert_eq!(weekend, Weekend::new(&[Weekday::Jomeh]));
+    assert_eq!(Weekend::new(&[Weekday::Panjshanbeh, Weekday::Jomeh]).bits(), 0b110_0000);
+    assert_eq!(Weekend::from_bits(0xff).business_days_per_week(), 0);
+
+    // 1403/07/19 is Panjshanbeh
+    let p_tm = ptime::from_persian_components(1403, 6, 19, 9, 0, 0, 0).unwrap();
+    assert!(p_tm.is_business_day(&weekend));
+    assert!(!p_tm.add_days(1).is_business_day(&weekend));
+    let next = p_tm.add_business_days(1, &weekend);
+    assert_eq!((next.tm_mday, next.weekday(), next.tm_hour), (21, Weekday::Shanbeh, 9));
+    assert_eq!(p_tm.add_business_days(6, &weekend).tm_mday, 26);
+    assert_eq!(p_tm.add_business_days(13, &weekend).tm_mon, 7);
+    assert_eq!(p_tm.add_business_days(-5, &weekend).tm_mday, 14);
+    assert_eq!(p_tm.add_business_days(0, &weekend), p_tm);
+    assert_eq!(p_tm.checked_add_business_days(6, &weekend), Some(p_tm.add_business_days(6, &weekend)));
+    let last = ptime::from_persian_date(ptime::MAX_YEAR, 11, 28).unwrap();
+    assert_eq!(last.checked_add_business_days(5, &weekend), None);
+    assert_eq!(ptime::Tm::MIN.checked_add_business_days(-1, &weekend), None);
+    assert_eq!(p_tm.checked_add_business_days(i32::MIN, &weekend), None);
+
+    let legacy = Weekend::new(&[Weekday::Panjshanbeh, Weekday::Jomeh]);
+    assert_eq!(p_tm.add_days(-1).add_business_days(1, &legacy).tm_mday, 21);
+    assert_eq!(p_tm.add_business_days(-1, &legacy).tm_mday, 18);
+    for days in -30..30 {
+        let tm = p_tm.add_business_days(days, &legacy);
+        let (from, to) = if days < 0 { (tm.add_days(-1), p_tm.add_days(-1)) } else { (p_tm, tm) };
+        let count = (1..=to.to_jdn() - from.to_jdn()).filter(|&i| from.add_days(i).is_business_day(&legacy)).count();
+        assert_eq!(count as i32, days.abs());
+        assert!(days == 0 || tm.is_business_day(&legacy));
+    }
+}
+
+#[test]
+fn weekends() {
+    use ptime::Weekend;
+
+    assert_eq!(Weekend::default(), Weekend::IRAN_FRIDAY_ONLY);
+    assert_eq!(Weekend::LEGACY_THU_FRI, Weekend::new(&[Weekday::Panjshanbeh, Weekday::Jomeh]));
+    assert_eq!(Weekend::AFGHAN_FRIDAY.business_days_per_week(), 6);
+
+    // 1403/07/19 is Panjshanbeh
+    let p_tm = ptime::from_persian_date(1403, 6, 19).unwrap();
+    assert!(!p_tm.is_weekend(&Weekend::IRAN_FRIDAY_ONLY));
+    assert!(p_tm.is_weekend(&Weekend::LEGACY_THU_FRI));
+    assert!(p_tm.add_days(1).is_weekend(&Weekend::AFGHAN_FRIDAY));
+    assert!(p_tm.add_days(2).is_weekend(&Weekend::from_bits(0b000_0001)));
+    assert_eq!(p_tm.add_business_days(1, &Weekend::LEGACY_THU_FRI).weekday(), Weekday::Shanbeh);
+}
+
+#[test]
+fn working_days_between() {
+    use ptime::Weekend;
+
+    let weekend = Weekend::default();
+    let from = ptime::from_persian_date(1403, 11, 1).unwrap();
+    let to = ptime::from_persian_date(1404, 0, 1).unwrap();
+    // Esfand 1403 has 30 days, 4 of which are Jomeh
+    assert_eq!(ptime::working_days_between(&from, &to, &weekend, None), 26);
+    assert_eq!(ptime::working_days_between(&to, &from, &weekend, None), -26);
+    assert_eq!(ptime::working_days_between(&from, &from, &weekend, None), 0);
+
+    let calendar = ptime::holidays::Custom::new().with_yearly(11, 29, "تعطیل");
+    assert_eq!(ptime::working_days_between(&from, &to, &weekend, Some(&calendar)), 25);
+
+    let from = ptime::from_persian_date(1404, 11, 1).unwrap();
+    let to = ptime::from_persian_date(1405, 0, 1).unwrap();
+    assert_eq!(ptime::working_days_between(&from, &to, &Weekend::new(&[]), None), 29);
+}
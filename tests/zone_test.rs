@@ -0,0 +1,17 @@
+#![cfg(feature = "tzdb")]
+
+extern crate ptime;
+
+#[test]
+fn at_zone() {
+    // 2 Farvardin 1395 10:30 +04:30 in both Tehran (in daylight saving time) and Kabul
+    let clock = ptime::Timespec::new(1458540000, 0);
+    let p_tm = ptime::at_zone(clock, "Asia/Tehran").unwrap();
+    assert_eq!((p_tm.tm_mday, p_tm.tm_hour, p_tm.tm_min, p_tm.tm_utcoff, p_tm.tm_isdst), (2, 10, 30, 16200, 1));
+    assert_eq!(p_tm, ptime::at_tehran(clock));
+    let p_tm = ptime::at_zone(clock, "Asia/Kabul").unwrap();
+    assert_eq!((p_tm.tm_mday, p_tm.tm_hour, p_tm.tm_min, p_tm.tm_utcoff, p_tm.tm_isdst), (2, 10, 30, 16200, 0));
+    assert_eq!(p_tm.to_zone("UTC").unwrap(), ptime::at_utc(clock));
+    assert_eq!(ptime::at_zone(clock, "Asia/Unknown"), None);
+    assert!(ptime::now_zone("Asia/Tehran").is_some());
+}